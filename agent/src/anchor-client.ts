@@ -12,7 +12,11 @@ import {
   PROGRAM_ID,
   getConnection,
   getRegistryPda,
+  getGlobalStatsPda,
   getAgentPda,
+  getSpecCounterPda,
+  getNameReservationPda,
+  NAMESPACE,
 } from "./config.js";
 
 // Track recent transaction signatures for the dashboard
@@ -59,6 +63,45 @@ function getProvider(wallet: Keypair): AnchorProvider {
   });
 }
 
+/** Registry settings for `initialize`: every optional feature off, default max depth */
+function defaultInitializeParams() {
+  return {
+    maxDepth: 0,
+    strictNames: false,
+    pruneGraceSecs: new BN(0),
+    enforceWithdrawWhitelist: false,
+    requiredBondLamports: new BN(0),
+    bondCooldownSecs: new BN(0),
+    staleThresholdSecs: new BN(0),
+    requireActiveLineage: false,
+    maxSingleEarning: new BN(0),
+    compactEvents: false,
+    requireBalanced: false,
+    branchingFactor: 0,
+    proposalTtlSecs: new BN(0),
+    allowCpi: false,
+    reparentRequiresChild: false,
+    minActiveSecs: new BN(0),
+    reporter: PublicKey.default,
+    reactivationQuarantineSecs: new BN(0),
+    quarantineGates: 0,
+    maxBatchNodes: 0,
+    emitMemos: false,
+    decimals: 0,
+    currencySymbol: [0, 0, 0, 0, 0, 0, 0, 0],
+    referralBps: 0,
+    referralDurationSecs: new BN(0),
+    spawnLicenseMint: PublicKey.default,
+    enforceSiblingShareCap: false,
+    minSnapshotIntervalSecs: new BN(0),
+    adjustmentCooldownSecs: new BN(0),
+    namespace: NAMESPACE,
+    oracleOnlyEarnings: false,
+    strictLineageChecks: false,
+    minDistributionIntervalSecs: new BN(0),
+  };
+}
+
 function getProgram(wallet: Keypair): Program {
   const provider = getProvider(wallet);
   return new Program(IDL, provider);
@@ -86,7 +129,7 @@ export async function initializeRegistry(
     const tx = await withRetry(
       () =>
         program.methods
-          .initialize()
+          .initialize(defaultInitializeParams())
           .accounts({ authority: authority.publicKey })
           .signers([authority])
           .rpc(),
@@ -132,8 +175,14 @@ export async function registerRootAgent(
     const tx = await withRetry(
       () =>
         program.methods
-          .registerRootAgent(name, specialization)
-          .accounts({ wallet, authority: authority.publicKey })
+          .registerRootAgent(NAMESPACE, name, specialization)
+          .accountsPartial({
+            agent: getAgentPda(wallet)[0],
+            wallet,
+            authority: authority.publicKey,
+            specCounter: getSpecCounterPda(specialization)[0],
+            nameReservation: getNameReservationPda(name)[0],
+          })
           .signers([authority])
           .rpc(),
       "registerRootAgent"
@@ -167,8 +216,24 @@ export async function spawnChildOnChain(
     const tx = await withRetry(
       () =>
         program.methods
-          .spawnChild(name, specialization, revShareBps)
-          .accounts({ parentWallet: parentWallet.publicKey, childWallet })
+          .spawnChild(
+            NAMESPACE,
+            name,
+            specialization,
+            revShareBps,
+            null,
+            0,
+            new BN(0),
+            new BN(0)
+          )
+          .accountsPartial({
+            parentAgent: getAgentPda(parentWallet.publicKey)[0],
+            parentWallet: parentWallet.publicKey,
+            childAgent: getAgentPda(childWallet)[0],
+            childWallet,
+            specCounter: getSpecCounterPda(specialization)[0],
+            nameReservation: getNameReservationPda(name)[0],
+          })
           .signers([parentWallet])
           .rpc(),
       "spawnChild"
@@ -183,10 +248,12 @@ export async function spawnChildOnChain(
   }
 }
 
-/** Record an earning on-chain */
+/** Record an earning on-chain; a zero nonce skips replay protection */
 export async function recordEarningOnChain(
   wallet: Keypair,
-  amount: BN
+  amount: BN,
+  nonce: BN = new BN(0),
+  category: object | null = null
 ): Promise<string | null> {
   try {
     const program = getProgram(wallet);
@@ -194,8 +261,11 @@ export async function recordEarningOnChain(
     const tx = await withRetry(
       () =>
         program.methods
-          .recordEarning(amount)
-          .accounts({ wallet: wallet.publicKey })
+          .recordEarning(NAMESPACE, amount, nonce, category)
+          .accountsPartial({
+            agent: getAgentPda(wallet.publicKey)[0],
+            wallet: wallet.publicKey,
+          })
           .signers([wallet])
           .rpc(),
       "recordEarning"
@@ -224,8 +294,13 @@ export async function distributeToParent(
     const tx = await withRetry(
       () =>
         program.methods
-          .distributeToParent(amount)
-          .accounts({ childWallet: childWallet.publicKey, parentWallet })
+          .distributeToParent(NAMESPACE, amount)
+          .accountsPartial({
+            childAgent: getAgentPda(childWallet.publicKey)[0],
+            parentAgent: getAgentPda(parentWallet)[0],
+            childWallet: childWallet.publicKey,
+            parentWallet,
+          })
           .signers([childWallet])
           .rpc(),
       "distributeToParent"
@@ -256,6 +331,19 @@ export async function fetchRegistry(): Promise<any | null> {
   }
 }
 
+/** Fetch the on-chain global stats (program-wide counters) */
+export async function fetchGlobalStats(): Promise<any | null> {
+  try {
+    const dummyKeypair = Keypair.generate();
+    const program = getProgram(dummyKeypair);
+    const [statsPda] = getGlobalStatsPda();
+    return await program.account.globalStats.fetch(statsPda);
+  } catch (err) {
+    console.error("[anchor] fetchGlobalStats failed:", (err as Error).message);
+    return null;
+  }
+}
+
 /** Fetch an on-chain agent account */
 export async function fetchAgentAccount(
   wallet: PublicKey
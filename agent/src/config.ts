@@ -1,4 +1,5 @@
 import { Keypair, PublicKey, Connection, clusterApiUrl } from "@solana/web3.js";
+import { createHash } from "crypto";
 import * as fs from "fs";
 import * as path from "path";
 
@@ -52,13 +53,56 @@ export function loadKeypair(filepath: string): Keypair {
   return Keypair.fromSecretKey(Uint8Array.from(JSON.parse(raw)));
 }
 
+// Registry namespace; the empty namespace is the program's original registry
+export const NAMESPACE = "";
+
 export function getRegistryPda(): [PublicKey, number] {
-  return PublicKey.findProgramAddressSync([Buffer.from("registry")], PROGRAM_ID);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("registry"), Buffer.from(NAMESPACE)],
+    PROGRAM_ID
+  );
+}
+
+export function getGlobalStatsPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("stats"), Buffer.from(NAMESPACE)],
+    PROGRAM_ID
+  );
+}
+
+// Agent PDAs take the namespace as its SHA-256 (empty for the default
+// namespace), so it can't run into the wallet seed that follows it
+function agentNamespaceSeed(): Buffer {
+  return NAMESPACE === ""
+    ? Buffer.alloc(0)
+    : createHash("sha256").update(NAMESPACE).digest();
 }
 
 export function getAgentPda(wallet: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("agent"), wallet.toBuffer()],
+    [Buffer.from("agent"), agentNamespaceSeed(), wallet.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function getSpecCounterPda(specialization: string): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("spec"),
+      createHash("sha256").update(specialization).digest(),
+      Buffer.from(NAMESPACE),
+    ],
+    PROGRAM_ID
+  );
+}
+
+export function getNameReservationPda(name: string): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("reservation"),
+      createHash("sha256").update(name).digest(),
+      Buffer.from(NAMESPACE),
+    ],
     PROGRAM_ID
   );
 }
@@ -16,6 +16,7 @@ import {
   initializeRegistry,
   registerRootAgent,
   fetchRegistry,
+  fetchGlobalStats,
   fetchAllAgentAccounts,
   recentTxSignatures,
 } from "./anchor-client.js";
@@ -352,18 +353,20 @@ app.get("/stats", (c) => {
 app.get("/on-chain", async (c) => {
   try {
     const registry = await fetchRegistry();
+    const stats = await fetchGlobalStats();
     const agents = await fetchAllAgentAccounts();
 
     return c.json({
       programId: PROGRAM_ID.toBase58(),
-      registry: registry
-        ? {
-            authority: registry.authority.toBase58(),
-            totalAgents: registry.totalAgents.toNumber(),
-            totalEarnings: registry.totalEarnings.toNumber() / LAMPORTS_PER_SOL,
-            totalSpawns: registry.totalSpawns.toNumber(),
-          }
-        : null,
+      registry:
+        registry && stats
+          ? {
+              authority: registry.authority.toBase58(),
+              totalAgents: stats.totalAgents.toNumber(),
+              totalEarnings: stats.totalReportedEarnings.toNumber() / LAMPORTS_PER_SOL,
+              totalSpawns: stats.totalSpawns.toNumber(),
+            }
+          : null,
       agents: agents.map((a: any) => ({
         pda: a.publicKey.toBase58(),
         wallet: a.account.wallet.toBase58(),
@@ -14,23 +14,25 @@ export type Hydra = {
   },
   "instructions": [
     {
-      "name": "deactivateAgent",
+      "name": "acceptAuthority",
       "docs": [
-        "Deactivate an agent."
+        "Accept a pending authority handoff (proposed key only), unless it is older",
+        "than the registry's `proposal_ttl_secs`."
       ],
       "discriminator": [
-        205,
-        171,
-        239,
-        225,
-        82,
-        126,
-        96,
-        166
+        107,
+        86,
+        198,
+        91,
+        33,
+        12,
+        107,
+        160
       ],
       "accounts": [
         {
           "name": "registry",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -45,139 +47,66 @@ export type Hydra = {
                   114,
                   121
                 ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "agent",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  97,
-                  103,
-                  101,
-                  110,
-                  116
-                ]
               },
               {
-                "kind": "account",
-                "path": "agent.wallet",
-                "account": "agentAccount"
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "signer": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "distributeToParent",
-      "docs": [
-        "Distribute SOL revenue from child to parent via system transfer."
-      ],
-      "discriminator": [
-        165,
-        17,
-        210,
-        207,
-        53,
-        28,
-        235,
-        57
-      ],
-      "accounts": [
-        {
-          "name": "childAgent",
+          "name": "stats",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  115,
+                  116,
                   97,
-                  103,
-                  101,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "childWallet"
-              }
-            ]
-          }
-        },
-        {
-          "name": "parentAgent",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  97,
-                  103,
-                  101,
-                  110,
-                  116
+                  116,
+                  115
                 ]
               },
               {
-                "kind": "account",
-                "path": "parentWallet"
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "childWallet",
-          "writable": true,
+          "name": "newAuthority",
           "signer": true
-        },
-        {
-          "name": "parentWallet",
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "namespace",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "initialize",
+      "name": "addOracle",
       "docs": [
-        "Initialize the Hydra registry. Called once."
+        "Approve `oracle` to call `record_earning_by_oracle`. Authority only."
       ],
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        185,
+        165,
+        165,
+        167,
+        208,
+        207,
+        55,
+        35
       ],
       "accounts": [
         {
           "name": "registry",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -192,112 +121,113 @@ export type Hydra = {
                   114,
                   121
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "recordEarning",
-      "docs": [
-        "Record earnings for an agent (called by agent's own wallet)."
-      ],
-      "discriminator": [
-        146,
-        64,
-        247,
-        159,
-        128,
-        80,
-        44,
-        56
-      ],
-      "accounts": [
-        {
-          "name": "registry",
+          "name": "stats",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  114,
-                  101,
-                  103,
-                  105,
                   115,
                   116,
-                  114,
-                  121
+                  97,
+                  116,
+                  115
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "agent",
+          "name": "oracleRecord",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  111,
+                  114,
                   97,
-                  103,
-                  101,
-                  110,
-                  116
+                  99,
+                  108,
+                  101
                 ]
               },
               {
-                "kind": "account",
-                "path": "wallet"
+                "kind": "arg",
+                "path": "oracle"
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "wallet",
-          "signer": true
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "oracle",
+          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "registerRootAgent",
+      "name": "adjustEarning",
       "docs": [
-        "Register the root agent (no parent). Only callable by registry authority."
+        "Correct an agent's `category` earnings by `delta`. Authority only, at",
+        "most once per `adjustment_cooldown_secs` per agent.",
+        "",
+        "The agent's total, category and current-epoch figures move together,",
+        "mirrored on the global stats, and the delta reaches the parent's subtree",
+        "total like a recorded earning would. A downward adjustment past zero",
+        "(overall or in `category`) fails rather than clamping; `attested_earned`",
+        "is lowered to stay within the new total. `parent_agent` is required to",
+        "take back earnings already rolled up to it."
       ],
       "discriminator": [
-        206,
-        102,
-        146,
-        202,
-        63,
-        197,
-        18,
-        58
+        176,
+        123,
+        92,
+        48,
+        226,
+        97,
+        154,
+        157
       ],
       "accounts": [
         {
           "name": "registry",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -312,78 +242,123 @@ export type Hydra = {
                   114,
                   121
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "agent",
+          "name": "stats",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  115,
+                  116,
                   97,
-                  103,
-                  101,
-                  110,
-                  116
+                  116,
+                  115
                 ]
               },
               {
-                "kind": "account",
-                "path": "wallet"
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "wallet"
+          "name": "agent",
+          "writable": true
         },
         {
           "name": "authority",
-          "writable": true,
           "signer": true,
           "relations": [
             "registry"
           ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "parentAgent",
+          "docs": [
+            "Direct parent; receives the delta in its subtree total"
+          ],
+          "writable": true,
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "name",
+          "name": "namespace",
           "type": "string"
         },
         {
-          "name": "specialization",
-          "type": "string"
+          "name": "delta",
+          "type": "i64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "earningCategory"
+            }
+          }
+        },
+        {
+          "name": "reasonCode",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "spawnChild",
+      "name": "agentExists",
       "docs": [
-        "Parent agent spawns a child agent."
+        "Read-only probe: whether `candidate` is a registered agent account.",
+        "Anchor returns the bool through `set_return_data`, so simulating is enough."
       ],
       "discriminator": [
-        57,
-        254,
-        127,
-        116,
+        37,
+        235,
+        16,
         244,
-        20,
-        212,
-        84
+        152,
+        27,
+        93,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "candidate"
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "approveAndPay",
+      "docs": [
+        "Approve a claimed bounty and release the escrow to the claimant's wallet,",
+        "recording it as a `TaskCompletion` earning of the claimant. Poster wallet",
+        "only; the bounty account is closed to the poster's wallet."
+      ],
+      "discriminator": [
+        17,
+        171,
+        252,
+        175,
+        75,
+        86,
+        76,
+        96
       ],
       "accounts": [
         {
           "name": "registry",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -398,231 +373,15443 @@ export type Hydra = {
                   114,
                   121
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "parentAgent",
+          "name": "stats",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  115,
+                  116,
                   97,
-                  103,
-                  101,
-                  110,
-                  116
+                  116,
+                  115
                 ]
               },
               {
-                "kind": "account",
-                "path": "parentWallet"
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "childAgent",
+          "name": "posterAgent",
+          "relations": [
+            "bounty"
+          ]
+        },
+        {
+          "name": "bounty",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  103,
-                  101,
+                  98,
+                  111,
+                  117,
                   110,
-                  116
+                  116,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "childWallet"
+                "path": "posterAgent"
+              },
+              {
+                "kind": "account",
+                "path": "bounty.bounty_id",
+                "account": "bounty"
               }
             ]
           }
         },
         {
-          "name": "parentWallet",
+          "name": "claimantAgent",
+          "writable": true
+        },
+        {
+          "name": "claimantWallet",
+          "writable": true
+        },
+        {
+          "name": "posterWallet",
           "writable": true,
           "signer": true
         },
         {
-          "name": "childWallet"
+          "name": "parentAgent",
+          "docs": [
+            "Claimant's direct parent; when supplied its subtree total is credited",
+            "immediately. Required for non-root claimants when the registry requires",
+            "an active lineage."
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "leaderboard",
+          "docs": [
+            "Updated with the claimant's new total when supplied"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
         }
       ],
       "args": [
         {
-          "name": "name",
+          "name": "namespace",
           "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "attestChildEarning",
+      "docs": [
+        "Parent vouches that its child has earned at least `attested_total`, raising",
+        "the child's `attested_earned` (never lowering it). `earning_nonce` must equal",
+        "the child's `last_earning_nonce`, pinning the attestation to the earnings the",
+        "parent reviewed; a total above `total_earned` is rejected."
+      ],
+      "discriminator": [
+        159,
+        121,
+        13,
+        184,
+        105,
+        255,
+        109,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
         },
         {
-          "name": "specialization",
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "parentAgent"
+        },
+        {
+          "name": "parentWallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
           "type": "string"
         },
         {
-          "name": "revenueShareBps",
-          "type": "u16"
+          "name": "attestedTotal",
+          "type": "u64"
+        },
+        {
+          "name": "earningNonce",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "agentAccount",
-      "discriminator": [
-        241,
-        119,
-        69,
-        140,
-        233,
-        9,
-        112,
-        50
-      ]
     },
     {
-      "name": "registry",
-      "discriminator": [
-        47,
-        174,
-        110,
-        246,
-        184,
-        182,
-        252,
-        218
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "agentDeactivated",
+      "name": "batchRecordEarnings",
+      "docs": [
+        "Record earnings for many agents at once (registry reporter only).",
+        "",
+        "`remaining_accounts` holds the agents in the same order as `amounts`. Each",
+        "earning is recorded as `Other` and left pending for `roll_up_earnings`."
+      ],
       "discriminator": [
-        138,
         251,
-        82,
-        87,
-        119,
-        148,
-        20,
-        180
-      ]
-    },
-    {
-      "name": "agentRegistered",
-      "discriminator": [
-        191,
-        78,
-        217,
-        54,
-        232,
-        100,
-        189,
-        85
-      ]
-    },
-    {
-      "name": "agentSpawned",
-      "discriminator": [
-        229,
+        179,
         125,
-        60,
-        41,
-        61,
-        109,
-        254,
-        34
-      ]
-    },
-    {
-      "name": "earningRecorded",
-      "discriminator": [
-        207,
-        235,
-        171,
-        216,
-        168,
-        79,
-        174,
-        197
+        23,
+        244,
+        45,
+        120,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporter",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amounts",
+          "type": {
+            "vec": "u64"
+          }
+        }
       ]
     },
     {
-      "name": "revenueDistributed",
+      "name": "cancelAuthorityProposal",
+      "docs": [
+        "Withdraw a pending authority handoff. Authority only."
+      ],
       "discriminator": [
-        78,
-        195,
-        188,
-        214,
-        203,
+        234,
+        52,
+        221,
+        94,
+        179,
+        175,
         219,
-        199,
-        87
+        114
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
       ]
-    }
-  ],
-  "errors": [
+    },
+    {
+      "name": "cancelBounty",
+      "docs": [
+        "Cancel an unclaimed bounty, refunding the escrow and closing the bounty",
+        "account to the poster's wallet."
+      ],
+      "discriminator": [
+        79,
+        65,
+        107,
+        143,
+        128,
+        165,
+        135,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "posterAgent",
+          "relations": [
+            "bounty"
+          ]
+        },
+        {
+          "name": "bounty",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  117,
+                  110,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "posterAgent"
+              },
+              {
+                "kind": "account",
+                "path": "bounty.bounty_id",
+                "account": "bounty"
+              }
+            ]
+          }
+        },
+        {
+          "name": "posterWallet",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "claimBounty",
+      "docs": [
+        "Claim an open bounty for the signer's agent (assignee only, if one is set)."
+      ],
+      "discriminator": [
+        225,
+        157,
+        163,
+        238,
+        239,
+        169,
+        75,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claimantAgent"
+        },
+        {
+          "name": "bounty",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  117,
+                  110,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bounty.poster_agent",
+                "account": "bounty"
+              },
+              {
+                "kind": "account",
+                "path": "bounty.bounty_id",
+                "account": "bounty"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claimantWallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "closeSpawnTemplate",
+      "docs": [
+        "Close a spawn template, refunding its rent to the parent wallet."
+      ],
+      "discriminator": [
+        76,
+        55,
+        152,
+        64,
+        94,
+        72,
+        79,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "parentAgent"
+        },
+        {
+          "name": "template",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  109,
+                  112,
+                  108,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "parentAgent"
+              },
+              {
+                "kind": "arg",
+                "path": "templateId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "templateId",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "closeWithForward",
+      "docs": [
+        "Close an agent, first moving all of its children under `new_parent`.",
+        "Signed by the closing agent's wallet and the new parent's wallet; rent goes",
+        "to the agent's recorded rent payer and a bond, if posted, back to the",
+        "wallet. The agent leaves its specialization count.",
+        "",
+        "`remaining_accounts` holds every child of the agent (exactly",
+        "`children_count` of them), followed by `new_parent`'s ancestors up to its",
+        "root, which must not include the agent (see `assert_not_descendant`). As",
+        "with `reparent_agent`, a child with children of its own must keep its depth.",
+        "`parent_agent` is required for a non-root agent unless `new_parent` is that",
+        "parent.",
+        "",
+        "The agent's and its children's pending earnings must be rolled up first.",
+        "The agent's branch leaves its parent's subtree total and each child's",
+        "branch is credited to `new_parent` (see `move_subtree`)."
+      ],
+      "discriminator": [
+        164,
+        54,
+        133,
+        218,
+        182,
+        44,
+        52,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "docs": [
+            "Also receives the agent's bond"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "parentAgent",
+          "docs": [
+            "The closing agent's own parent; omitted for roots and when it is `new_parent`"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "newParent",
+          "writable": true
+        },
+        {
+          "name": "newParentWallet",
+          "signer": true
+        },
+        {
+          "name": "rentDestination",
+          "writable": true
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "commitDistributionSchedule",
+      "docs": [
+        "Commit the signer's agent to distributing at least `min_amount` to its",
+        "parent every `interval_secs`. A single `distribute_to_parent` (or",
+        "`distribute_percentage`) of at least `min_amount` that supplies the",
+        "commitment account satisfies the current interval."
+      ],
+      "discriminator": [
+        41,
+        163,
+        144,
+        254,
+        36,
+        210,
+        68,
+        47
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent"
+        },
+        {
+          "name": "commitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minAmount",
+          "type": "u64"
+        },
+        {
+          "name": "intervalSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "createSpawnTemplate",
+      "docs": [
+        "Parent stores reusable child parameters at `[\"template\", parent_agent, template_id_le]`",
+        "for `spawn_from_template`, usable `max_uses` times."
+      ],
+      "discriminator": [
+        44,
+        7,
+        51,
+        46,
+        83,
+        147,
+        216,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent"
+        },
+        {
+          "name": "template",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  109,
+                  112,
+                  108,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "parentAgent"
+              },
+              {
+                "kind": "arg",
+                "path": "templateId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "templateId",
+          "type": "u32"
+        },
+        {
+          "name": "namePrefix",
+          "type": "string"
+        },
+        {
+          "name": "specialization",
+          "type": "string"
+        },
+        {
+          "name": "revenueShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "maxUses",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "deactivateAgent",
+      "docs": [
+        "Deactivate an agent. The agent wallet must wait out the registry's",
+        "`min_active_secs` after creation; the registry authority may deactivate any time."
+      ],
+      "discriminator": [
+        205,
+        171,
+        239,
+        225,
+        82,
+        126,
+        96,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Registry authority (deactivation for cause) or the agent's own wallet"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "distributePercentage",
+      "docs": [
+        "`distribute_to_parent` with the amount taken as `bps` of the child's",
+        "undistributed earnings (`total_earned - total_distributed_to_parent`),",
+        "rounded down. The rest stays with the child and is reported as `remainder`."
+      ],
+      "discriminator": [
+        218,
+        184,
+        29,
+        212,
+        25,
+        44,
+        85,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "childWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "parentWallet",
+          "docs": [
+            "(a keypair wallet or a vault PDA such as a Squads multisig vault)."
+          ],
+          "writable": true
+        },
+        {
+          "name": "grandparentAgent",
+          "docs": [
+            "Parent's parent; required when the parent forwards a share upstream"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "grandparentWallet",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "commitment",
+          "docs": [
+            "The child's distribution commitment; a large enough amount satisfies it"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "bps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "distributeSplit",
+      "docs": [
+        "Distribute SOL from the agent wallet across its configured split recipients.",
+        "Recipient accounts are passed in `remaining_accounts` in config order."
+      ],
+      "discriminator": [
+        98,
+        16,
+        104,
+        210,
+        106,
+        53,
+        245,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "splitConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeToChildren",
+      "docs": [
+        "Fund a parent's children from its wallet, splitting `amount` across the active",
+        "children in proportion to their `revenue_share_bps`.",
+        "",
+        "`remaining_accounts` holds `(child agent, child wallet)` pairs. Inactive",
+        "children are skipped and their weight spread over the rest; rounding dust",
+        "stays with the parent."
+      ],
+      "discriminator": [
+        152,
+        137,
+        102,
+        88,
+        186,
+        109,
+        249,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent"
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeToParent",
+      "docs": [
+        "Distribute SOL revenue from child to parent via system transfer.",
+        "",
+        "If the parent has a grandparent and a nonzero `forward_share_bps`, the",
+        "grandparent accounts must be supplied and the forward cut is sent to the",
+        "grandparent directly; the parent receives the remainder."
+      ],
+      "discriminator": [
+        165,
+        17,
+        210,
+        207,
+        53,
+        28,
+        235,
+        57
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "childWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "parentWallet",
+          "docs": [
+            "(a keypair wallet or a vault PDA such as a Squads multisig vault)."
+          ],
+          "writable": true
+        },
+        {
+          "name": "grandparentAgent",
+          "docs": [
+            "Parent's parent; required when the parent forwards a share upstream"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "grandparentWallet",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "commitment",
+          "docs": [
+            "The child's distribution commitment; a large enough amount satisfies it"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeToRoot",
+      "docs": [
+        "Send SOL from any agent's wallet straight to the root agent's wallet,",
+        "for flat revenue models that bypass intermediate parents."
+      ],
+      "discriminator": [
+        249,
+        144,
+        3,
+        73,
+        116,
+        128,
+        184,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "rootAgent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "rootWallet",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeToSecondary",
+      "docs": [
+        "Distribute SOL revenue from child to its secondary beneficiary agent."
+      ],
+      "discriminator": [
+        252,
+        129,
+        69,
+        11,
+        124,
+        26,
+        117,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "secondaryAgent"
+        },
+        {
+          "name": "childWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "secondaryWallet",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeUpChain",
+      "docs": [
+        "Cascade `amount` from a child's wallet up its whole ancestor chain.",
+        "",
+        "`remaining_accounts` holds `(agent, wallet)` pairs from the child's parent up",
+        "to the root. Each agent passes `revenue_share_bps` of what it receives to its",
+        "parent and keeps the rest. An inactive ancestor's kept portion rolls up to",
+        "the nearest active ancestor above it; if none exists it stays with the child."
+      ],
+      "discriminator": [
+        164,
+        41,
+        179,
+        237,
+        94,
+        157,
+        201,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "childWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeWithParentOverride",
+      "docs": [
+        "`distribute_to_parent` co-signed by the parent's wallet, which bypasses",
+        "the child's `max_single_distribution` and `max_daily_distribution` caps.",
+        "The amount still counts toward the day's total."
+      ],
+      "discriminator": [
+        167,
+        73,
+        144,
+        114,
+        249,
+        45,
+        15,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "childWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "parentWallet",
+          "docs": [
+            "(a keypair wallet or a vault PDA such as a Squads multisig vault)."
+          ],
+          "writable": true
+        },
+        {
+          "name": "grandparentAgent",
+          "docs": [
+            "Parent's parent; required when the parent forwards a share upstream"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "grandparentWallet",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "commitment",
+          "docs": [
+            "The child's distribution commitment; a large enough amount satisfies it"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "expireAgent",
+      "docs": [
+        "Deactivate an agent past its `expires_at`, making the expiry explicit",
+        "in account state. Permissionless."
+      ],
+      "discriminator": [
+        129,
+        253,
+        146,
+        228,
+        250,
+        206,
+        165,
+        6
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "extendAgentTtl",
+      "docs": [
+        "Push out a child's expiry (parent wallet only). `new_expires_at` must be",
+        "later than the current expiry, or 0 to remove it."
+      ],
+      "discriminator": [
+        131,
+        232,
+        228,
+        73,
+        32,
+        82,
+        204,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "parentAgent"
+        },
+        {
+          "name": "parentWallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "newExpiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "finalizeAgent",
+      "docs": [
+        "Permanently freeze the agent's profile URI, tag, forward share, split config",
+        "and withdrawal whitelist (agent wallet only). Cannot be undone."
+      ],
+      "discriminator": [
+        160,
+        42,
+        210,
+        130,
+        61,
+        38,
+        160,
+        72
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "flagMissedDistribution",
+      "docs": [
+        "Record a missed interval on a distribution commitment. Permissionless;",
+        "fails with `CommitmentNotDue` until a full interval has passed since the",
+        "last qualifying distribution or the last flag, whichever is later."
+      ],
+      "discriminator": [
+        239,
+        33,
+        27,
+        233,
+        67,
+        188,
+        4,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "commitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "commitment.agent",
+                "account": "distributionCommitment"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "freezeAgent",
+      "docs": [
+        "Archive an agent: no instruction may modify it afterwards, including",
+        "counter updates caused by its children, so nothing can be spawned under it.",
+        "The registry authority or the agent's wallet; cannot be undone."
+      ],
+      "discriminator": [
+        142,
+        40,
+        239,
+        10,
+        18,
+        216,
+        106,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Registry authority or the agent's own wallet"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "getAgentSummary",
+      "docs": [
+        "View: summary of an agent's state, returned via `set_return_data`."
+      ],
+      "discriminator": [
+        103,
+        68,
+        90,
+        115,
+        176,
+        114,
+        52,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "agent"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "agentSummary"
+        }
+      }
+    },
+    {
+      "name": "getLineageDepth",
+      "docs": [
+        "View: an agent's depth against the registry's max depth, returned via `set_return_data`."
+      ],
+      "discriminator": [
+        183,
+        82,
+        205,
+        63,
+        148,
+        135,
+        190,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "lineageDepth"
+        }
+      }
+    },
+    {
+      "name": "getProjectedSplit",
+      "docs": [
+        "View: how `amount` would be divided across the agent's split recipients,",
+        "returned via `set_return_data`. No lamports move."
+      ],
+      "discriminator": [
+        32,
+        158,
+        79,
+        59,
+        212,
+        129,
+        31,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "splitConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "split_config.agent",
+                "account": "splitConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "projectedSplit"
+        }
+      }
+    },
+    {
+      "name": "getRegistryStats",
+      "docs": [
+        "View: registry-wide totals and currency metadata, returned via `set_return_data`."
+      ],
+      "discriminator": [
+        166,
+        159,
+        139,
+        121,
+        81,
+        225,
+        17,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "registryStats"
+        }
+      }
+    },
+    {
+      "name": "heartbeat",
+      "docs": [
+        "Record a liveness heartbeat, clearing any stale flag (agent wallet only)."
+      ],
+      "discriminator": [
+        202,
+        104,
+        56,
+        6,
+        240,
+        170,
+        63,
+        134
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "docs": [
+        "Initialize the Hydra registry with its full configuration. Called once.",
+        "A `max_depth` of 0 selects the default `MAX_DEPTH`; other zero values",
+        "leave the corresponding feature disabled."
+      ],
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "initializeParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeDefault",
+      "docs": [
+        "Initialize the registry with the default configuration."
+      ],
+      "discriminator": [
+        245,
+        26,
+        138,
+        7,
+        110,
+        249,
+        119,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeLeaderboard",
+      "docs": [
+        "Create the registry-wide top earners leaderboard. Authority only."
+      ],
+      "discriminator": [
+        47,
+        23,
+        34,
+        39,
+        46,
+        108,
+        91,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "isAncestor",
+      "docs": [
+        "View: whether `ancestor` is a strict ancestor of `descendant`, and how many",
+        "hops separate them, returned via `set_return_data`.",
+        "",
+        "`remaining_accounts` holds the agents between them, from the descendant's",
+        "parent upward (or up to the root when there is no ancestry). Reaching the",
+        "root yields `false`; a chain that breaks or runs out is `InvalidChain`."
+      ],
+      "discriminator": [
+        173,
+        28,
+        113,
+        68,
+        228,
+        118,
+        160,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "ancestor"
+        },
+        {
+          "name": "descendant"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "ancestry"
+        }
+      }
+    },
+    {
+      "name": "listChildren",
+      "docs": [
+        "View: wallets of the active children of `parent` among the candidate",
+        "agents in `remaining_accounts`, returned via `set_return_data`.",
+        "",
+        "Candidates that aren't agent accounts, belong to another parent, are",
+        "inactive, or repeat an earlier candidate are skipped, so a client can check",
+        "a claimed child set against `children_count` in one simulation."
+      ],
+      "discriminator": [
+        154,
+        149,
+        58,
+        43,
+        252,
+        142,
+        231,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "parent"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "pubkey"
+      }
+    },
+    {
+      "name": "lockAgent",
+      "docs": [
+        "Lock the agent until `until_ts`, blocking deactivation, bond withdrawal and",
+        "reparenting meanwhile (agent wallet only). A lock can be extended but not shortened."
+      ],
+      "discriminator": [
+        100,
+        216,
+        180,
+        95,
+        45,
+        189,
+        23,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "untilTs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "markStale",
+      "docs": [
+        "Flag an agent whose last activity is older than the registry stale",
+        "threshold. The flag clears on the agent's next activity. Permissionless."
+      ],
+      "discriminator": [
+        173,
+        114,
+        248,
+        127,
+        229,
+        16,
+        189,
+        64
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "matchesSpecPrefix",
+      "docs": [
+        "View: which agents' `specialization` starts with `prefix`, as a bitmask",
+        "returned via `set_return_data`. Bit 0 is `agent`; bit `i + 1` is",
+        "`remaining_accounts[i]`, left clear for accounts that aren't agents. With no",
+        "remaining accounts the result is 0 or 1, usable as a bool."
+      ],
+      "discriminator": [
+        250,
+        199,
+        167,
+        159,
+        207,
+        145,
+        46,
+        138
+      ],
+      "accounts": [
+        {
+          "name": "agent"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "mergeAgents",
+      "docs": [
+        "Fold a duplicate leaf agent into a sibling leaf and close it. Authority only.",
+        "",
+        "The source's earnings and distribution totals move to `target` (see",
+        "`AgentAccount::absorb`), as do any lamports its PDA holds above rent",
+        "exemption. Rent goes to the source's recorded rent payer; a bond, if",
+        "posted, is closed to the source's wallet. The source leaves its",
+        "specialization count and, when `leaderboard` is supplied, the board.",
+        "`parent_agent` is required unless both agents are roots."
+      ],
+      "discriminator": [
+        134,
+        248,
+        7,
+        81,
+        42,
+        244,
+        57,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "source",
+          "writable": true
+        },
+        {
+          "name": "target",
+          "writable": true
+        },
+        {
+          "name": "parentAgent",
+          "docs": [
+            "The agents' shared parent; omitted when both are roots"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "rentDestination",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "source"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sourceWallet",
+          "writable": true
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        },
+        {
+          "name": "leaderboard",
+          "docs": [
+            "Loses the source's entry when supplied"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "migrateAgent",
+      "docs": [
+        "Grow an agent account to the current `AgentAccount::SIZE` and bring it to",
+        "`AGENT_SCHEMA_VERSION`. New fields are zero-initialized; the payer covers",
+        "any extra rent. No-op for accounts already on the current schema."
+      ],
+      "discriminator": [
+        102,
+        150,
+        249,
+        223,
+        92,
+        169,
+        131,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateRegistry",
+      "docs": [
+        "Grow the registry to the current `Registry::SIZE`. Appended fields are",
+        "zero-initialized; the payer covers any extra rent. No-op when already current."
+      ],
+      "discriminator": [
+        216,
+        135,
+        36,
+        181,
+        124,
+        116,
+        222,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "payAgentWithSplit",
+      "docs": [
+        "An external payer pays an agent, with the parent's `revenue_share_bps` cut",
+        "sent straight from the payer to the parent's wallet and the remainder to the",
+        "child's wallet, so the child never holds the parent's portion. Root agents",
+        "(no parent accounts) receive the full amount."
+      ],
+      "discriminator": [
+        69,
+        56,
+        135,
+        248,
+        182,
+        158,
+        152,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "childWallet",
+          "writable": true
+        },
+        {
+          "name": "parentAgent",
+          "docs": [
+            "Required unless the child is a root agent"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "referral",
+          "docs": [
+            "The payer's referral record; pays the referring agent while unexpired"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referringAgent",
+          "docs": [
+            "Required with an active referral"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referringWallet",
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "postBounty",
+      "docs": [
+        "Escrow `amount` lamports in a new bounty posted by the signer's agent.",
+        "An `assignee` agent restricts who may claim; `None` leaves it open."
+      ],
+      "discriminator": [
+        40,
+        217,
+        222,
+        103,
+        151,
+        83,
+        147,
+        130
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "posterAgent"
+        },
+        {
+          "name": "bounty",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  117,
+                  110,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "posterAgent"
+              },
+              {
+                "kind": "arg",
+                "path": "bountyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "posterWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "bountyId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "assignee",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "descriptionHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "probeSpawnAllowed",
+      "docs": [
+        "View: whether `agent` could spawn a child right now, returned via",
+        "`set_return_data`. A rejection carries the `HydraError` code `spawn_child`",
+        "would fail with (e.g. `MaxDepthReached`, `ParentInactive`, `TreeNotBalanced`)."
+      ],
+      "discriminator": [
+        116,
+        230,
+        89,
+        210,
+        217,
+        23,
+        35,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "spawnProbe"
+        }
+      }
+    },
+    {
+      "name": "promoteToRoot",
+      "docs": [
+        "Detach an agent from its parent and make it a root. Authority only; the",
+        "parent must be inactive unless `force` is set.",
+        "",
+        "The agent's pending earnings must be rolled up first (`roll_up_earnings`);",
+        "the departing branch is then taken out of the old parent's subtree total",
+        "(see `detach_subtree`). The agent's descendants keep their old depths",
+        "until healed one at a time with `recompute_depth`."
+      ],
+      "discriminator": [
+        188,
+        151,
+        55,
+        21,
+        95,
+        10,
+        198,
+        82
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "oldParent",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "force",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "docs": [
+        "Propose `new_authority` as the next registry authority. Takes effect only once",
+        "the proposed key calls `accept_authority`. Authority only."
+      ],
+      "discriminator": [
+        20,
+        148,
+        236,
+        198,
+        76,
+        119,
+        99,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "pruneInactive",
+      "docs": [
+        "Close deactivated leaf agents past the registry grace period. Authority only.",
+        "",
+        "`remaining_accounts` holds `(agent, rent_destination, parent_agent, spec_counter,",
+        "bond, wallet)` groups; rent is returned to the agent's recorded rent payer",
+        "(see `AgentAccount::rent_refund_destination`) and the agent leaves its",
+        "specialization count. A posted bond is closed to the agent's wallet, so",
+        "slash it before pruning if the agent was deactivated for cause. Root agents",
+        "pass any account as the parent slot. Agents that don't qualify are skipped",
+        "rather than failing the sweep."
+      ],
+      "discriminator": [
+        250,
+        27,
+        177,
+        66,
+        35,
+        224,
+        245,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "reactivateAgent",
+      "docs": [
+        "Reactivate a deactivated agent, starting the registry's reactivation",
+        "quarantine. The registry authority may reactivate any agent; an agent's",
+        "wallet only one it deactivated itself."
+      ],
+      "discriminator": [
+        231,
+        7,
+        179,
+        97,
+        210,
+        24,
+        209,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Registry authority or the agent's own wallet"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "recomputeDepth",
+      "docs": [
+        "Set an agent's depth to one below its parent's, healing subtrees after",
+        "`promote_to_root`. Permissionless."
+      ],
+      "discriminator": [
+        64,
+        58,
+        2,
+        18,
+        59,
+        17,
+        175,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "parentAgent"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "reconcileChildrenCount",
+      "docs": [
+        "Overwrite a parent's `children_count` and `children_share_sum` from the",
+        "supplied agents whose `parent` is that agent. Authority only.",
+        "",
+        "`remaining_accounts` holds all of the parent's direct children; agents",
+        "belonging to another parent are ignored."
+      ],
+      "discriminator": [
+        219,
+        153,
+        228,
+        170,
+        179,
+        118,
+        136,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "reconcileReport",
+      "docs": [
+        "Emit self-reported earnings against lamports actually moved through the",
+        "program. Permissionless."
+      ],
+      "discriminator": [
+        155,
+        63,
+        220,
+        95,
+        4,
+        58,
+        253,
+        181
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "recordEarning",
+      "docs": [
+        "Record earnings for an agent (called by agent's own wallet). Fails with",
+        "`OracleRequired` while the registry is in oracle-only mode.",
+        "",
+        "A nonzero `nonce` must be strictly greater than the agent's last one, so",
+        "client retries of an already-landed earning are rejected. Zero skips the check.",
+        "`category` tags the earning's source; `None` records it as `Other`."
+      ],
+      "discriminator": [
+        146,
+        64,
+        247,
+        159,
+        128,
+        80,
+        44,
+        56
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        },
+        {
+          "name": "parentAgent",
+          "docs": [
+            "Direct parent; when supplied its subtree total is credited immediately.",
+            "Required for non-root agents when the registry requires an active lineage."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "leaderboard",
+          "docs": [
+            "Updated with the agent's new total when supplied"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "earningCategory"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordEarningByOracle",
+      "docs": [
+        "Record earnings for an agent on behalf of its wallet, signed by an oracle",
+        "approved with `add_oracle`. Otherwise identical to `record_earning`,",
+        "including nonce handling and the counters it updates."
+      ],
+      "discriminator": [
+        208,
+        127,
+        6,
+        213,
+        54,
+        144,
+        253,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "oracle",
+          "signer": true
+        },
+        {
+          "name": "oracleRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle"
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent",
+          "docs": [
+            "Direct parent; when supplied its subtree total is credited immediately.",
+            "Required for non-root agents when the registry requires an active lineage."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "leaderboard",
+          "docs": [
+            "Updated with the agent's new total when supplied"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "earningCategory"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordEarningsBulk",
+      "docs": [
+        "Record up to `MAX_BULK_EARNINGS` earnings for one agent in a single",
+        "counter update (agent wallet only), for agents finishing many small tasks.",
+        "",
+        "Each amount must be nonzero and within `max_single_earning`; their sum is",
+        "credited as one `category` earning. `task_hashes` is either empty or holds",
+        "one hash per amount; hashes are then rejected if repeated within the batch",
+        "or found in the agent's `TaskLog`, which must be supplied."
+      ],
+      "discriminator": [
+        9,
+        146,
+        60,
+        247,
+        168,
+        218,
+        47,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "parentAgent",
+          "docs": [
+            "Direct parent; when supplied its subtree total is credited immediately.",
+            "Required for non-root agents when the registry requires an active lineage."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "leaderboard",
+          "docs": [
+            "Updated with the agent's new total when supplied"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "taskLog",
+          "docs": [
+            "Required when `task_hashes` is non-empty; created on first use"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  115,
+                  107,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amounts",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "taskHashes",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "category",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "earningCategory"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordTokenEarning",
+      "docs": [
+        "Record earnings denominated in an SPL token mint (agent wallet only).",
+        "Tracked per `(agent, mint)` in a `TokenEarnings` account, separately from SOL."
+      ],
+      "discriminator": [
+        13,
+        89,
+        123,
+        115,
+        17,
+        46,
+        91,
+        118
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent"
+        },
+        {
+          "name": "tokenEarnings",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  101,
+                  97,
+                  114,
+                  110,
+                  105,
+                  110,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "registerAgentIndexed",
+      "docs": [
+        "Parent agent spawns a child at the indexed PDA `[\"agent\", child_wallet, agent_index_le]`,",
+        "letting one wallet operate several agents. `agent_index` must be the wallet's",
+        "next index from its `WalletAgentCounter`, so indices never collide or leave gaps."
+      ],
+      "discriminator": [
+        155,
+        1,
+        45,
+        24,
+        66,
+        164,
+        23,
+        33
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "walletAgentCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childWallet"
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "Required when the registry demands a bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "childWallet"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "spawnLicense",
+          "docs": [
+            "when the registry gates spawning, parsed in `check_spawn_license`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "nameReservation",
+          "docs": [
+            "`check_name_reservation`"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "agentIndex",
+          "type": "u32"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "specialization",
+          "type": "string"
+        },
+        {
+          "name": "revenueShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "registerReferral",
+      "docs": [
+        "Record the agent that introduced the signing payer. One referral per payer;",
+        "it lapses after the registry's `referral_duration_secs`."
+      ],
+      "discriminator": [
+        158,
+        196,
+        134,
+        102,
+        193,
+        102,
+        184,
+        86
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referral",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referringAgent"
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "registerRootAgent",
+      "docs": [
+        "Register the root agent (no parent). Only callable by registry authority."
+      ],
+      "discriminator": [
+        206,
+        102,
+        146,
+        202,
+        63,
+        197,
+        18,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "Required when the registry demands a bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        },
+        {
+          "name": "wallet"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "nameReservation",
+          "docs": [
+            "`check_name_reservation`"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "specialization",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "registerRootAgentV2",
+      "docs": [
+        "`register_root_agent` with its arguments in a single struct."
+      ],
+      "discriminator": [
+        214,
+        150,
+        112,
+        94,
+        45,
+        3,
+        121,
+        51
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "Required when the registry demands a bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        },
+        {
+          "name": "wallet"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "nameReservation",
+          "docs": [
+            "`check_name_reservation`"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "registerRootAgentArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "rejectClaim",
+      "docs": [
+        "Send a claimed bounty back to `Open`, clearing the claimant, when the",
+        "poster will not approve the work. Poster wallet only."
+      ],
+      "discriminator": [
+        238,
+        185,
+        227,
+        8,
+        51,
+        188,
+        35,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "posterAgent",
+          "relations": [
+            "bounty"
+          ]
+        },
+        {
+          "name": "bounty",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  117,
+                  110,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "posterAgent"
+              },
+              {
+                "kind": "account",
+                "path": "bounty.bounty_id",
+                "account": "bounty"
+              }
+            ]
+          }
+        },
+        {
+          "name": "posterWallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "releaseReservation",
+      "docs": [
+        "Close a name reservation, refunding its rent to the reserver. The",
+        "reserver may release at any time; anyone may once it has expired."
+      ],
+      "discriminator": [
+        57,
+        15,
+        101,
+        135,
+        113,
+        21,
+        168,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nameReservation",
+          "writable": true
+        },
+        {
+          "name": "reserver",
+          "writable": true,
+          "relations": [
+            "nameReservation"
+          ]
+        },
+        {
+          "name": "signer",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "removeOracle",
+      "docs": [
+        "Revoke an oracle by closing its record; it can no longer record earnings",
+        "from the next transaction on. Authority only."
+      ],
+      "discriminator": [
+        60,
+        93,
+        51,
+        197,
+        182,
+        42,
+        170,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "oracle"
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "oracle",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "reparentAgent",
+      "docs": [
+        "Move a child under a new parent. Both parents' wallets sign, plus the child's",
+        "wallet when the registry requires it.",
+        "",
+        "`remaining_accounts` holds the new parent's ancestors up to its root, which",
+        "must not include the child (see `assert_not_descendant`). An agent with children keeps",
+        "its depth, so the new parent must sit at the old parent's depth; otherwise",
+        "its descendants' stored depths would go stale. The child's pending earnings",
+        "must be rolled up first; its branch then moves between the parents' subtree",
+        "totals (see `move_subtree`)."
+      ],
+      "discriminator": [
+        160,
+        132,
+        24,
+        161,
+        72,
+        202,
+        186,
+        209
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "oldParent",
+          "writable": true
+        },
+        {
+          "name": "newParent",
+          "writable": true
+        },
+        {
+          "name": "oldParentWallet",
+          "signer": true
+        },
+        {
+          "name": "newParentWallet",
+          "signer": true
+        },
+        {
+          "name": "childWallet",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "reportPosition",
+      "docs": [
+        "Emit an agent's current net position. Permissionless."
+      ],
+      "discriminator": [
+        245,
+        131,
+        147,
+        204,
+        25,
+        170,
+        123,
+        192
+      ],
+      "accounts": [
+        {
+          "name": "agent"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reportWaterfall",
+      "docs": [
+        "Dry-run of a multi-hop distribution of `amount` from a leaf up to its root.",
+        "",
+        "`remaining_accounts` holds the agent chain from leaf to root. Each agent",
+        "passes `revenue_share_bps` of what it receives to its parent. Emits a",
+        "`WaterfallReport` with what each ancestor would keep; no lamports move."
+      ],
+      "discriminator": [
+        89,
+        228,
+        110,
+        180,
+        250,
+        23,
+        27,
+        239
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "reserveName",
+      "docs": [
+        "Reserve `name` for the signer for `NAME_RESERVATION_SECS`. Until it",
+        "expires, `register_root_agent` and `spawn_child` only accept the name when",
+        "the reserver is the signer or the new agent's wallet."
+      ],
+      "discriminator": [
+        145,
+        109,
+        167,
+        240,
+        235,
+        132,
+        136,
+        36
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nameReservation",
+          "writable": true
+        },
+        {
+          "name": "reserver",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "revokeDistributionCommitment",
+      "docs": [
+        "Revoke a distribution commitment, refunding its rent to the child wallet.",
+        "The parent's wallet must co-sign."
+      ],
+      "discriminator": [
+        79,
+        111,
+        162,
+        155,
+        166,
+        133,
+        199,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent"
+        },
+        {
+          "name": "parentAgent"
+        },
+        {
+          "name": "commitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "parentWallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "rollUpEarnings",
+      "docs": [
+        "Move an agent's pending subtree earnings one level up to its parent. Permissionless."
+      ],
+      "discriminator": [
+        153,
+        8,
+        180,
+        35,
+        83,
+        154,
+        7,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "setAdjustmentCooldown",
+      "docs": [
+        "Set the minimum seconds between `adjust_earning` calls on the same agent",
+        "(0 = no limit). Authority only."
+      ],
+      "discriminator": [
+        73,
+        29,
+        174,
+        228,
+        132,
+        107,
+        108,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "adjustmentCooldownSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setAgentTag",
+      "docs": [
+        "Set the agent's free-form tag (e.g. \"prod\") for frontend filtering.",
+        "Signed by the agent wallet."
+      ],
+      "discriminator": [
+        188,
+        190,
+        241,
+        16,
+        9,
+        107,
+        58,
+        143
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "tag",
+          "type": {
+            "array": [
+              "u8",
+              16
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAllowCpi",
+      "docs": [
+        "Toggle whether protected instructions (`record_earning`, `spawn_child`,",
+        "`distribute_to_parent`) may be invoked via CPI. Authority only."
+      ],
+      "discriminator": [
+        117,
+        239,
+        63,
+        100,
+        203,
+        190,
+        236,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "allowCpi",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setBondConfig",
+      "docs": [
+        "Configure the SOL bond posted by new agents and the withdrawal cooldown. Authority only.",
+        "A required bond of 0 disables bonding for new agents."
+      ],
+      "discriminator": [
+        165,
+        131,
+        62,
+        211,
+        241,
+        154,
+        137,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "requiredBondLamports",
+          "type": "u64"
+        },
+        {
+          "name": "cooldownSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setCompactEvents",
+      "docs": [
+        "Toggle compact creation events that omit name/specialization strings. Authority only."
+      ],
+      "discriminator": [
+        199,
+        21,
+        44,
+        227,
+        198,
+        128,
+        155,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "compactEvents",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setCurrencyMetadata",
+      "docs": [
+        "Set the display metadata for amounts tracked by the registry. Authority only."
+      ],
+      "discriminator": [
+        74,
+        254,
+        124,
+        91,
+        121,
+        10,
+        103,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        },
+        {
+          "name": "currencySymbol",
+          "type": {
+            "array": [
+              "u8",
+              8
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDistributionLimits",
+      "docs": [
+        "Cap what this agent's wallet can send out per distribution and per UTC day",
+        "(agent wallet only). Zero means unlimited. The caps cover every",
+        "distribution the wallet signs; only `distribute_with_parent_override`",
+        "bypasses them."
+      ],
+      "discriminator": [
+        242,
+        244,
+        221,
+        40,
+        84,
+        251,
+        231,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxSingleDistribution",
+          "type": "u64"
+        },
+        {
+          "name": "maxDailyDistribution",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setEarningGoal",
+      "docs": [
+        "Set the agent's earning goal (0 = none) and re-arm `GoalReached` (agent wallet only)."
+      ],
+      "discriminator": [
+        219,
+        223,
+        241,
+        163,
+        167,
+        126,
+        236,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "earningGoal",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setEmitMemos",
+      "docs": [
+        "Toggle SPL Memo CPIs on earnings and distributions. Authority only."
+      ],
+      "discriminator": [
+        209,
+        153,
+        68,
+        21,
+        255,
+        83,
+        219,
+        63
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "emitMemos",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setEnforceSiblingShareCap",
+      "docs": [
+        "Cap the summed `revenue_share_bps` of a parent's children at 10000.",
+        "Authority only."
+      ],
+      "discriminator": [
+        100,
+        187,
+        37,
+        156,
+        9,
+        54,
+        44,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "enforce",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setEnforceWithdrawWhitelist",
+      "docs": [
+        "Toggle enforcement of per-agent withdrawal whitelists. Authority only."
+      ],
+      "discriminator": [
+        101,
+        139,
+        105,
+        107,
+        89,
+        137,
+        18,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "enforce",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setForwardShare",
+      "docs": [
+        "Set the share of incoming child revenue forwarded to this agent's parent."
+      ],
+      "discriminator": [
+        93,
+        20,
+        186,
+        112,
+        171,
+        172,
+        25,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "forwardShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setMaxBatchNodes",
+      "docs": [
+        "Set the cap on `remaining_accounts` for traversal instructions (0 = only",
+        "the per-instruction limits apply). Authority only."
+      ],
+      "discriminator": [
+        75,
+        160,
+        47,
+        123,
+        38,
+        247,
+        236,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxBatchNodes",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setMaxDepth",
+      "docs": [
+        "Change the registry's max tree depth. Authority only.",
+        "",
+        "Lowering the limit is allowed: it is only enforced when spawning, so",
+        "existing agents deeper than the new limit keep operating but cannot spawn."
+      ],
+      "discriminator": [
+        116,
+        71,
+        154,
+        146,
+        40,
+        182,
+        210,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxDepth",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setMaxSingleEarning",
+      "docs": [
+        "Cap the amount a single `record_earning` may report (0 = unlimited). Authority only."
+      ],
+      "discriminator": [
+        198,
+        137,
+        65,
+        250,
+        173,
+        199,
+        132,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxSingleEarning",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMinActiveSecs",
+      "docs": [
+        "Set how long an agent must exist before its own wallet may deactivate it",
+        "(0 = no minimum). Authority only."
+      ],
+      "discriminator": [
+        181,
+        107,
+        104,
+        0,
+        19,
+        100,
+        216,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minActiveSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setMinDistributionInterval",
+      "docs": [
+        "Set the minimum seconds between `distribute_to_parent` calls by the same",
+        "agent (0 = no limit). Authority only."
+      ],
+      "discriminator": [
+        119,
+        81,
+        243,
+        85,
+        65,
+        22,
+        36,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minDistributionIntervalSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setMinSnapshotInterval",
+      "docs": [
+        "Set the minimum seconds between `take_snapshot` calls (0 = no limit).",
+        "Authority only."
+      ],
+      "discriminator": [
+        85,
+        165,
+        3,
+        36,
+        124,
+        79,
+        117,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minSnapshotIntervalSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setOracleOnlyEarnings",
+      "docs": [
+        "Toggle whether earnings may only be recorded by approved oracles, which",
+        "disables the self-reported `record_earning` path. Authority only."
+      ],
+      "discriminator": [
+        37,
+        62,
+        247,
+        4,
+        216,
+        112,
+        96,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "oracleOnly",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setProposalTtl",
+      "docs": [
+        "Set how long an authority proposal stays acceptable (0 = no expiry). Authority only."
+      ],
+      "discriminator": [
+        25,
+        115,
+        16,
+        126,
+        22,
+        58,
+        111,
+        183
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "ttlSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setPruneGraceSecs",
+      "docs": [
+        "Set how long a deactivated agent must wait before it can be pruned. Authority only."
+      ],
+      "discriminator": [
+        16,
+        115,
+        63,
+        149,
+        39,
+        5,
+        89,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "graceSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setReactivationQuarantine",
+      "docs": [
+        "Set the quarantine applied after `reactivate_agent` and which actions it",
+        "blocks (`QUARANTINE_*` bits). Authority only."
+      ],
+      "discriminator": [
+        94,
+        106,
+        21,
+        49,
+        147,
+        161,
+        180,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reactivationQuarantineSecs",
+          "type": "i64"
+        },
+        {
+          "name": "quarantineGates",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setReferralConfig",
+      "docs": [
+        "Set the share of `pay_agent_with_split` payments paid to a payer's referring",
+        "agent, and how long a referral lasts (0 = forever). Authority only. A",
+        "referred payment to a child whose `revenue_share_bps` plus `referral_bps`",
+        "exceeds 10_000 fails with `InvalidRevenueShare`."
+      ],
+      "discriminator": [
+        114,
+        36,
+        32,
+        209,
+        207,
+        21,
+        21,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "referralBps",
+          "type": "u16"
+        },
+        {
+          "name": "referralDurationSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setReparentRequiresChild",
+      "docs": [
+        "Toggle whether `reparent_agent` also needs the child wallet's signature. Authority only."
+      ],
+      "discriminator": [
+        114,
+        163,
+        186,
+        158,
+        157,
+        114,
+        244,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reparentRequiresChild",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setReporter",
+      "docs": [
+        "Set the trusted reporter allowed to call `batch_record_earnings`",
+        "(`Pubkey::default()` disables batching). Authority only."
+      ],
+      "discriminator": [
+        85,
+        106,
+        154,
+        18,
+        252,
+        50,
+        144,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reporter",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setRequireActiveLineage",
+      "docs": [
+        "Toggle blocking earnings and distributions for agents whose direct parent",
+        "is deactivated. Authority only."
+      ],
+      "discriminator": [
+        7,
+        22,
+        220,
+        195,
+        222,
+        140,
+        150,
+        126
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "requireActiveLineage",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setSpawnLicenseMint",
+      "docs": [
+        "Require spawning parents to hold a token of `spawn_license_mint`",
+        "(`Pubkey::default()` disables the gate). Authority only."
+      ],
+      "discriminator": [
+        145,
+        229,
+        209,
+        232,
+        44,
+        232,
+        236,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "spawnLicenseMint",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setSplitConfig",
+      "docs": [
+        "Configure custom revenue-split recipients for an agent (agent wallet only)."
+      ],
+      "discriminator": [
+        157,
+        183,
+        178,
+        245,
+        241,
+        5,
+        199,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent"
+        },
+        {
+          "name": "splitConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "splitRecipient"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setStaleThreshold",
+      "docs": [
+        "Set how long an agent may go without activity before `mark_stale`",
+        "can flag it. Authority only."
+      ],
+      "discriminator": [
+        54,
+        7,
+        246,
+        49,
+        43,
+        54,
+        132,
+        18
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "thresholdSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setStrictLineageChecks",
+      "docs": [
+        "Toggle checking `spawn_child` wallets against supplied ancestors.",
+        "Authority only."
+      ],
+      "discriminator": [
+        122,
+        178,
+        189,
+        22,
+        65,
+        238,
+        51,
+        168
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "strict",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setStrictNames",
+      "docs": [
+        "Toggle strict (URL-safe) agent name validation. Authority only."
+      ],
+      "discriminator": [
+        188,
+        104,
+        248,
+        179,
+        226,
+        235,
+        118,
+        215
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "strictNames",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTreeBalance",
+      "docs": [
+        "Configure tree-balance enforcement on spawn (see `GlobalStats::level_filled`).",
+        "Enabling requires a branching factor of at least 1. Authority only."
+      ],
+      "discriminator": [
+        180,
+        70,
+        235,
+        178,
+        186,
+        228,
+        164,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "requireBalanced",
+          "type": "bool"
+        },
+        {
+          "name": "branchingFactor",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setWithdrawWhitelist",
+      "docs": [
+        "Set the agent's withdrawal destination whitelist (agent wallet only).",
+        "Unused slots are `Pubkey::default()`; an all-default list means no restriction."
+      ],
+      "discriminator": [
+        156,
+        55,
+        62,
+        126,
+        48,
+        121,
+        122,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "whitelist",
+          "type": {
+            "array": [
+              "pubkey",
+              3
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "slashBond",
+      "docs": [
+        "Slash up to `amount` from an agent's bond into the fee vault. Authority only.",
+        "Slashing more than the bond holds caps at the bond balance."
+      ],
+      "discriminator": [
+        143,
+        246,
+        51,
+        243,
+        88,
+        198,
+        217,
+        48
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond.agent",
+                "account": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "spawnChild",
+      "docs": [
+        "Parent agent spawns a child agent.",
+        "",
+        "An optional secondary beneficiary (a co-incubating agent) can be given a",
+        "share alongside the parent; it can only be set here, at spawn time.",
+        "A nonzero `expires_at` makes the child inactive from that timestamp on, and a",
+        "nonzero `earning_goal` fires `GoalReached` once the child earns that much.",
+        "Under `strict_lineage_checks`, `remaining_accounts` may carry the parent's",
+        "ancestors, whose wallets the child wallet must not reuse."
+      ],
+      "discriminator": [
+        57,
+        254,
+        127,
+        116,
+        244,
+        20,
+        212,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "Required when the registry demands a bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "childWallet"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "spawnLicense",
+          "docs": [
+            "when the registry gates spawning, parsed in `check_spawn_license`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "nameReservation",
+          "docs": [
+            "`check_name_reservation`"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "specialization",
+          "type": "string"
+        },
+        {
+          "name": "revenueShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "secondaryBeneficiary",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "secondaryShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        },
+        {
+          "name": "earningGoal",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "spawnResult"
+        }
+      }
+    },
+    {
+      "name": "spawnChildV2",
+      "docs": [
+        "`spawn_child` with its arguments in a single struct."
+      ],
+      "discriminator": [
+        69,
+        31,
+        175,
+        80,
+        160,
+        170,
+        139,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "Required when the registry demands a bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "childWallet"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "spawnLicense",
+          "docs": [
+            "when the registry gates spawning, parsed in `check_spawn_license`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "nameReservation",
+          "docs": [
+            "`check_name_reservation`"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "spawnChildArgs"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "spawnResult"
+        }
+      }
+    },
+    {
+      "name": "spawnFromTemplate",
+      "docs": [
+        "Spawn a child from one of the parent's templates, named `name_prefix + name_suffix`.",
+        "Otherwise behaves like `spawn_child` with no secondary beneficiary, expiry or goal."
+      ],
+      "discriminator": [
+        20,
+        145,
+        116,
+        237,
+        192,
+        4,
+        138,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAgent",
+          "writable": true
+        },
+        {
+          "name": "template",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  109,
+                  112,
+                  108,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "parentAgent"
+              },
+              {
+                "kind": "arg",
+                "path": "templateId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childAgent",
+          "writable": true
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "Required when the registry demands a bond"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "childAgent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "specCounter",
+          "writable": true
+        },
+        {
+          "name": "parentWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "childWallet"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "spawnLicense",
+          "docs": [
+            "when the registry gates spawning, parsed in `check_spawn_license`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "nameReservation",
+          "docs": [
+            "`check_name_reservation`"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "templateId",
+          "type": "u32"
+        },
+        {
+          "name": "nameSuffix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "splitStats",
+      "docs": [
+        "Move a pre-existing registry's counters into a new `GlobalStats` account,",
+        "zeroing the legacy `Registry` fields. Authority only; runs once, since the",
+        "stats account can only be created once."
+      ],
+      "discriminator": [
+        58,
+        224,
+        139,
+        98,
+        144,
+        23,
+        3,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "sweepAgentPda",
+      "docs": [
+        "Return SOL accidentally sent to the agent PDA (anything above rent",
+        "exemption) to the agent's wallet. Agent wallet only."
+      ],
+      "discriminator": [
+        193,
+        182,
+        74,
+        52,
+        248,
+        253,
+        68,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "takeSnapshot",
+      "docs": [
+        "Record a tamper-evident checkpoint: sha256 over the global agent, reported",
+        "earnings and spawn counters plus the current slot, appended to the",
+        "`SnapshotLog` ring buffer. Authority only, at most once per",
+        "`min_snapshot_interval_secs`."
+      ],
+      "discriminator": [
+        183,
+        210,
+        251,
+        68,
+        140,
+        132,
+        191,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "snapshotLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  110,
+                  97,
+                  112,
+                  115,
+                  104,
+                  111,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "unlockAgent",
+      "docs": [
+        "Clear an expired lock (agent wallet only)."
+      ],
+      "discriminator": [
+        177,
+        9,
+        111,
+        255,
+        34,
+        57,
+        24,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateAgentUri",
+      "docs": [
+        "Point the agent at an off-chain profile JSON, with the document's hash so",
+        "consumers can verify it. Signed by the agent wallet; an empty `uri` clears it.",
+        "Accounts created before the field existed must first go through `migrate_agent`."
+      ],
+      "discriminator": [
+        127,
+        236,
+        245,
+        134,
+        56,
+        22,
+        211,
+        36
+      ],
+      "accounts": [
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "verifyPda",
+      "docs": [
+        "View: canonical PDA and bump for an account kind, returned via `set_return_data`.",
+        "",
+        "Kinds: 0 registry (`seed_key` ignored), 1 agent (wallet), 2 split config",
+        "(agent), 3 bond (agent), 4 wallet agent counter (wallet), 5 leaderboard",
+        "(`seed_key` ignored), 6 referral (payer), 7 global stats (`seed_key` ignored),",
+        "8 snapshot log (`seed_key` ignored), 9 oracle record (oracle). All but the",
+        "split config and bond, which are keyed by an agent PDA, derive under `namespace`."
+      ],
+      "discriminator": [
+        209,
+        132,
+        246,
+        247,
+        33,
+        17,
+        172,
+        54
+      ],
+      "accounts": [],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "kind",
+          "type": "u8"
+        },
+        {
+          "name": "seedKey",
+          "type": "pubkey"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "derivedPda"
+        }
+      }
+    },
+    {
+      "name": "withdrawBond",
+      "docs": [
+        "Withdraw an agent's bond after voluntary deactivation and the cooldown.",
+        "Closes the bond account, returning bond and rent to the agent wallet."
+      ],
+      "discriminator": [
+        222,
+        199,
+        141,
+        31,
+        188,
+        93,
+        155,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent"
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "agentAccount",
+      "discriminator": [
+        241,
+        119,
+        69,
+        140,
+        233,
+        9,
+        112,
+        50
+      ]
+    },
+    {
+      "name": "bond",
+      "discriminator": [
+        224,
+        128,
+        48,
+        251,
+        182,
+        246,
+        111,
+        196
+      ]
+    },
+    {
+      "name": "bounty",
+      "discriminator": [
+        237,
+        16,
+        105,
+        198,
+        19,
+        69,
+        242,
+        234
+      ]
+    },
+    {
+      "name": "distributionCommitment",
+      "discriminator": [
+        185,
+        230,
+        42,
+        233,
+        167,
+        11,
+        242,
+        128
+      ]
+    },
+    {
+      "name": "globalStats",
+      "discriminator": [
+        119,
+        53,
+        78,
+        3,
+        254,
+        129,
+        78,
+        28
+      ]
+    },
+    {
+      "name": "leaderboard",
+      "discriminator": [
+        247,
+        186,
+        238,
+        243,
+        194,
+        30,
+        9,
+        36
+      ]
+    },
+    {
+      "name": "nameReservation",
+      "discriminator": [
+        174,
+        3,
+        104,
+        101,
+        43,
+        37,
+        151,
+        250
+      ]
+    },
+    {
+      "name": "oracleRecord",
+      "discriminator": [
+        79,
+        253,
+        178,
+        156,
+        51,
+        4,
+        98,
+        233
+      ]
+    },
+    {
+      "name": "referral",
+      "discriminator": [
+        30,
+        235,
+        136,
+        224,
+        106,
+        107,
+        49,
+        64
+      ]
+    },
+    {
+      "name": "registry",
+      "discriminator": [
+        47,
+        174,
+        110,
+        246,
+        184,
+        182,
+        252,
+        218
+      ]
+    },
+    {
+      "name": "snapshotLog",
+      "discriminator": [
+        102,
+        90,
+        238,
+        2,
+        164,
+        70,
+        250,
+        59
+      ]
+    },
+    {
+      "name": "spawnTemplate",
+      "discriminator": [
+        125,
+        160,
+        10,
+        139,
+        189,
+        197,
+        109,
+        20
+      ]
+    },
+    {
+      "name": "specCounter",
+      "discriminator": [
+        96,
+        210,
+        133,
+        133,
+        203,
+        213,
+        186,
+        132
+      ]
+    },
+    {
+      "name": "splitConfig",
+      "discriminator": [
+        49,
+        201,
+        50,
+        228,
+        22,
+        142,
+        12,
+        222
+      ]
+    },
+    {
+      "name": "taskLog",
+      "discriminator": [
+        198,
+        78,
+        135,
+        238,
+        66,
+        12,
+        255,
+        54
+      ]
+    },
+    {
+      "name": "tokenEarnings",
+      "discriminator": [
+        139,
+        123,
+        166,
+        85,
+        142,
+        66,
+        108,
+        166
+      ]
+    },
+    {
+      "name": "walletAgentCounter",
+      "discriminator": [
+        112,
+        26,
+        234,
+        245,
+        117,
+        52,
+        68,
+        156
+      ]
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [
+        225,
+        114,
+        235,
+        206,
+        169,
+        33,
+        71,
+        57
+      ],
+      "name": "adjustmentCooldownSet"
+    },
+    {
+      "discriminator": [
+        72,
+        133,
+        207,
+        59,
+        220,
+        188,
+        201,
+        165
+      ],
+      "name": "agentClosed"
+    },
+    {
+      "discriminator": [
+        138,
+        251,
+        82,
+        87,
+        119,
+        148,
+        20,
+        180
+      ],
+      "name": "agentDeactivated"
+    },
+    {
+      "discriminator": [
+        211,
+        205,
+        216,
+        89,
+        153,
+        43,
+        200,
+        43
+      ],
+      "name": "agentDepthRecomputed"
+    },
+    {
+      "discriminator": [
+        180,
+        47,
+        27,
+        167,
+        4,
+        25,
+        39,
+        123
+      ],
+      "name": "agentExpired"
+    },
+    {
+      "discriminator": [
+        63,
+        163,
+        227,
+        130,
+        171,
+        233,
+        157,
+        126
+      ],
+      "name": "agentFinalized"
+    },
+    {
+      "discriminator": [
+        49,
+        38,
+        208,
+        185,
+        219,
+        3,
+        92,
+        27
+      ],
+      "name": "agentFrozen"
+    },
+    {
+      "discriminator": [
+        83,
+        201,
+        173,
+        210,
+        251,
+        102,
+        170,
+        53
+      ],
+      "name": "agentHeartbeat"
+    },
+    {
+      "discriminator": [
+        41,
+        29,
+        251,
+        85,
+        88,
+        192,
+        165,
+        135
+      ],
+      "name": "agentLocked"
+    },
+    {
+      "discriminator": [
+        155,
+        88,
+        224,
+        49,
+        146,
+        60,
+        127,
+        163
+      ],
+      "name": "agentMarkedStale"
+    },
+    {
+      "discriminator": [
+        58,
+        251,
+        115,
+        70,
+        18,
+        230,
+        95,
+        164
+      ],
+      "name": "agentMigrated"
+    },
+    {
+      "discriminator": [
+        12,
+        214,
+        125,
+        244,
+        150,
+        237,
+        188,
+        63
+      ],
+      "name": "agentPromotedToRoot"
+    },
+    {
+      "discriminator": [
+        23,
+        241,
+        7,
+        252,
+        229,
+        96,
+        117,
+        217
+      ],
+      "name": "agentReactivated"
+    },
+    {
+      "discriminator": [
+        191,
+        78,
+        217,
+        54,
+        232,
+        100,
+        189,
+        85
+      ],
+      "name": "agentRegistered"
+    },
+    {
+      "discriminator": [
+        228,
+        66,
+        77,
+        22,
+        52,
+        95,
+        159,
+        109
+      ],
+      "name": "agentRegisteredCompact"
+    },
+    {
+      "discriminator": [
+        122,
+        115,
+        251,
+        186,
+        77,
+        109,
+        189,
+        32
+      ],
+      "name": "agentReparented"
+    },
+    {
+      "discriminator": [
+        229,
+        125,
+        60,
+        41,
+        61,
+        109,
+        254,
+        34
+      ],
+      "name": "agentSpawned"
+    },
+    {
+      "discriminator": [
+        110,
+        227,
+        24,
+        178,
+        146,
+        11,
+        211,
+        129
+      ],
+      "name": "agentSpawnedCompact"
+    },
+    {
+      "discriminator": [
+        106,
+        176,
+        34,
+        180,
+        128,
+        132,
+        33,
+        127
+      ],
+      "name": "agentTtlExtended"
+    },
+    {
+      "discriminator": [
+        133,
+        118,
+        164,
+        12,
+        185,
+        109,
+        45,
+        0
+      ],
+      "name": "agentUnlocked"
+    },
+    {
+      "discriminator": [
+        114,
+        212,
+        16,
+        210,
+        33,
+        180,
+        40,
+        81
+      ],
+      "name": "agentUriUpdated"
+    },
+    {
+      "discriminator": [
+        150,
+        32,
+        58,
+        200,
+        43,
+        133,
+        100,
+        149
+      ],
+      "name": "agentsMerged"
+    },
+    {
+      "discriminator": [
+        60,
+        188,
+        216,
+        195,
+        34,
+        133,
+        217,
+        43
+      ],
+      "name": "allowCpiSet"
+    },
+    {
+      "discriminator": [
+        201,
+        36,
+        146,
+        19,
+        47,
+        101,
+        65,
+        248
+      ],
+      "name": "authorityProposalCancelled"
+    },
+    {
+      "discriminator": [
+        244,
+        117,
+        94,
+        112,
+        53,
+        151,
+        35,
+        89
+      ],
+      "name": "authorityProposed"
+    },
+    {
+      "discriminator": [
+        245,
+        109,
+        179,
+        54,
+        135,
+        92,
+        22,
+        64
+      ],
+      "name": "authorityTransferred"
+    },
+    {
+      "discriminator": [
+        116,
+        240,
+        164,
+        118,
+        35,
+        244,
+        79,
+        175
+      ],
+      "name": "bondConfigSet"
+    },
+    {
+      "discriminator": [
+        39,
+        196,
+        2,
+        142,
+        202,
+        74,
+        107,
+        205
+      ],
+      "name": "bondPosted"
+    },
+    {
+      "discriminator": [
+        59,
+        7,
+        252,
+        195,
+        234,
+        156,
+        42,
+        54
+      ],
+      "name": "bondSlashed"
+    },
+    {
+      "discriminator": [
+        111,
+        192,
+        154,
+        231,
+        58,
+        115,
+        130,
+        103
+      ],
+      "name": "bondWithdrawn"
+    },
+    {
+      "discriminator": [
+        234,
+        186,
+        248,
+        214,
+        198,
+        69,
+        152,
+        23
+      ],
+      "name": "bountyCancelled"
+    },
+    {
+      "discriminator": [
+        15,
+        133,
+        20,
+        215,
+        8,
+        226,
+        17,
+        235
+      ],
+      "name": "bountyClaimRejected"
+    },
+    {
+      "discriminator": [
+        140,
+        202,
+        21,
+        51,
+        211,
+        65,
+        142,
+        21
+      ],
+      "name": "bountyClaimed"
+    },
+    {
+      "discriminator": [
+        60,
+        72,
+        10,
+        171,
+        122,
+        59,
+        238,
+        148
+      ],
+      "name": "bountyPaid"
+    },
+    {
+      "discriminator": [
+        61,
+        61,
+        26,
+        239,
+        184,
+        44,
+        139,
+        232
+      ],
+      "name": "bountyPosted"
+    },
+    {
+      "discriminator": [
+        142,
+        193,
+        111,
+        205,
+        148,
+        204,
+        136,
+        82
+      ],
+      "name": "bulkEarningsRecorded"
+    },
+    {
+      "discriminator": [
+        102,
+        103,
+        38,
+        110,
+        177,
+        156,
+        56,
+        161
+      ],
+      "name": "chainDistributed"
+    },
+    {
+      "discriminator": [
+        175,
+        82,
+        59,
+        85,
+        211,
+        225,
+        147,
+        212
+      ],
+      "name": "childFunded"
+    },
+    {
+      "discriminator": [
+        174,
+        185,
+        187,
+        199,
+        108,
+        234,
+        221,
+        126
+      ],
+      "name": "childrenCountReconciled"
+    },
+    {
+      "discriminator": [
+        180,
+        134,
+        211,
+        229,
+        46,
+        82,
+        230,
+        253
+      ],
+      "name": "commitmentMissed"
+    },
+    {
+      "discriminator": [
+        210,
+        40,
+        180,
+        169,
+        125,
+        38,
+        118,
+        183
+      ],
+      "name": "compactEventsSet"
+    },
+    {
+      "discriminator": [
+        106,
+        241,
+        37,
+        77,
+        66,
+        138,
+        61,
+        236
+      ],
+      "name": "currencyMetadataSet"
+    },
+    {
+      "discriminator": [
+        44,
+        144,
+        210,
+        136,
+        186,
+        78,
+        129,
+        185
+      ],
+      "name": "directSplitPayment"
+    },
+    {
+      "discriminator": [
+        122,
+        27,
+        64,
+        200,
+        134,
+        235,
+        100,
+        76
+      ],
+      "name": "distributionCommitmentRevoked"
+    },
+    {
+      "discriminator": [
+        230,
+        198,
+        40,
+        122,
+        94,
+        67,
+        184,
+        240
+      ],
+      "name": "distributionCommitted"
+    },
+    {
+      "discriminator": [
+        64,
+        239,
+        21,
+        49,
+        62,
+        79,
+        202,
+        65
+      ],
+      "name": "distributionLimitsSet"
+    },
+    {
+      "discriminator": [
+        131,
+        70,
+        179,
+        205,
+        208,
+        80,
+        13,
+        168
+      ],
+      "name": "dustSwept"
+    },
+    {
+      "discriminator": [
+        177,
+        200,
+        147,
+        59,
+        142,
+        107,
+        89,
+        153
+      ],
+      "name": "earningAdjusted"
+    },
+    {
+      "discriminator": [
+        218,
+        253,
+        64,
+        68,
+        64,
+        78,
+        226,
+        178
+      ],
+      "name": "earningAttested"
+    },
+    {
+      "discriminator": [
+        48,
+        24,
+        116,
+        36,
+        22,
+        252,
+        175,
+        65
+      ],
+      "name": "earningGoalSet"
+    },
+    {
+      "discriminator": [
+        207,
+        235,
+        171,
+        216,
+        168,
+        79,
+        174,
+        197
+      ],
+      "name": "earningRecorded"
+    },
+    {
+      "discriminator": [
+        199,
+        87,
+        101,
+        191,
+        21,
+        91,
+        97,
+        238
+      ],
+      "name": "emitMemosSet"
+    },
+    {
+      "discriminator": [
+        24,
+        89,
+        184,
+        130,
+        114,
+        162,
+        34,
+        106
+      ],
+      "name": "forwardShareSet"
+    },
+    {
+      "discriminator": [
+        234,
+        89,
+        63,
+        200,
+        244,
+        82,
+        235,
+        134
+      ],
+      "name": "goalReached"
+    },
+    {
+      "discriminator": [
+        86,
+        216,
+        59,
+        243,
+        165,
+        219,
+        49,
+        245
+      ],
+      "name": "leaderboardChanged"
+    },
+    {
+      "discriminator": [
+        49,
+        63,
+        138,
+        215,
+        67,
+        38,
+        102,
+        42
+      ],
+      "name": "maxBatchNodesSet"
+    },
+    {
+      "discriminator": [
+        240,
+        101,
+        198,
+        185,
+        236,
+        61,
+        185,
+        3
+      ],
+      "name": "maxDepthSet"
+    },
+    {
+      "discriminator": [
+        116,
+        119,
+        248,
+        173,
+        90,
+        201,
+        13,
+        137
+      ],
+      "name": "maxSingleEarningSet"
+    },
+    {
+      "discriminator": [
+        234,
+        55,
+        24,
+        33,
+        42,
+        152,
+        104,
+        241
+      ],
+      "name": "milestoneUnlocked"
+    },
+    {
+      "discriminator": [
+        6,
+        220,
+        98,
+        236,
+        117,
+        196,
+        121,
+        214
+      ],
+      "name": "minActiveSecsSet"
+    },
+    {
+      "discriminator": [
+        204,
+        60,
+        169,
+        127,
+        159,
+        223,
+        55,
+        88
+      ],
+      "name": "minDistributionIntervalSet"
+    },
+    {
+      "discriminator": [
+        168,
+        105,
+        114,
+        248,
+        47,
+        134,
+        54,
+        227
+      ],
+      "name": "minSnapshotIntervalSet"
+    },
+    {
+      "discriminator": [
+        199,
+        75,
+        140,
+        236,
+        6,
+        69,
+        138,
+        101
+      ],
+      "name": "nameReservationCreated"
+    },
+    {
+      "discriminator": [
+        14,
+        160,
+        71,
+        175,
+        65,
+        19,
+        89,
+        199
+      ],
+      "name": "nameReservationReleased"
+    },
+    {
+      "discriminator": [
+        48,
+        0,
+        207,
+        33,
+        20,
+        56,
+        215,
+        219
+      ],
+      "name": "oracleAdded"
+    },
+    {
+      "discriminator": [
+        123,
+        15,
+        215,
+        255,
+        76,
+        49,
+        227,
+        105
+      ],
+      "name": "oracleEarningRecorded"
+    },
+    {
+      "discriminator": [
+        220,
+        190,
+        142,
+        34,
+        95,
+        8,
+        133,
+        80
+      ],
+      "name": "oracleOnlyEarningsSet"
+    },
+    {
+      "discriminator": [
+        62,
+        112,
+        125,
+        81,
+        128,
+        93,
+        194,
+        96
+      ],
+      "name": "oracleRemoved"
+    },
+    {
+      "discriminator": [
+        99,
+        66,
+        62,
+        200,
+        196,
+        92,
+        65,
+        28
+      ],
+      "name": "positionReport"
+    },
+    {
+      "discriminator": [
+        14,
+        153,
+        224,
+        140,
+        98,
+        91,
+        158,
+        26
+      ],
+      "name": "proposalTtlSet"
+    },
+    {
+      "discriminator": [
+        254,
+        175,
+        221,
+        233,
+        3,
+        245,
+        85,
+        192
+      ],
+      "name": "pruneCompleted"
+    },
+    {
+      "discriminator": [
+        182,
+        78,
+        57,
+        9,
+        67,
+        37,
+        218,
+        217
+      ],
+      "name": "pruneGraceSet"
+    },
+    {
+      "discriminator": [
+        151,
+        58,
+        230,
+        195,
+        10,
+        174,
+        207,
+        182
+      ],
+      "name": "reactivationQuarantineSet"
+    },
+    {
+      "discriminator": [
+        114,
+        93,
+        160,
+        98,
+        3,
+        249,
+        96,
+        99
+      ],
+      "name": "reconciliationReport"
+    },
+    {
+      "discriminator": [
+        174,
+        66,
+        125,
+        58,
+        20,
+        47,
+        0,
+        34
+      ],
+      "name": "referralConfigSet"
+    },
+    {
+      "discriminator": [
+        70,
+        190,
+        133,
+        42,
+        145,
+        213,
+        87,
+        197
+      ],
+      "name": "referralPaid"
+    },
+    {
+      "discriminator": [
+        210,
+        150,
+        27,
+        227,
+        209,
+        161,
+        7,
+        232
+      ],
+      "name": "referralRegistered"
+    },
+    {
+      "discriminator": [
+        144,
+        138,
+        62,
+        105,
+        58,
+        38,
+        100,
+        177
+      ],
+      "name": "registryInitialized"
+    },
+    {
+      "discriminator": [
+        147,
+        228,
+        29,
+        245,
+        202,
+        41,
+        128,
+        158
+      ],
+      "name": "registryMigrated"
+    },
+    {
+      "discriminator": [
+        97,
+        203,
+        69,
+        71,
+        19,
+        228,
+        182,
+        86
+      ],
+      "name": "reparentPolicySet"
+    },
+    {
+      "discriminator": [
+        161,
+        240,
+        25,
+        33,
+        231,
+        20,
+        11,
+        3
+      ],
+      "name": "reporterSet"
+    },
+    {
+      "discriminator": [
+        243,
+        253,
+        123,
+        19,
+        35,
+        13,
+        156,
+        36
+      ],
+      "name": "requireActiveLineageSet"
+    },
+    {
+      "discriminator": [
+        78,
+        195,
+        188,
+        214,
+        203,
+        219,
+        199,
+        87
+      ],
+      "name": "revenueDistributed"
+    },
+    {
+      "discriminator": [
+        58,
+        187,
+        191,
+        152,
+        162,
+        142,
+        144,
+        162
+      ],
+      "name": "secondaryRevenueDistributed"
+    },
+    {
+      "discriminator": [
+        98,
+        243,
+        118,
+        169,
+        52,
+        171,
+        95,
+        251
+      ],
+      "name": "siblingShareCapSet"
+    },
+    {
+      "discriminator": [
+        223,
+        160,
+        135,
+        223,
+        41,
+        36,
+        142,
+        178
+      ],
+      "name": "skippedInactiveAncestor"
+    },
+    {
+      "discriminator": [
+        113,
+        204,
+        151,
+        224,
+        2,
+        128,
+        237,
+        207
+      ],
+      "name": "snapshotTaken"
+    },
+    {
+      "discriminator": [
+        14,
+        101,
+        157,
+        189,
+        51,
+        71,
+        188,
+        163
+      ],
+      "name": "spawnLicenseMintSet"
+    },
+    {
+      "discriminator": [
+        190,
+        45,
+        153,
+        163,
+        134,
+        183,
+        238,
+        38
+      ],
+      "name": "spawnTemplateCreated"
+    },
+    {
+      "discriminator": [
+        157,
+        142,
+        60,
+        177,
+        216,
+        59,
+        82,
+        101
+      ],
+      "name": "specCountChanged"
+    },
+    {
+      "discriminator": [
+        46,
+        147,
+        176,
+        254,
+        189,
+        10,
+        47,
+        118
+      ],
+      "name": "splitDistributed"
+    },
+    {
+      "discriminator": [
+        199,
+        234,
+        130,
+        52,
+        201,
+        208,
+        149,
+        51
+      ],
+      "name": "staleThresholdSet"
+    },
+    {
+      "discriminator": [
+        84,
+        225,
+        42,
+        212,
+        248,
+        238,
+        23,
+        150
+      ],
+      "name": "statsSplit"
+    },
+    {
+      "discriminator": [
+        140,
+        191,
+        155,
+        19,
+        179,
+        249,
+        228,
+        130
+      ],
+      "name": "strictLineageChecksSet"
+    },
+    {
+      "discriminator": [
+        171,
+        30,
+        160,
+        213,
+        27,
+        120,
+        59,
+        243
+      ],
+      "name": "strictNamesSet"
+    },
+    {
+      "discriminator": [
+        115,
+        142,
+        125,
+        195,
+        154,
+        121,
+        145,
+        193
+      ],
+      "name": "subtreeEarningRolledUp"
+    },
+    {
+      "discriminator": [
+        33,
+        241,
+        48,
+        81,
+        244,
+        26,
+        247,
+        153
+      ],
+      "name": "tagChanged"
+    },
+    {
+      "discriminator": [
+        168,
+        129,
+        46,
+        160,
+        46,
+        18,
+        195,
+        209
+      ],
+      "name": "tokenEarningRecorded"
+    },
+    {
+      "discriminator": [
+        126,
+        51,
+        59,
+        202,
+        250,
+        23,
+        194,
+        250
+      ],
+      "name": "treeBalanceSet"
+    },
+    {
+      "discriminator": [
+        189,
+        193,
+        57,
+        107,
+        182,
+        40,
+        134,
+        115
+      ],
+      "name": "waterfallReport"
+    },
+    {
+      "discriminator": [
+        106,
+        215,
+        73,
+        75,
+        6,
+        18,
+        62,
+        251
+      ],
+      "name": "withdrawWhitelistEnforcementSet"
+    },
+    {
+      "discriminator": [
+        193,
+        12,
+        252,
+        64,
+        82,
+        158,
+        61,
+        208
+      ],
+      "name": "withdrawWhitelistSet"
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "nameTooLong",
+      "msg": "Agent name exceeds maximum length"
+    },
+    {
+      "code": 6001,
+      "name": "specTooLong",
+      "msg": "Specialization exceeds maximum length"
+    },
+    {
+      "code": 6002,
+      "name": "invalidRevenueShare",
+      "msg": "Revenue share basis points must be <= 10000"
+    },
+    {
+      "code": 6003,
+      "name": "agentInactive",
+      "msg": "Agent is not active"
+    },
+    {
+      "code": 6004,
+      "name": "maxDepthReached",
+      "msg": "Maximum agent tree depth reached"
+    },
+    {
+      "code": 6005,
+      "name": "zeroAmount",
+      "msg": "Amount must be greater than zero"
+    },
+    {
+      "code": 6006,
+      "name": "noParentAgent",
+      "msg": "Agent has no parent"
+    },
+    {
+      "code": 6007,
+      "name": "missingGrandparentAccounts",
+      "msg": "Parent forwards a share but grandparent accounts were not supplied"
+    },
+    {
+      "code": 6008,
+      "name": "invalidGrandparent",
+      "msg": "Grandparent accounts do not match the parent's lineage"
+    },
+    {
+      "code": 6009,
+      "name": "invalidNameChar",
+      "msg": "Agent name contains a disallowed character"
+    },
+    {
+      "code": 6010,
+      "name": "tooManySplitRecipients",
+      "msg": "Too many split recipients"
+    },
+    {
+      "code": 6011,
+      "name": "invalidSplitRecipient",
+      "msg": "Split recipient account does not match the split config"
+    },
+    {
+      "code": 6012,
+      "name": "invalidMaxDepth",
+      "msg": "Max depth must be between 1 and the program ceiling"
+    },
+    {
+      "code": 6013,
+      "name": "invalidConfigValue",
+      "msg": "Config value is out of range"
+    },
+    {
+      "code": 6014,
+      "name": "invalidPruneAccounts",
+      "msg": "Prune accounts must be (agent, rent destination, parent) triples matching each agent"
+    },
+    {
+      "code": 6015,
+      "name": "parentMismatch",
+      "msg": "Parent agent account does not match the agent's parent"
+    },
+    {
+      "code": 6016,
+      "name": "destinationNotWhitelisted",
+      "msg": "Destination is not in the agent's withdrawal whitelist"
+    },
+    {
+      "code": 6017,
+      "name": "unauthorized",
+      "msg": "Signer is not authorized for this action"
+    },
+    {
+      "code": 6018,
+      "name": "missingBondAccount",
+      "msg": "Registry requires a bond but no bond account was supplied"
+    },
+    {
+      "code": 6019,
+      "name": "bondLocked",
+      "msg": "Bond can only be withdrawn after voluntary deactivation"
+    },
+    {
+      "code": 6020,
+      "name": "bondCooldownActive",
+      "msg": "Bond withdrawal cooldown has not elapsed"
+    },
+    {
+      "code": 6021,
+      "name": "reasonTooLong",
+      "msg": "Reason exceeds maximum length"
+    },
+    {
+      "code": 6022,
+      "name": "agentNotStale",
+      "msg": "Agent has a recent heartbeat and is not stale"
+    },
+    {
+      "code": 6023,
+      "name": "duplicateNonce",
+      "msg": "Earning nonce must be greater than the last recorded nonce"
+    },
+    {
+      "code": 6024,
+      "name": "invalidChain",
+      "msg": "Agent chain must link leaf to root through parent accounts"
+    },
+    {
+      "code": 6025,
+      "name": "invalidAgentAccount",
+      "msg": "Account is not a Hydra agent account"
+    },
+    {
+      "code": 6026,
+      "name": "missingParentAccount",
+      "msg": "Parent agent account is required"
+    },
+    {
+      "code": 6027,
+      "name": "ancestorInactive",
+      "msg": "An ancestor agent is not active"
+    },
+    {
+      "code": 6028,
+      "name": "nothingToSweep",
+      "msg": "No lamports above rent exemption to sweep"
+    },
+    {
+      "code": 6029,
+      "name": "earningExceedsLimit",
+      "msg": "Earning exceeds the registry's single-earning limit"
+    },
+    {
+      "code": 6030,
+      "name": "notRootAgent",
+      "msg": "Account is not a root agent"
+    },
+    {
+      "code": 6031,
+      "name": "invalidSecondaryBeneficiary",
+      "msg": "Account is not the agent's secondary beneficiary"
+    },
+    {
+      "code": 6032,
+      "name": "selfDistribution",
+      "msg": "Child and parent wallets must differ"
+    },
+    {
+      "code": 6033,
+      "name": "invalidAgentIndex",
+      "msg": "Agent index must be the wallet's next unused index"
+    },
+    {
+      "code": 6034,
+      "name": "selfClaim",
+      "msg": "An agent cannot claim its own bounty"
+    },
+    {
+      "code": 6035,
+      "name": "notBountyAssignee",
+      "msg": "Bounty is assigned to a different agent"
+    },
+    {
+      "code": 6036,
+      "name": "bountyNotClaimed",
+      "msg": "Bounty has not been claimed"
+    },
+    {
+      "code": 6037,
+      "name": "bountyAlreadyClaimed",
+      "msg": "Bounty has already been claimed"
+    },
+    {
+      "code": 6038,
+      "name": "bountyAlreadyPaid",
+      "msg": "Bounty has already been paid"
+    },
+    {
+      "code": 6039,
+      "name": "bountyAlreadyCancelled",
+      "msg": "Bounty has already been cancelled"
+    },
+    {
+      "code": 6040,
+      "name": "treeNotBalanced",
+      "msg": "Parent's depth level is not yet filled to the branching factor"
+    },
+    {
+      "code": 6041,
+      "name": "proposalExpired",
+      "msg": "Authority proposal has expired"
+    },
+    {
+      "code": 6042,
+      "name": "noPendingAuthority",
+      "msg": "No authority handoff is pending"
+    },
+    {
+      "code": 6043,
+      "name": "cpiNotAllowed",
+      "msg": "Instruction may not be invoked via CPI"
+    },
+    {
+      "code": 6044,
+      "name": "invalidExpiry",
+      "msg": "Expiry must be in the future and later than the current one"
+    },
+    {
+      "code": 6045,
+      "name": "agentNotExpired",
+      "msg": "Agent has not expired"
+    },
+    {
+      "code": 6046,
+      "name": "selfSpawn",
+      "msg": "Parent and child wallets must differ"
+    },
+    {
+      "code": 6047,
+      "name": "invalidReparent",
+      "msg": "New parent would create a cycle, repeat the old parent, or shift the child's subtree depth"
+    },
+    {
+      "code": 6048,
+      "name": "tooSoonToDeactivate",
+      "msg": "Agent has not been active long enough to deactivate"
+    },
+    {
+      "code": 6049,
+      "name": "lengthMismatch",
+      "msg": "Amounts and agent accounts differ in length"
+    },
+    {
+      "code": 6050,
+      "name": "invalidBatchSize",
+      "msg": "Batch must hold between 1 and MAX_BATCH_LEN entries"
+    },
+    {
+      "code": 6051,
+      "name": "invalidPdaKind",
+      "msg": "Unknown PDA kind"
+    },
+    {
+      "code": 6052,
+      "name": "agentAlreadyActive",
+      "msg": "Agent is already active"
+    },
+    {
+      "code": 6053,
+      "name": "agentInQuarantine",
+      "msg": "Agent is in its reactivation quarantine"
+    },
+    {
+      "code": 6054,
+      "name": "batchTooLarge",
+      "msg": "Too many accounts for the registry's batch limit"
+    },
+    {
+      "code": 6055,
+      "name": "invalidParentWalletOwner",
+      "msg": "Parent wallet must be owned by the System Program"
+    },
+    {
+      "code": 6056,
+      "name": "agentLocked",
+      "msg": "Agent is locked"
+    },
+    {
+      "code": 6057,
+      "name": "invalidLockTime",
+      "msg": "Lock must end in the future and not before the current lock"
+    },
+    {
+      "code": 6058,
+      "name": "agentNotLocked",
+      "msg": "Agent is not locked"
+    },
+    {
+      "code": 6059,
+      "name": "missingMemoProgram",
+      "msg": "Memo program account required when memos are enabled"
+    },
+    {
+      "code": 6060,
+      "name": "parentStillActive",
+      "msg": "Parent is still active; pass force to promote anyway"
+    },
+    {
+      "code": 6061,
+      "name": "missingReferralAccounts",
+      "msg": "Referring agent accounts required for an active referral"
+    },
+    {
+      "code": 6062,
+      "name": "referralMismatch",
+      "msg": "Referring agent does not match the referral"
+    },
+    {
+      "code": 6063,
+      "name": "spawnLicenseMissing",
+      "msg": "Parent wallet does not hold the spawn license"
+    },
+    {
+      "code": 6064,
+      "name": "siblingShareExceeded",
+      "msg": "Children's combined revenue share would exceed 10000 bps"
+    },
+    {
+      "code": 6065,
+      "name": "uriTooLong",
+      "msg": "URI too long"
+    },
+    {
+      "code": 6066,
+      "name": "snapshotTooSoon",
+      "msg": "Snapshot taken too soon after the previous one"
+    },
+    {
+      "code": 6067,
+      "name": "attestationExceedsEarnings",
+      "msg": "Attested total exceeds the child's recorded earnings"
+    },
+    {
+      "code": 6068,
+      "name": "staleAttestation",
+      "msg": "Attestation does not reference the child's latest earning nonce"
+    },
+    {
+      "code": 6069,
+      "name": "adjustmentUnderflow",
+      "msg": "Adjustment would take total earnings below zero"
+    },
+    {
+      "code": 6070,
+      "name": "adjustmentCooldown",
+      "msg": "Agent was adjusted too recently"
+    },
+    {
+      "code": 6071,
+      "name": "templateExhausted",
+      "msg": "Spawn template has no uses left"
+    },
+    {
+      "code": 6072,
+      "name": "notAChild",
+      "msg": "Account is not a child of this parent"
+    },
+    {
+      "code": 6073,
+      "name": "noFundableChildren",
+      "msg": "No active child with a nonzero revenue share to fund"
+    },
+    {
+      "code": 6074,
+      "name": "agentImmutable",
+      "msg": "Agent is finalized and its configuration cannot change"
+    },
+    {
+      "code": 6075,
+      "name": "agentFrozen",
+      "msg": "Agent is frozen"
+    },
+    {
+      "code": 6076,
+      "name": "childInactive",
+      "msg": "Child agent is inactive"
+    },
+    {
+      "code": 6077,
+      "name": "parentInactive",
+      "msg": "Parent agent is inactive"
+    },
+    {
+      "code": 6078,
+      "name": "namespaceTooLong",
+      "msg": "Namespace too long"
+    },
+    {
+      "code": 6079,
+      "name": "overflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6080,
+      "name": "oracleRequired",
+      "msg": "Earnings may only be recorded by an approved oracle"
+    },
+    {
+      "code": 6081,
+      "name": "commitmentNotDue",
+      "msg": "Distribution commitment interval has not elapsed"
+    },
+    {
+      "code": 6082,
+      "name": "ancestorWalletReuse",
+      "msg": "Child wallet is already an ancestor's wallet"
+    },
+    {
+      "code": 6083,
+      "name": "distributionTooSoon",
+      "msg": "Distribution interval has not elapsed since the agent's last distribution"
+    },
+    {
+      "code": 6084,
+      "name": "nameReserved",
+      "msg": "Name is reserved by another wallet"
+    },
+    {
+      "code": 6085,
+      "name": "duplicateTask",
+      "msg": "Task hash was already recorded"
+    },
+    {
+      "code": 6086,
+      "name": "missingTaskLog",
+      "msg": "Task log account is required when task hashes are given"
+    },
+    {
+      "code": 6087,
+      "name": "distributionLimitExceeded",
+      "msg": "Distribution exceeds the child's single or daily distribution limit"
+    },
+    {
+      "code": 6088,
+      "name": "hasActiveChildren",
+      "msg": "Agent has children"
+    },
+    {
+      "code": 6089,
+      "name": "invalidStatusTransition",
+      "msg": "Agent status cannot move to the requested state"
+    },
+    {
+      "code": 6090,
+      "name": "namespaceMismatch",
+      "msg": "Agent belongs to a different namespace"
+    },
+    {
+      "code": 6091,
+      "name": "pendingRollUp",
+      "msg": "Agent has subtree earnings not yet rolled up to its parent"
+    }
+  ],
+  "types": [
+    {
+      "docs": [
+        "Global stats total an event's amount was counted in."
+      ],
+      "name": "accountingBucket",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "reported"
+          },
+          {
+            "name": "transferred"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adjustmentCooldownSet",
+      "type": {
+        "fields": [
+          {
+            "name": "adjustmentCooldownSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "specialization",
+            "type": "string"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "totalDistributedToParent",
+            "type": "u64"
+          },
+          {
+            "name": "childrenCount",
+            "type": "u64"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "revenueShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "forwardShareBps",
+            "docs": [
+              "Share of revenue received from children that is forwarded to this agent's parent"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "epochEarned",
+            "docs": [
+              "Earnings recorded during `current_epoch`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "currentEpoch",
+            "docs": [
+              "Epoch of the most recent earning"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deactivatedAt",
+            "docs": [
+              "Unix timestamp of deactivation (0 while active)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "subtreeEarned",
+            "docs": [
+              "Gross earnings of all descendants rolled up so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unrolledEarnings",
+            "docs": [
+              "Subtree earnings not yet rolled up to the parent"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawWhitelist",
+            "docs": [
+              "Approved withdrawal destinations; all-default means unrestricted"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "deactivatedByAuthority",
+            "docs": [
+              "Whether the registry authority (rather than the agent) deactivated this agent"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "lastActiveAt",
+            "docs": [
+              "Unix timestamp of the last heartbeat, earning or distribution (creation time until then)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastEarningNonce",
+            "docs": [
+              "Highest nonzero nonce accepted by `record_earning`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalReceivedFromChildren",
+            "docs": [
+              "Cumulative revenue received from children via `distribute_to_parent`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "schemaVersion",
+            "docs": [
+              "Layout version, see `AGENT_SCHEMA_VERSION` and `migrate_agent`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdBy",
+            "docs": [
+              "Signer that initiated this agent's creation (default for pre-v2 accounts)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Account that paid creation rent and receives it back on close"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalReceivedFromSubtree",
+            "docs": [
+              "Revenue received directly from descendants via `distribute_to_root` (roots only)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "secondaryBeneficiary",
+            "docs": [
+              "Co-incubating agent owed a share alongside the parent, fixed at spawn"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "secondaryShareBps",
+            "docs": [
+              "Share owed to the secondary beneficiary (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "totalDistributedToSecondary",
+            "docs": [
+              "Total revenue sent to the secondary beneficiary"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isIndexed",
+            "docs": [
+              "Whether the PDA is `[\"agent\", wallet, agent_index_le]` rather than `[\"agent\", wallet]`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "agentIndex",
+            "docs": [
+              "Per-wallet index for indexed agents"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Timestamp from which the agent is treated as inactive (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "earnedByCategory",
+            "docs": [
+              "Earnings per `EarningCategory`"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "earningGoal",
+            "docs": [
+              "`total_earned` at which `GoalReached` fires (0 = no goal)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "goalReached",
+            "docs": [
+              "Whether `GoalReached` has fired for the current goal"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "isStale",
+            "docs": [
+              "Set by `mark_stale`, cleared by the agent's next activity"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reactivatedAt",
+            "docs": [
+              "Last `reactivate_agent` timestamp (0 = never reactivated)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lockedUntil",
+            "docs": [
+              "Deactivation, bond withdrawal and reparenting are blocked until this timestamp (0 = unlocked)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalReferralEarnings",
+            "docs": [
+              "Lamports received as a referring agent"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "childrenShareSum",
+            "docs": [
+              "Sum of the current children's `revenue_share_bps`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "uri",
+            "docs": [
+              "Off-chain profile JSON (empty = none)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "docs": [
+              "Hash of the document at `uri`"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "attestedEarned",
+            "docs": [
+              "Portion of `total_earned` vouched for by the parent"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "adjustmentCount",
+            "docs": [
+              "Authority corrections applied via `adjust_earning`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastAdjustedAt",
+            "docs": [
+              "Last `adjust_earning` timestamp (0 = never adjusted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "tag",
+            "docs": [
+              "Mutable off-chain categorization label (zeros = untagged)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "isImmutable",
+            "docs": [
+              "Set once by `finalize_agent`; freezes the agent's configuration"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "isFrozen",
+            "docs": [
+              "Set once by `freeze_agent`; the account is read-only from then on"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "lastDistributedAt",
+            "docs": [
+              "Last `distribute_to_parent` timestamp (0 = never distributed)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "distributionCount",
+            "docs": [
+              "Successful `distribute_to_parent` calls"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "milestones",
+            "docs": [
+              "Unlocked `MILESTONE_*` bits; never cleared"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "streakDays",
+            "docs": [
+              "Consecutive UTC days with an earning or distribution, ending at `streak_last_day`"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "streakLastDay",
+            "docs": [
+              "UTC day (days since the Unix epoch) of the latest streak activity"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxSingleDistribution",
+            "docs": [
+              "Cap on a single outflow from the agent's wallet, set by that wallet (0 = unlimited)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxDailyDistribution",
+            "docs": [
+              "Cap on outflows from the agent's wallet per UTC day (0 = unlimited)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dailyDistributed",
+            "docs": [
+              "Sent out during `daily_distribution_day`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dailyDistributionDay",
+            "docs": [
+              "UTC day that `daily_distributed` covers"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Lifecycle state, changed only through `transition_status`. `is_active`",
+              "and `is_frozen` are kept in lockstep for existing readers."
+            ],
+            "type": {
+              "defined": {
+                "name": "agentStatus"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "agentClosed",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "rentDestination",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentDeactivated",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentDepthRecomputed",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldDepth",
+            "type": "u8"
+          },
+          {
+            "name": "newDepth",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentExpired",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentFinalized",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentFrozen",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "frozenBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentHeartbeat",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentLocked",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "lockedUntil",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentMarkedStale",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "lastActiveAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentMigrated",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentPromotedToRoot",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldParent",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldDepth",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentReactivated",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Gated actions are blocked until this timestamp"
+            ],
+            "name": "quarantineUntil",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentRegistered",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "specialization",
+            "type": "string"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "createdBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "`AgentRegistered` without name/specialization, emitted when `Registry.compact_events` is set"
+      ],
+      "name": "agentRegisteredCompact",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "createdBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentReparented",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldParent",
+            "type": "pubkey"
+          },
+          {
+            "name": "newParent",
+            "type": "pubkey"
+          },
+          {
+            "name": "newDepth",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentSpawned",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "childWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "specialization",
+            "type": "string"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "revenueShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "createdBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "`AgentSpawned` without name/specialization, emitted when `Registry.compact_events` is set"
+      ],
+      "name": "agentSpawnedCompact",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "childWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "revenueShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "createdBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentStatus",
+      "docs": [
+        "Agent lifecycle; see `transition_status` for the legal moves."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "deactivated"
+          },
+          {
+            "name": "frozen"
+          },
+          {
+            "name": "quarantined"
+          },
+          {
+            "name": "expired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "agentSummary",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "childrenCount",
+            "type": "u64"
+          },
+          {
+            "name": "isOperational",
+            "type": "bool"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "netPosition",
+            "type": "i128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "agentTtlExtended",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentUnlocked",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentUriUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "oldContentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "newContentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "agentsMerged",
+      "type": {
+        "fields": [
+          {
+            "name": "source",
+            "type": "pubkey"
+          },
+          {
+            "name": "target",
+            "type": "pubkey"
+          },
+          {
+            "name": "mergedEarned",
+            "type": "u64"
+          },
+          {
+            "name": "movedLamports",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "allowCpiSet",
+      "type": {
+        "fields": [
+          {
+            "name": "allowCpi",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "ancestry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isAncestor",
+            "type": "bool"
+          },
+          {
+            "name": "hops",
+            "docs": [
+              "Parent links from descendant to ancestor (0 when not an ancestor)"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityProposalCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "authorityProposed",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "authorityTransferred",
+      "type": {
+        "fields": [
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bond",
+      "docs": [
+        "SOL bond posted for an agent; lamports above rent are the bond."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondConfigSet",
+      "type": {
+        "fields": [
+          {
+            "name": "requiredBondLamports",
+            "type": "u64"
+          },
+          {
+            "name": "cooldownSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bondPosted",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bondSlashed",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bondWithdrawn",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bounty",
+      "docs": [
+        "Lamports escrowed by one agent for work by another; lamports above rent are the escrow."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "posterAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "bountyId",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "assignee",
+            "docs": [
+              "Only this agent may claim; `None` means open to any agent"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "claimant",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "descriptionHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "bountyStatus"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bountyCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "bounty",
+            "type": "pubkey"
+          },
+          {
+            "name": "posterAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bountyClaimRejected",
+      "type": {
+        "fields": [
+          {
+            "name": "bounty",
+            "type": "pubkey"
+          },
+          {
+            "name": "posterAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "claimantAgent",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bountyClaimed",
+      "type": {
+        "fields": [
+          {
+            "name": "bounty",
+            "type": "pubkey"
+          },
+          {
+            "name": "claimantAgent",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bountyPaid",
+      "type": {
+        "fields": [
+          {
+            "name": "bounty",
+            "type": "pubkey"
+          },
+          {
+            "name": "posterAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "claimantAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bucket",
+            "type": {
+              "defined": {
+                "name": "accountingBucket"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bountyPosted",
+      "type": {
+        "fields": [
+          {
+            "name": "bounty",
+            "type": "pubkey"
+          },
+          {
+            "name": "posterAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "bountyId",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "assignee",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bountyStatus",
+      "docs": [
+        "Bounty lifecycle: `Open -> Claimed -> Paid`, or `Open -> Cancelled`; a",
+        "rejected claim returns `Claimed -> Open`. Paying or cancelling closes the",
+        "account, so `Paid` and `Cancelled` are only seen within that instruction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "open"
+          },
+          {
+            "name": "claimed"
+          },
+          {
+            "name": "paid"
+          },
+          {
+            "name": "cancelled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bulkEarningsRecorded",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "chainDistributed",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Child's own share: `amount` minus what it passed to its parent"
+            ],
+            "name": "remainder",
+            "type": "u64"
+          },
+          {
+            "name": "distributed",
+            "type": "u64"
+          },
+          {
+            "name": "returnedToChild",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "childFunded",
+      "type": {
+        "fields": [
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "childrenCountReconciled",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldCount",
+            "type": "u64"
+          },
+          {
+            "name": "newCount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "commitmentMissed",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "missedCount",
+            "type": "u32"
+          },
+          {
+            "docs": [
+              "When the missed interval ended"
+            ],
+            "name": "dueAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "compactEventsSet",
+      "type": {
+        "fields": [
+          {
+            "name": "compactEvents",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "currencyMetadataSet",
+      "type": {
+        "fields": [
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "currencySymbol",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "derivedPda",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "directSplitPayment",
+      "type": {
+        "fields": [
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Default key for root agents"
+            ],
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "parentCut",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "distributionCommitment",
+      "docs": [
+        "A child's promise to distribute at least `min_amount` to its parent every",
+        "`interval_secs`; see `commit_distribution_schedule`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "intervalSecs",
+            "type": "i64"
+          },
+          {
+            "name": "lastSatisfiedAt",
+            "docs": [
+              "Last qualifying distribution, or the commitment's creation time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastFlaggedAt",
+            "docs": [
+              "Last `flag_missed_distribution` (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "missedCount",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "distributionCommitmentRevoked",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "missedCount",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "distributionCommitted",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "intervalSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "distributionLimitsSet",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "maxSingleDistribution",
+            "type": "u64"
+          },
+          {
+            "name": "maxDailyDistribution",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "dustSwept",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "earningAdjusted",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "delta",
+            "type": "i64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "earningCategory"
+              }
+            }
+          },
+          {
+            "name": "reasonCode",
+            "type": "u8"
+          },
+          {
+            "name": "oldTotal",
+            "type": "u64"
+          },
+          {
+            "name": "newTotal",
+            "type": "u64"
+          },
+          {
+            "name": "adjustmentCount",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "earningAttested",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "attestedTotal",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Source of a recorded earning; indexes `earned_by_category`."
+      ],
+      "name": "earningCategory",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "taskCompletion"
+          },
+          {
+            "name": "referral"
+          },
+          {
+            "name": "subscription"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "earningGoalSet",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "earningGoal",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "earningRecorded",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "epochEarned",
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "earningCategory"
+              }
+            }
+          },
+          {
+            "name": "bucket",
+            "type": {
+              "defined": {
+                "name": "accountingBucket"
+              }
+            }
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "emitMemosSet",
+      "type": {
+        "fields": [
+          {
+            "name": "emitMemos",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "epochBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "earnings",
+            "type": "u64"
+          },
+          {
+            "name": "spawns",
+            "type": "u64"
+          },
+          {
+            "name": "distributed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "forwardShareSet",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "forwardShareBps",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "globalStats",
+      "docs": [
+        "Program-wide counters, kept apart from `Registry` so that earning, spawning",
+        "and distribution instructions only read the config account."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalAgents",
+            "type": "u64"
+          },
+          {
+            "name": "totalReportedEarnings",
+            "docs": [
+              "Sum of self-reported `record_earning` amounts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSpawns",
+            "type": "u64"
+          },
+          {
+            "name": "totalTransferredVolume",
+            "docs": [
+              "Lamports actually moved by distribution and bounty instructions"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "earnedByCategory",
+            "docs": [
+              "Reported earnings per `EarningCategory`"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "depthCounts",
+            "docs": [
+              "Live agents at each depth, maintained on creation and pruning"
+            ],
+            "type": {
+              "array": [
+                "u32",
+                256
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalAttestedEarnings",
+            "docs": [
+              "Sum of parent-attested child earnings (see `attest_child_earning`)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recentEpochs",
+            "docs": [
+              "Activity for the most recent epochs, newest first; `recent_epochs[0]` is",
+              "the latest epoch anything was recorded in"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "epochBucket"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "eventSeq",
+            "docs": [
+              "Program-wide sequence number, advanced by every instruction that changes",
+              "state (other than the `migrate_*` layout upgrades, which can run before",
+              "this account exists). Events that carry a `seq` take the value at their",
+              "emission, so a gap between two of them means other changes happened in",
+              "between."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goalReached",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "earningGoal",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Registry configuration supplied to `initialize`. Defaults disable every",
+        "optional feature and select `MAX_DEPTH`."
+      ],
+      "name": "initializeParams",
+      "type": {
+        "fields": [
+          {
+            "name": "maxDepth",
+            "type": "u8"
+          },
+          {
+            "name": "strictNames",
+            "type": "bool"
+          },
+          {
+            "name": "pruneGraceSecs",
+            "type": "i64"
+          },
+          {
+            "name": "enforceWithdrawWhitelist",
+            "type": "bool"
+          },
+          {
+            "name": "requiredBondLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bondCooldownSecs",
+            "type": "i64"
+          },
+          {
+            "name": "staleThresholdSecs",
+            "type": "i64"
+          },
+          {
+            "name": "requireActiveLineage",
+            "type": "bool"
+          },
+          {
+            "name": "maxSingleEarning",
+            "type": "u64"
+          },
+          {
+            "name": "compactEvents",
+            "type": "bool"
+          },
+          {
+            "name": "requireBalanced",
+            "type": "bool"
+          },
+          {
+            "name": "branchingFactor",
+            "type": "u16"
+          },
+          {
+            "name": "proposalTtlSecs",
+            "type": "i64"
+          },
+          {
+            "name": "allowCpi",
+            "type": "bool"
+          },
+          {
+            "name": "reparentRequiresChild",
+            "type": "bool"
+          },
+          {
+            "name": "minActiveSecs",
+            "type": "i64"
+          },
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "reactivationQuarantineSecs",
+            "type": "i64"
+          },
+          {
+            "name": "quarantineGates",
+            "type": "u8"
+          },
+          {
+            "name": "maxBatchNodes",
+            "type": "u16"
+          },
+          {
+            "name": "emitMemos",
+            "type": "bool"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "currencySymbol",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "referralDurationSecs",
+            "type": "i64"
+          },
+          {
+            "name": "spawnLicenseMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enforceSiblingShareCap",
+            "type": "bool"
+          },
+          {
+            "name": "minSnapshotIntervalSecs",
+            "type": "i64"
+          },
+          {
+            "name": "adjustmentCooldownSecs",
+            "type": "i64"
+          },
+          {
+            "docs": [
+              "At most `MAX_NAMESPACE_LEN` bytes; fixed for the registry's lifetime"
+            ],
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "oracleOnlyEarnings",
+            "type": "bool"
+          },
+          {
+            "name": "strictLineageChecks",
+            "type": "bool"
+          },
+          {
+            "name": "minDistributionIntervalSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "leaderboard",
+      "docs": [
+        "Top earners by `total_earned`, sorted descending; empty slots are default entries."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "leaderboardEntry"
+                  }
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "leaderboardChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "entered",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Default key when the entrant took an empty slot"
+            ],
+            "name": "evicted",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "leaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "lineageDepth",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "maxDepth",
+            "type": "u8"
+          },
+          {
+            "name": "levelsBelow",
+            "docs": [
+              "Further generations that may still be spawned below this agent"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "maxBatchNodesSet",
+      "type": {
+        "fields": [
+          {
+            "name": "maxBatchNodes",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "maxDepthSet",
+      "type": {
+        "fields": [
+          {
+            "name": "maxDepth",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "maxSingleEarningSet",
+      "type": {
+        "fields": [
+          {
+            "name": "maxSingleEarning",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "milestoneUnlocked",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "`MILESTONE_*` bit index"
+            ],
+            "name": "milestone",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "minActiveSecsSet",
+      "type": {
+        "fields": [
+          {
+            "name": "minActiveSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "minDistributionIntervalSet",
+      "type": {
+        "fields": [
+          {
+            "name": "minDistributionIntervalSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "minSnapshotIntervalSet",
+      "type": {
+        "fields": [
+          {
+            "name": "minSnapshotIntervalSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "nameReservation",
+      "docs": [
+        "Holds an agent name for `reserver` until `expires_at`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reserver",
+            "type": "pubkey"
+          },
+          {
+            "name": "nameHash",
+            "docs": [
+              "SHA-256 of the reserved name"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nameReservationCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "reserver",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "nameReservationReleased",
+      "type": {
+        "fields": [
+          {
+            "name": "reserver",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "oracleAdded",
+      "type": {
+        "fields": [
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "oracleEarningRecorded",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "oracleOnlyEarningsSet",
+      "type": {
+        "fields": [
+          {
+            "name": "oracleOnly",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "oracleRecord",
+      "docs": [
+        "Marks `oracle` as approved to record earnings on agents' behalf. Exists only",
+        "while approved; `remove_oracle` closes it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          },
+          {
+            "name": "addedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleRemoved",
+      "type": {
+        "fields": [
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "positionReport",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "totalDistributedToParent",
+            "type": "u64"
+          },
+          {
+            "name": "totalReceivedFromChildren",
+            "type": "u64"
+          },
+          {
+            "name": "netPosition",
+            "type": "i128"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "projectedShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "projectedSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "projectedShare"
+                }
+              }
+            }
+          },
+          {
+            "name": "retained",
+            "docs": [
+              "Left with the agent after all recipients"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalTtlSet",
+      "type": {
+        "fields": [
+          {
+            "name": "ttlSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "pruneCompleted",
+      "type": {
+        "fields": [
+          {
+            "name": "pruned",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "pruneGraceSet",
+      "type": {
+        "fields": [
+          {
+            "name": "graceSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "reactivationQuarantineSet",
+      "type": {
+        "fields": [
+          {
+            "name": "reactivationQuarantineSecs",
+            "type": "i64"
+          },
+          {
+            "name": "quarantineGates",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "reconciliationReport",
+      "type": {
+        "fields": [
+          {
+            "name": "totalReportedEarnings",
+            "type": "u64"
+          },
+          {
+            "name": "totalTransferredVolume",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Reported minus transferred"
+            ],
+            "name": "difference",
+            "type": "i128"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "referral",
+      "docs": [
+        "The agent that introduced an external payer."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "referringAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Timestamp from which the referral pays nothing (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralConfigSet",
+      "type": {
+        "fields": [
+          {
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "referralDurationSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "referralPaid",
+      "type": {
+        "fields": [
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "referringAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "totalReferralEarnings",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "referralRegistered",
+      "type": {
+        "fields": [
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "referringAgent",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "registerRootAgentArgs",
+      "docs": [
+        "Arguments to `register_root_agent_v2`.",
+        "",
+        "`name` and `specialization` must stay the leading fields: `RegisterRootAgent`",
+        "reads them through `#[instruction]` to derive the spec counter seed, and a",
+        "struct's Borsh encoding starts with its fields in order."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "specialization",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAgents",
+            "docs": [
+              "Legacy; superseded by `GlobalStats` (see `split_stats`)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalReportedEarnings",
+            "docs": [
+              "Legacy; superseded by `GlobalStats` (see `split_stats`)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSpawns",
+            "docs": [
+              "Legacy; superseded by `GlobalStats` (see `split_stats`)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "strictNames",
+            "docs": [
+              "Restrict agent names to URL-safe characters"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "maxDepth",
+            "docs": [
+              "Max agent tree depth, at most `MAX_DEPTH_CEILING`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pruneGraceSecs",
+            "docs": [
+              "Seconds a deactivated agent must wait before `prune_inactive` may close it"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "enforceWithdrawWhitelist",
+            "docs": [
+              "Enforce per-agent withdrawal whitelists on outgoing transfers"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "requiredBondLamports",
+            "docs": [
+              "SOL bond new agents must post (0 = no bond)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bondCooldownSecs",
+            "docs": [
+              "Seconds after voluntary deactivation before a bond can be withdrawn"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "staleThresholdSecs",
+            "docs": [
+              "Seconds without activity after which an agent may be marked stale"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "requireActiveLineage",
+            "docs": [
+              "Block earnings/distributions by non-root agents whose parent is inactive"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "maxSingleEarning",
+            "docs": [
+              "Largest amount a single earning may report (0 = unlimited)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "compactEvents",
+            "docs": [
+              "Emit compact creation events without name/specialization strings"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "requireBalanced",
+            "docs": [
+              "Reject spawns that would grow a depth level before the one above it is filled"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "branchingFactor",
+            "docs": [
+              "Children per agent a level must average before the next level may grow"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "depthCounts",
+            "docs": [
+              "Legacy; superseded by `GlobalStats` (see `split_stats`)"
+            ],
+            "type": {
+              "array": [
+                "u32",
+                17
+              ]
+            }
+          },
+          {
+            "name": "totalTransferredVolume",
+            "docs": [
+              "Legacy; superseded by `GlobalStats` (see `split_stats`)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingAuthority",
+            "docs": [
+              "Proposed next authority awaiting `accept_authority`"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "authorityProposalTs",
+            "docs": [
+              "When the pending authority was proposed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "proposalTtlSecs",
+            "docs": [
+              "Seconds a proposal stays acceptable (0 = no expiry)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "allowCpi",
+            "docs": [
+              "Allow protected instructions to be invoked via CPI"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reparentRequiresChild",
+            "docs": [
+              "`reparent_agent` also requires the child wallet's signature"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "minActiveSecs",
+            "docs": [
+              "Seconds after creation before an agent's wallet may deactivate it"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "earnedByCategory",
+            "docs": [
+              "Legacy; superseded by `GlobalStats` (see `split_stats`)"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "reporter",
+            "docs": [
+              "Oracle allowed to call `batch_record_earnings` (default = none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reactivationQuarantineSecs",
+            "docs": [
+              "Seconds after `reactivate_agent` during which gated actions are blocked"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "quarantineGates",
+            "docs": [
+              "`QUARANTINE_*` bits selecting which actions the quarantine blocks"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "maxBatchNodes",
+            "docs": [
+              "Max `remaining_accounts` per traversal instruction (0 = no registry cap)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "emitMemos",
+            "docs": [
+              "CPI the SPL Memo program on `record_earning` and `distribute_to_parent`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals of the unit amounts are tracked in (display only)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "currencySymbol",
+            "docs": [
+              "Currency symbol, ASCII zero-padded (display only)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "referralBps",
+            "docs": [
+              "Share of `pay_agent_with_split` payments paid to the payer's referring agent"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "referralDurationSecs",
+            "docs": [
+              "Seconds a referral stays active after registration (0 = forever)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "spawnLicenseMint",
+            "docs": [
+              "Mint whose token a parent wallet must hold to spawn (default = no gate)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "enforceSiblingShareCap",
+            "docs": [
+              "Reject spawns and reparents that push a parent's `children_share_sum` past 10000"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "minSnapshotIntervalSecs",
+            "docs": [
+              "Minimum seconds between `take_snapshot` calls (0 = no limit)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "adjustmentCooldownSecs",
+            "docs": [
+              "Minimum seconds between `adjust_earning` calls on one agent (0 = no limit)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Seed suffix separating independent trees under one deployment (empty = default tree)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "oracleOnlyEarnings",
+            "docs": [
+              "Only approved oracles may record earnings (see `record_earning_by_oracle`)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "strictLineageChecks",
+            "docs": [
+              "`spawn_child` rejects a child wallet reused from any ancestor supplied in",
+              "`remaining_accounts`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "minDistributionIntervalSecs",
+            "docs": [
+              "Minimum seconds between `distribute_to_parent` calls by one agent (0 = no limit)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registryInitialized",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Effective configuration, with defaults applied"
+            ],
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "initializeParams"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "registryMigrated",
+      "type": {
+        "fields": [
+          {
+            "name": "fromSize",
+            "type": "u32"
+          },
+          {
+            "name": "toSize",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "registryStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalAgents",
+            "type": "u64"
+          },
+          {
+            "name": "totalSpawns",
+            "type": "u64"
+          },
+          {
+            "name": "totalReportedEarnings",
+            "type": "u64"
+          },
+          {
+            "name": "totalTransferredVolume",
+            "type": "u64"
+          },
+          {
+            "name": "maxDepth",
+            "type": "u8"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "currencySymbol",
+            "docs": [
+              "ASCII, zero-padded"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "eventSeq",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reparentPolicySet",
+      "type": {
+        "fields": [
+          {
+            "name": "reparentRequiresChild",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
     {
-      "code": 6000,
-      "name": "nameTooLong",
-      "msg": "Agent name exceeds maximum length"
+      "name": "reporterSet",
+      "type": {
+        "fields": [
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6001,
-      "name": "specTooLong",
-      "msg": "Specialization exceeds maximum length"
+      "name": "requireActiveLineageSet",
+      "type": {
+        "fields": [
+          {
+            "name": "requireActiveLineage",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidRevenueShare",
-      "msg": "Revenue share basis points must be <= 10000"
+      "name": "revenueDistributed",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Part of the split's base kept by `child` (rounding dust included)"
+            ],
+            "name": "remainder",
+            "type": "u64"
+          },
+          {
+            "name": "totalDistributed",
+            "type": "u64"
+          },
+          {
+            "name": "totalReceivedByParent",
+            "type": "u64"
+          },
+          {
+            "name": "directToRoot",
+            "type": "bool"
+          },
+          {
+            "name": "bucket",
+            "type": {
+              "defined": {
+                "name": "accountingBucket"
+              }
+            }
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6003,
-      "name": "agentInactive",
-      "msg": "Agent is not active"
+      "name": "secondaryRevenueDistributed",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "secondary",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "totalDistributed",
+            "type": "u64"
+          },
+          {
+            "name": "bucket",
+            "type": {
+              "defined": {
+                "name": "accountingBucket"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6004,
-      "name": "maxDepthReached",
-      "msg": "Maximum agent tree depth reached"
+      "name": "siblingShareCapSet",
+      "type": {
+        "fields": [
+          {
+            "name": "enforce",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6005,
-      "name": "zeroAmount",
-      "msg": "Amount must be greater than zero"
+      "name": "skippedInactiveAncestor",
+      "type": {
+        "fields": [
+          {
+            "name": "child",
+            "type": "pubkey"
+          },
+          {
+            "name": "skipped",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Active ancestor that received the portion; `None` if it stayed with the child"
+            ],
+            "name": "routedTo",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6006,
-      "name": "noParentAgent",
-      "msg": "Agent has no parent"
-    }
-  ],
-  "types": [
+      "name": "snapshotEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
-      "name": "agentAccount",
+      "name": "snapshotLog",
+      "docs": [
+        "Ring buffer of recent `take_snapshot` hashes."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "wallet",
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "snapshotEntry"
+                  }
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "docs": [
+              "Snapshots taken so far; the next one goes to `entries[count % SNAPSHOT_LOG_LEN]`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTakenAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "snapshotTaken",
+      "type": {
+        "fields": [
+          {
+            "name": "hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "totalAgents",
+            "type": "u64"
+          },
+          {
+            "name": "totalReportedEarnings",
+            "type": "u64"
+          },
+          {
+            "name": "totalSpawns",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Sequence number of this snapshot in the log"
+            ],
+            "name": "index",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "spawnChildArgs",
+      "docs": [
+        "Arguments to `spawn_child_v2`. As with `RegisterRootAgentArgs`, `name` and",
+        "`specialization` must stay the leading fields."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "specialization",
+            "type": "string"
+          },
+          {
+            "name": "revenueShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "secondaryBeneficiary",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "secondaryShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "earningGoal",
+            "type": "u64"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "spawnLicenseMintSet",
+      "type": {
+        "fields": [
+          {
+            "name": "spawnLicenseMint",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "spawnProbe",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowed",
+            "type": "bool"
+          },
+          {
+            "name": "errorCode",
+            "docs": [
+              "`HydraError` code of the first failed check (0 when allowed)"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spawnResult",
+      "docs": [
+        "Return data of `spawn_child` and `spawn_child_v2`, so clients need not",
+        "re-derive the child PDA or fetch it to learn its depth."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "child",
             "type": "pubkey"
           },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spawnTemplate",
+      "docs": [
+        "Child parameters a parent can stamp out repeatedly with `spawn_from_template`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "parent",
             "type": "pubkey"
           },
           {
-            "name": "name",
+            "name": "templateId",
+            "type": "u32"
+          },
+          {
+            "name": "namePrefix",
+            "docs": [
+              "Prepended to each spawn's `name_suffix`"
+            ],
             "type": "string"
           },
           {
@@ -630,32 +15817,92 @@ export type Hydra = {
             "type": "string"
           },
           {
-            "name": "totalEarned",
-            "type": "u64"
+            "name": "revenueShareBps",
+            "type": "u16"
           },
           {
-            "name": "totalDistributedToParent",
-            "type": "u64"
+            "name": "remainingUses",
+            "type": "u32"
           },
           {
-            "name": "childrenCount",
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spawnTemplateCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "parent",
+            "type": "pubkey"
           },
           {
-            "name": "depth",
-            "type": "u8"
+            "name": "template",
+            "type": "pubkey"
+          },
+          {
+            "name": "templateId",
+            "type": "u32"
           },
           {
             "name": "revenueShareBps",
             "type": "u16"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "maxUses",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "specCountChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "specHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "createdAt",
-            "type": "i64"
+            "name": "count",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "specCounter",
+      "docs": [
+        "Number of agents registered under one specialization."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "specHash",
+            "docs": [
+              "SHA-256 of the specialization string"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u64"
           },
           {
             "name": "bump",
@@ -665,7 +15912,10 @@ export type Hydra = {
       }
     },
     {
-      "name": "agentDeactivated",
+      "name": "splitConfig",
+      "docs": [
+        "Custom revenue-split recipients for an agent."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -674,89 +15924,232 @@ export type Hydra = {
             "type": "pubkey"
           },
           {
-            "name": "wallet",
-            "type": "pubkey"
+            "name": "recipients",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "splitRecipient"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "agentRegistered",
+      "name": "splitDistributed",
       "type": {
-        "kind": "struct",
         "fields": [
           {
             "name": "agent",
             "type": "pubkey"
           },
           {
-            "name": "wallet",
+            "name": "recipient",
             "type": "pubkey"
           },
           {
-            "name": "parent",
-            "type": "pubkey"
+            "name": "bps",
+            "type": "u16"
           },
           {
-            "name": "name",
-            "type": "string"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "specialization",
-            "type": "string"
+            "name": "bucket",
+            "type": {
+              "defined": {
+                "name": "accountingBucket"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "splitRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
           },
           {
-            "name": "depth",
-            "type": "u8"
+            "name": "bps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "agentSpawned",
+      "name": "staleThresholdSet",
       "type": {
-        "kind": "struct",
         "fields": [
           {
-            "name": "child",
+            "name": "thresholdSecs",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "statsSplit",
+      "type": {
+        "fields": [
+          {
+            "name": "totalAgents",
+            "type": "u64"
+          },
+          {
+            "name": "totalReportedEarnings",
+            "type": "u64"
+          },
+          {
+            "name": "totalSpawns",
+            "type": "u64"
+          },
+          {
+            "name": "totalTransferredVolume",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "strictLineageChecksSet",
+      "type": {
+        "fields": [
+          {
+            "name": "strict",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "strictNamesSet",
+      "type": {
+        "fields": [
+          {
+            "name": "strictNames",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "subtreeEarningRolledUp",
+      "type": {
+        "fields": [
+          {
+            "name": "from",
             "type": "pubkey"
           },
           {
-            "name": "parent",
+            "name": "to",
             "type": "pubkey"
           },
           {
-            "name": "childWallet",
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "tagChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
             "type": "pubkey"
           },
           {
-            "name": "name",
-            "type": "string"
+            "name": "oldTag",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
           },
           {
-            "name": "specialization",
-            "type": "string"
+            "name": "newTag",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "taskLog",
+      "docs": [
+        "Ring buffer of an agent's recent `record_earnings_bulk` task hashes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
           },
           {
-            "name": "depth",
-            "type": "u8"
+            "name": "hashes",
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                32
+              ]
+            }
           },
           {
-            "name": "revenueShareBps",
-            "type": "u16"
+            "name": "count",
+            "docs": [
+              "Hashes recorded so far; the next one goes to `hashes[count % TASK_LOG_LEN]`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "earningRecorded",
+      "name": "tokenEarningRecorded",
       "type": {
-        "kind": "struct",
         "fields": [
           {
             "name": "agent",
             "type": "pubkey"
           },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
           {
             "name": "amount",
             "type": "u64"
@@ -765,28 +16158,32 @@ export type Hydra = {
             "name": "totalEarned",
             "type": "u64"
           }
-        ]
+        ],
+        "kind": "struct"
       }
     },
     {
-      "name": "registry",
+      "name": "tokenEarnings",
+      "docs": [
+        "Per-mint earnings for one agent, kept apart from SOL `total_earned`."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "agent",
             "type": "pubkey"
           },
           {
-            "name": "totalAgents",
-            "type": "u64"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "totalEarnings",
+            "name": "totalEarned",
             "type": "u64"
           },
           {
-            "name": "totalSpawns",
+            "name": "totalDistributed",
             "type": "u64"
           },
           {
@@ -797,16 +16194,76 @@ export type Hydra = {
       }
     },
     {
-      "name": "revenueDistributed",
+      "name": "treeBalanceSet",
+      "type": {
+        "fields": [
+          {
+            "name": "requireBalanced",
+            "type": "bool"
+          },
+          {
+            "name": "branchingFactor",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "walletAgentCounter",
+      "docs": [
+        "Next free agent index for a wallet operating indexed agents."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "child",
+            "name": "wallet",
             "type": "pubkey"
           },
           {
-            "name": "parent",
+            "name": "nextIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "waterfallHop",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Amount passed up to this agent"
+            ],
+            "name": "received",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Amount this agent keeps after passing its own share upward"
+            ],
+            "name": "retained",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "waterfallReport",
+      "type": {
+        "fields": [
+          {
+            "name": "leaf",
             "type": "pubkey"
           },
           {
@@ -814,10 +16271,54 @@ export type Hydra = {
             "type": "u64"
           },
           {
-            "name": "totalDistributed",
+            "name": "leafResidual",
             "type": "u64"
+          },
+          {
+            "name": "hops",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "waterfallHop"
+                }
+              }
+            }
           }
-        ]
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "withdrawWhitelistEnforcementSet",
+      "type": {
+        "fields": [
+          {
+            "name": "enforce",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "withdrawWhitelistSet",
+      "type": {
+        "fields": [
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "whitelist",
+            "type": {
+              "array": [
+                "pubkey",
+                3
+              ]
+            }
+          }
+        ],
+        "kind": "struct"
       }
     }
   ]
@@ -1,397 +1,9601 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::program::invoke;
 use anchor_lang::system_program;
+use solana_sha256_hasher::hash;
 
 declare_id!("HmHxoZHi5GN3187RoXPDAXcjY5j1ghTdXn54u9pVzrvp");
 
-/// Max depth of agent tree to prevent unbounded recursion
+/// Default max depth of agent tree, used when `initialize` is given 0
 const MAX_DEPTH: u8 = 5;
+/// Absolute ceiling for a registry's configurable max depth; the full `u8` range
+const MAX_DEPTH_CEILING: u8 = u8::MAX;
 /// Max name length
 const MAX_NAME_LEN: usize = 32;
 /// Max specialization length
 const MAX_SPEC_LEN: usize = 64;
+/// Max custom split recipients per agent
+const MAX_SPLIT_RECIPIENTS: usize = 4;
+/// Max withdrawal destinations in an agent's whitelist
+const MAX_WHITELIST_LEN: usize = 3;
+/// Entries kept on the earnings leaderboard
+const LEADERBOARD_LEN: usize = 10;
+/// Slots in per-category earnings breakdowns (room for future `EarningCategory` variants)
+const EARNING_CATEGORIES: usize = 8;
+/// Recent per-epoch activity buckets kept on `GlobalStats.recent_epochs`
+const EPOCH_BUCKETS: usize = 8;
+/// Spare bytes allocated on account creation beyond `SIZE`, so later appended
+/// fields fit new accounts without a realloc
+const RESERVED: usize = 64;
+/// Max candidates per `list_children` call; the returned wallets must fit in
+/// the 1024 bytes of return data
+const MAX_LISTED_CHILDREN: usize = 31;
+/// Max agents per `batch_record_earnings` call
+const MAX_BATCH_LEN: usize = 16;
+/// Max amounts per `record_earnings_bulk` call
+const MAX_BULK_EARNINGS: usize = 32;
+/// Recent task hashes kept in an agent's `TaskLog` ring buffer
+const TASK_LOG_LEN: usize = 32;
+/// Max length of a bond slashing reason
+const MAX_REASON_LEN: usize = 64;
+/// Max length of an agent's off-chain profile URI
+const MAX_URI_LEN: usize = 200;
+/// Max length of a registry namespace
+const MAX_NAMESPACE_LEN: usize = 16;
+/// Recent hashes kept in the `SnapshotLog` ring buffer
+const SNAPSHOT_LOG_LEN: usize = 16;
+/// Number of depth levels tracked by `GlobalStats.depth_counts` (depths 0..=MAX_DEPTH_CEILING).
+/// Fixed at the ceiling rather than the configured max so the account size never changes.
+const DEPTH_LEVELS: usize = MAX_DEPTH_CEILING as usize + 1;
+/// Depth levels in the legacy `Registry.depth_counts`, from when the ceiling was 16
+const LEGACY_DEPTH_LEVELS: usize = 17;
+/// Current `AgentAccount` layout version; bump whenever fields are appended
+pub const AGENT_SCHEMA_VERSION: u8 = 23;
+/// `Registry.quarantine_gates` bit: block earnings during reactivation quarantine
+pub const QUARANTINE_EARNINGS: u8 = 1 << 0;
+/// `Registry.quarantine_gates` bit: block distributions during reactivation quarantine
+pub const QUARANTINE_DISTRIBUTIONS: u8 = 1 << 1;
+/// `Registry.quarantine_gates` bit: block spawning during reactivation quarantine
+pub const QUARANTINE_SPAWNS: u8 = 1 << 2;
+/// `AgentAccount.milestones` bit index: first recorded earning
+pub const MILESTONE_FIRST_EARNING: u8 = 0;
+/// `AgentAccount.milestones` bit index: tenth distribution to the parent
+pub const MILESTONE_TEN_DISTRIBUTIONS: u8 = 1;
+/// `AgentAccount.milestones` bit index: active on 30 consecutive UTC days
+pub const MILESTONE_THIRTY_DAY_STREAK: u8 = 2;
+/// Seconds per UTC day bucket used for activity streaks
+const SECONDS_PER_DAY: i64 = 86_400;
+/// How long a `reserve_name` reservation holds its name
+const NAME_RESERVATION_SECS: i64 = 7 * SECONDS_PER_DAY;
 
 #[program]
 pub mod hydra {
     use super::*;
 
-    /// Initialize the Hydra registry. Called once.
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    /// Initialize the Hydra registry with its full configuration. Called once.
+    /// A `max_depth` of 0 selects the default `MAX_DEPTH`; other zero values
+    /// leave the corresponding feature disabled.
+    pub fn initialize(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
+        let max_depth = if params.max_depth == 0 { MAX_DEPTH } else { params.max_depth };
+
+        check_limit(
+            "namespace length",
+            params.namespace.len(),
+            MAX_NAMESPACE_LEN,
+            HydraError::NamespaceTooLong,
+        )?;
+
         let registry = &mut ctx.accounts.registry;
         registry.authority = ctx.accounts.authority.key();
-        registry.total_agents = 0;
-        registry.total_earnings = 0;
-        registry.total_spawns = 0;
         registry.bump = ctx.bumps.registry;
+        registry.strict_names = params.strict_names;
+        registry.max_depth = max_depth;
+        registry.prune_grace_secs = params.prune_grace_secs;
+        registry.enforce_withdraw_whitelist = params.enforce_withdraw_whitelist;
+        registry.required_bond_lamports = params.required_bond_lamports;
+        registry.bond_cooldown_secs = params.bond_cooldown_secs;
+        registry.stale_threshold_secs = params.stale_threshold_secs;
+        registry.require_active_lineage = params.require_active_lineage;
+        registry.max_single_earning = params.max_single_earning;
+        registry.compact_events = params.compact_events;
+        registry.require_balanced = params.require_balanced;
+        registry.branching_factor = params.branching_factor;
+        registry.pending_authority = None;
+        registry.authority_proposal_ts = 0;
+        registry.proposal_ttl_secs = params.proposal_ttl_secs;
+        registry.allow_cpi = params.allow_cpi;
+        registry.reparent_requires_child = params.reparent_requires_child;
+        registry.min_active_secs = params.min_active_secs;
+        registry.reporter = params.reporter;
+        registry.reactivation_quarantine_secs = params.reactivation_quarantine_secs;
+        registry.quarantine_gates = params.quarantine_gates;
+        registry.max_batch_nodes = params.max_batch_nodes;
+        registry.emit_memos = params.emit_memos;
+        registry.decimals = params.decimals;
+        registry.currency_symbol = params.currency_symbol;
+        registry.referral_bps = params.referral_bps;
+        registry.referral_duration_secs = params.referral_duration_secs;
+        registry.spawn_license_mint = params.spawn_license_mint;
+        registry.enforce_sibling_share_cap = params.enforce_sibling_share_cap;
+        registry.min_snapshot_interval_secs = params.min_snapshot_interval_secs;
+        registry.oracle_only_earnings = params.oracle_only_earnings;
+        registry.strict_lineage_checks = params.strict_lineage_checks;
+        registry.min_distribution_interval_secs = params.min_distribution_interval_secs;
+        registry.adjustment_cooldown_secs = params.adjustment_cooldown_secs;
+        registry.namespace = params.namespace.clone();
+        validate_registry_config(registry)?;
+
+        ctx.accounts.stats.bump = ctx.bumps.stats;
+
+        emit!(RegistryInitialized {
+            authority: registry.authority,
+            config: InitializeParams { max_depth, ..params },
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
         Ok(())
     }
 
-    /// Register the root agent (no parent). Only callable by registry authority.
-    pub fn register_root_agent(
-        ctx: Context<RegisterRootAgent>,
-        name: String,
-        specialization: String,
+    /// Move a pre-existing registry's counters into a new `GlobalStats` account,
+    /// zeroing the legacy `Registry` fields. Authority only; runs once, since the
+    /// stats account can only be created once.
+    pub fn split_stats(ctx: Context<SplitStats>, _namespace: String) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let stats = &mut ctx.accounts.stats;
+        stats.total_agents = std::mem::take(&mut registry.total_agents);
+        stats.total_reported_earnings = std::mem::take(&mut registry.total_reported_earnings);
+        stats.total_spawns = std::mem::take(&mut registry.total_spawns);
+        stats.total_transferred_volume = std::mem::take(&mut registry.total_transferred_volume);
+        stats.earned_by_category = std::mem::take(&mut registry.earned_by_category);
+        let legacy_depths = std::mem::replace(&mut registry.depth_counts, [0; LEGACY_DEPTH_LEVELS]);
+        stats.depth_counts[..LEGACY_DEPTH_LEVELS].copy_from_slice(&legacy_depths);
+        stats.bump = ctx.bumps.stats;
+
+        emit!(StatsSplit {
+            total_agents: stats.total_agents,
+            total_reported_earnings: stats.total_reported_earnings,
+            total_spawns: stats.total_spawns,
+            total_transferred_volume: stats.total_transferred_volume,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Initialize the registry with the default configuration.
+    pub fn initialize_default(ctx: Context<Initialize>) -> Result<()> {
+        initialize(ctx, InitializeParams::default())
+    }
+
+    /// Set the trusted reporter allowed to call `batch_record_earnings`
+    /// (`Pubkey::default()` disables batching). Authority only.
+    pub fn set_reporter(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        reporter: Pubkey,
     ) -> Result<()> {
-        require!(name.len() <= MAX_NAME_LEN, HydraError::NameTooLong);
-        require!(specialization.len() <= MAX_SPEC_LEN, HydraError::SpecTooLong);
+        let registry = &mut ctx.accounts.registry;
+        registry.reporter = reporter;
 
-        let agent = &mut ctx.accounts.agent;
-        agent.wallet = ctx.accounts.wallet.key();
-        agent.parent = Pubkey::default();
-        agent.name = name;
-        agent.specialization = specialization;
-        agent.total_earned = 0;
-        agent.total_distributed_to_parent = 0;
-        agent.children_count = 0;
-        agent.depth = 0;
-        agent.revenue_share_bps = 0;
-        agent.is_active = true;
-        agent.created_at = Clock::get()?.unix_timestamp;
-        agent.bump = ctx.bumps.agent;
+        emit!(ReporterSet { reporter });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
 
+    /// Approve `oracle` to call `record_earning_by_oracle`. Authority only.
+    pub fn add_oracle(ctx: Context<AddOracle>, _namespace: String, oracle: Pubkey) -> Result<()> {
+        let record = &mut ctx.accounts.oracle_record;
+        record.oracle = oracle;
+        record.added_at = Clock::get()?.unix_timestamp;
+        record.bump = ctx.bumps.oracle_record;
+
+        emit!(OracleAdded { oracle });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Revoke an oracle by closing its record; it can no longer record earnings
+    /// from the next transaction on. Authority only.
+    pub fn remove_oracle(
+        _ctx: Context<RemoveOracle>,
+        _namespace: String,
+        oracle: Pubkey,
+    ) -> Result<()> {
+        emit!(OracleRemoved { oracle });
+
+        Ok(())
+    }
+
+    /// Toggle whether earnings may only be recorded by approved oracles, which
+    /// disables the self-reported `record_earning` path. Authority only.
+    pub fn set_oracle_only_earnings(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        oracle_only: bool,
+    ) -> Result<()> {
         let registry = &mut ctx.accounts.registry;
-        registry.total_agents = registry.total_agents.checked_add(1).unwrap();
+        registry.oracle_only_earnings = oracle_only;
 
-        emit!(AgentRegistered {
-            agent: agent.key(),
-            wallet: agent.wallet,
-            parent: Pubkey::default(),
-            name: agent.name.clone(),
-            specialization: agent.specialization.clone(),
-            depth: 0,
-        });
+        emit!(OracleOnlyEarningsSet { oracle_only });
+
+        ctx.accounts.stats.next_event_seq();
 
         Ok(())
     }
 
-    /// Parent agent spawns a child agent.
-    pub fn spawn_child(
-        ctx: Context<SpawnChild>,
-        name: String,
-        specialization: String,
-        revenue_share_bps: u16,
+    /// Set how long an agent must exist before its own wallet may deactivate it
+    /// (0 = no minimum). Authority only.
+    pub fn set_min_active_secs(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        min_active_secs: i64,
     ) -> Result<()> {
-        require!(name.len() <= MAX_NAME_LEN, HydraError::NameTooLong);
-        require!(specialization.len() <= MAX_SPEC_LEN, HydraError::SpecTooLong);
-        require!(revenue_share_bps <= 10_000, HydraError::InvalidRevenueShare);
+        let registry = &mut ctx.accounts.registry;
+        registry.min_active_secs = min_active_secs;
+        validate_registry_config(registry)?;
 
-        let parent = &ctx.accounts.parent_agent;
-        require!(parent.is_active, HydraError::AgentInactive);
-        require!(parent.depth < MAX_DEPTH, HydraError::MaxDepthReached);
+        emit!(MinActiveSecsSet { min_active_secs });
 
-        let child = &mut ctx.accounts.child_agent;
-        child.wallet = ctx.accounts.child_wallet.key();
-        child.parent = ctx.accounts.parent_agent.key();
-        child.name = name.clone();
-        child.specialization = specialization.clone();
-        child.total_earned = 0;
-        child.total_distributed_to_parent = 0;
-        child.children_count = 0;
-        child.depth = parent.depth.checked_add(1).unwrap();
-        child.revenue_share_bps = revenue_share_bps;
-        child.is_active = true;
-        child.created_at = Clock::get()?.unix_timestamp;
-        child.bump = ctx.bumps.child_agent;
+        ctx.accounts.stats.next_event_seq();
 
-        let parent_agent = &mut ctx.accounts.parent_agent;
-        parent_agent.children_count = parent_agent.children_count.checked_add(1).unwrap();
+        Ok(())
+    }
 
+    /// Set the quarantine applied after `reactivate_agent` and which actions it
+    /// blocks (`QUARANTINE_*` bits). Authority only.
+    pub fn set_reactivation_quarantine(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        reactivation_quarantine_secs: i64,
+        quarantine_gates: u8,
+    ) -> Result<()> {
         let registry = &mut ctx.accounts.registry;
-        registry.total_agents = registry.total_agents.checked_add(1).unwrap();
-        registry.total_spawns = registry.total_spawns.checked_add(1).unwrap();
+        registry.reactivation_quarantine_secs = reactivation_quarantine_secs;
+        registry.quarantine_gates = quarantine_gates;
+        validate_registry_config(registry)?;
 
-        emit!(AgentSpawned {
-            child: child.key(),
-            parent: ctx.accounts.parent_agent.key(),
-            child_wallet: child.wallet,
-            name,
-            specialization,
-            depth: child.depth,
-            revenue_share_bps,
+        emit!(ReactivationQuarantineSet {
+            reactivation_quarantine_secs,
+            quarantine_gates,
         });
 
+        ctx.accounts.stats.next_event_seq();
+
         Ok(())
     }
 
-    /// Record earnings for an agent (called by agent's own wallet).
-    pub fn record_earning(ctx: Context<RecordEarning>, amount: u64) -> Result<()> {
-        require!(amount > 0, HydraError::ZeroAmount);
+    /// Set the cap on `remaining_accounts` for traversal instructions (0 = only
+    /// the per-instruction limits apply). Authority only.
+    pub fn set_max_batch_nodes(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        max_batch_nodes: u16,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.max_batch_nodes = max_batch_nodes;
 
-        let agent = &mut ctx.accounts.agent;
-        require!(agent.is_active, HydraError::AgentInactive);
+        emit!(MaxBatchNodesSet { max_batch_nodes });
 
-        agent.total_earned = agent.total_earned.checked_add(amount).unwrap();
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
 
+    /// Toggle whether `reparent_agent` also needs the child wallet's signature. Authority only.
+    pub fn set_reparent_requires_child(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        reparent_requires_child: bool,
+    ) -> Result<()> {
         let registry = &mut ctx.accounts.registry;
-        registry.total_earnings = registry.total_earnings.checked_add(amount).unwrap();
+        registry.reparent_requires_child = reparent_requires_child;
 
-        emit!(EarningRecorded {
-            agent: agent.key(),
-            amount,
-            total_earned: agent.total_earned,
+        emit!(ReparentPolicySet {
+            reparent_requires_child,
         });
 
+        ctx.accounts.stats.next_event_seq();
+
         Ok(())
     }
 
-    /// Distribute SOL revenue from child to parent via system transfer.
-    pub fn distribute_to_parent(ctx: Context<DistributeToParent>, amount: u64) -> Result<()> {
-        require!(amount > 0, HydraError::ZeroAmount);
+    /// Create the registry-wide top earners leaderboard. Authority only.
+    pub fn initialize_leaderboard(
+        ctx: Context<InitializeLeaderboard>,
+        _namespace: String,
+    ) -> Result<()> {
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        leaderboard.entries = [LeaderboardEntry::default(); LEADERBOARD_LEN];
+        leaderboard.bump = ctx.bumps.leaderboard;
+        ctx.accounts.stats.next_event_seq();
+        Ok(())
+    }
 
-        let child = &ctx.accounts.child_agent;
-        require!(child.is_active, HydraError::AgentInactive);
-        require!(child.parent != Pubkey::default(), HydraError::NoParentAgent);
+    /// Toggle whether protected instructions (`record_earning`, `spawn_child`,
+    /// `distribute_to_parent`) may be invoked via CPI. Authority only.
+    pub fn set_allow_cpi(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        allow_cpi: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.allow_cpi = allow_cpi;
 
-        // SOL transfer from child wallet to parent wallet
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.child_wallet.to_account_info(),
-                    to: ctx.accounts.parent_wallet.to_account_info(),
-                },
-            ),
-            amount,
-        )?;
+        emit!(AllowCpiSet { allow_cpi });
 
-        let child_mut = &mut ctx.accounts.child_agent;
-        child_mut.total_distributed_to_parent = child_mut
-            .total_distributed_to_parent
-            .checked_add(amount)
-            .unwrap();
+        ctx.accounts.stats.next_event_seq();
 
-        emit!(RevenueDistributed {
-            child: child_mut.key(),
-            parent: ctx.accounts.parent_agent.key(),
-            amount,
-            total_distributed: child_mut.total_distributed_to_parent,
+        Ok(())
+    }
+
+    /// Propose `new_authority` as the next registry authority. Takes effect only once
+    /// the proposed key calls `accept_authority`. Authority only.
+    pub fn propose_authority(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.pending_authority = Some(new_authority);
+        registry.authority_proposal_ts = Clock::get()?.unix_timestamp;
+
+        emit!(AuthorityProposed {
+            authority: registry.authority,
+            pending_authority: new_authority,
         });
 
+        ctx.accounts.stats.next_event_seq();
+
         Ok(())
     }
 
-    /// Deactivate an agent.
-    pub fn deactivate_agent(ctx: Context<DeactivateAgent>) -> Result<()> {
-        let agent = &mut ctx.accounts.agent;
-        agent.is_active = false;
+    /// Accept a pending authority handoff (proposed key only), unless it is older
+    /// than the registry's `proposal_ttl_secs`.
+    pub fn accept_authority(ctx: Context<AcceptAuthority>, _namespace: String) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let ttl = registry.proposal_ttl_secs;
+        let age = Clock::get()?
+            .unix_timestamp
+            .saturating_sub(registry.authority_proposal_ts);
+        require!(ttl == 0 || age <= ttl, HydraError::ProposalExpired);
 
-        emit!(AgentDeactivated {
-            agent: agent.key(),
-            wallet: agent.wallet,
+        let previous = registry.authority;
+        registry.authority = ctx.accounts.new_authority.key();
+        registry.pending_authority = None;
+        registry.authority_proposal_ts = 0;
+
+        emit!(AuthorityTransferred {
+            previous_authority: previous,
+            new_authority: registry.authority,
         });
 
+        ctx.accounts.stats.next_event_seq();
+
         Ok(())
     }
-}
 
-// ============================================================================
-// Accounts
-// ============================================================================
+    /// Withdraw a pending authority handoff. Authority only.
+    pub fn cancel_authority_proposal(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let pending = registry.pending_authority.ok_or(HydraError::NoPendingAuthority)?;
+        registry.pending_authority = None;
+        registry.authority_proposal_ts = 0;
 
-#[account]
-pub struct Registry {
-    pub authority: Pubkey,
-    pub total_agents: u64,
-    pub total_earnings: u64,
-    pub total_spawns: u64,
-    pub bump: u8,
-}
+        emit!(AuthorityProposalCancelled {
+            pending_authority: pending,
+        });
 
-impl Registry {
-    pub const SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1;
-}
+        ctx.accounts.stats.next_event_seq();
 
-#[account]
-pub struct AgentAccount {
-    pub wallet: Pubkey,
-    pub parent: Pubkey,
-    pub name: String,
-    pub specialization: String,
-    pub total_earned: u64,
-    pub total_distributed_to_parent: u64,
-    pub children_count: u64,
-    pub depth: u8,
-    pub revenue_share_bps: u16,
-    pub is_active: bool,
-    pub created_at: i64,
-    pub bump: u8,
-}
+        Ok(())
+    }
 
-impl AgentAccount {
-    pub const SIZE: usize = 8 + 32 + 32 + (4 + MAX_NAME_LEN) + (4 + MAX_SPEC_LEN) + 8 + 8 + 8 + 1 + 2 + 1 + 8 + 1;
-}
+    /// Set how long an authority proposal stays acceptable (0 = no expiry). Authority only.
+    pub fn set_proposal_ttl(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        ttl_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.proposal_ttl_secs = ttl_secs;
+        validate_registry_config(registry)?;
 
-// ============================================================================
-// Instruction Contexts
-// ============================================================================
+        emit!(ProposalTtlSet { ttl_secs });
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = Registry::SIZE,
-        seeds = [b"registry"],
-        bump,
-    )]
-    pub registry: Account<'info, Registry>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+        ctx.accounts.stats.next_event_seq();
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct RegisterRootAgent<'info> {
-    #[account(
-        mut,
-        seeds = [b"registry"],
-        bump = registry.bump,
-        has_one = authority,
-    )]
-    pub registry: Account<'info, Registry>,
-    #[account(
-        init,
-        payer = authority,
-        space = AgentAccount::SIZE,
-        seeds = [b"agent", wallet.key().as_ref()],
-        bump,
-    )]
-    pub agent: Account<'info, AgentAccount>,
-    /// CHECK: Agent's operating wallet, validated by PDA seed
-    pub wallet: UncheckedAccount<'info>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+        Ok(())
+    }
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct SpawnChild<'info> {
-    #[account(
-        mut,
-        seeds = [b"registry"],
-        bump = registry.bump,
-    )]
-    pub registry: Account<'info, Registry>,
-    #[account(
-        mut,
-        seeds = [b"agent", parent_wallet.key().as_ref()],
-        bump = parent_agent.bump,
-    )]
-    pub parent_agent: Account<'info, AgentAccount>,
-    #[account(
-        init,
-        payer = parent_wallet,
-        space = AgentAccount::SIZE,
-        seeds = [b"agent", child_wallet.key().as_ref()],
-        bump,
-    )]
-    pub child_agent: Account<'info, AgentAccount>,
-    #[account(mut)]
-    pub parent_wallet: Signer<'info>,
-    /// CHECK: New child agent's wallet, validated by PDA seed
-    pub child_wallet: UncheckedAccount<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Configure tree-balance enforcement on spawn (see `GlobalStats::level_filled`).
+    /// Enabling requires a branching factor of at least 1. Authority only.
+    pub fn set_tree_balance(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        require_balanced: bool,
+        branching_factor: u16,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.require_balanced = require_balanced;
+        registry.branching_factor = branching_factor;
+        validate_registry_config(registry)?;
 
-#[derive(Accounts)]
-pub struct RecordEarning<'info> {
-    #[account(
-        mut,
-        seeds = [b"registry"],
-        bump = registry.bump,
-    )]
-    pub registry: Account<'info, Registry>,
-    #[account(
-        mut,
-        seeds = [b"agent", wallet.key().as_ref()],
-        bump = agent.bump,
-    )]
-    pub agent: Account<'info, AgentAccount>,
-    pub wallet: Signer<'info>,
-}
+        emit!(TreeBalanceSet {
+            require_balanced,
+            branching_factor,
+        });
 
-#[derive(Accounts)]
-pub struct DistributeToParent<'info> {
-    #[account(
-        mut,
-        seeds = [b"agent", child_wallet.key().as_ref()],
-        bump = child_agent.bump,
-    )]
-    pub child_agent: Account<'info, AgentAccount>,
-    #[account(
-        seeds = [b"agent", parent_wallet.key().as_ref()],
-        bump = parent_agent.bump,
-    )]
-    pub parent_agent: Account<'info, AgentAccount>,
-    #[account(mut)]
-    pub child_wallet: Signer<'info>,
-    /// CHECK: Parent's wallet, validated by parent_agent PDA
-    #[account(mut)]
-    pub parent_wallet: UncheckedAccount<'info>,
-    pub system_program: Program<'info, System>,
-}
+        ctx.accounts.stats.next_event_seq();
 
-#[derive(Accounts)]
-pub struct DeactivateAgent<'info> {
-    #[account(
+        Ok(())
+    }
+
+    /// Toggle compact creation events that omit name/specialization strings. Authority only.
+    pub fn set_compact_events(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        compact_events: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.compact_events = compact_events;
+
+        emit!(CompactEventsSet { compact_events });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Toggle SPL Memo CPIs on earnings and distributions. Authority only.
+    pub fn set_emit_memos(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        emit_memos: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.emit_memos = emit_memos;
+
+        emit!(EmitMemosSet { emit_memos });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the display metadata for amounts tracked by the registry. Authority only.
+    pub fn set_currency_metadata(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        decimals: u8,
+        currency_symbol: [u8; 8],
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.decimals = decimals;
+        registry.currency_symbol = currency_symbol;
+
+        emit!(CurrencyMetadataSet {
+            decimals,
+            currency_symbol,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the share of `pay_agent_with_split` payments paid to a payer's referring
+    /// agent, and how long a referral lasts (0 = forever). Authority only. A
+    /// referred payment to a child whose `revenue_share_bps` plus `referral_bps`
+    /// exceeds 10_000 fails with `InvalidRevenueShare`.
+    pub fn set_referral_config(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        referral_bps: u16,
+        referral_duration_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.referral_bps = referral_bps;
+        registry.referral_duration_secs = referral_duration_secs;
+        validate_registry_config(registry)?;
+
+        emit!(ReferralConfigSet {
+            referral_bps,
+            referral_duration_secs,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Require spawning parents to hold a token of `spawn_license_mint`
+    /// (`Pubkey::default()` disables the gate). Authority only.
+    pub fn set_spawn_license_mint(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        spawn_license_mint: Pubkey,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.spawn_license_mint = spawn_license_mint;
+
+        emit!(SpawnLicenseMintSet { spawn_license_mint });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Cap the summed `revenue_share_bps` of a parent's children at 10000.
+    /// Authority only.
+    pub fn set_enforce_sibling_share_cap(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        enforce: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.enforce_sibling_share_cap = enforce;
+
+        emit!(SiblingShareCapSet { enforce });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the minimum seconds between `take_snapshot` calls (0 = no limit).
+    /// Authority only.
+    pub fn set_min_snapshot_interval(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        min_snapshot_interval_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.min_snapshot_interval_secs = min_snapshot_interval_secs;
+        validate_registry_config(registry)?;
+
+        emit!(MinSnapshotIntervalSet { min_snapshot_interval_secs });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the minimum seconds between `adjust_earning` calls on the same agent
+    /// (0 = no limit). Authority only.
+    pub fn set_adjustment_cooldown(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        adjustment_cooldown_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.adjustment_cooldown_secs = adjustment_cooldown_secs;
+        validate_registry_config(registry)?;
+
+        emit!(AdjustmentCooldownSet { adjustment_cooldown_secs });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the minimum seconds between `distribute_to_parent` calls by the same
+    /// agent (0 = no limit). Authority only.
+    pub fn set_min_distribution_interval(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        min_distribution_interval_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.min_distribution_interval_secs = min_distribution_interval_secs;
+        validate_registry_config(registry)?;
+
+        emit!(MinDistributionIntervalSet { min_distribution_interval_secs });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Cap the amount a single `record_earning` may report (0 = unlimited). Authority only.
+    pub fn set_max_single_earning(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        max_single_earning: u64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.max_single_earning = max_single_earning;
+
+        emit!(MaxSingleEarningSet { max_single_earning });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Toggle blocking earnings and distributions for agents whose direct parent
+    /// is deactivated. Authority only.
+    pub fn set_require_active_lineage(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        require_active_lineage: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.require_active_lineage = require_active_lineage;
+
+        emit!(RequireActiveLineageSet {
+            require_active_lineage,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set how long an agent may go without activity before `mark_stale`
+    /// can flag it. Authority only.
+    pub fn set_stale_threshold(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        threshold_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.stale_threshold_secs = threshold_secs;
+        validate_registry_config(registry)?;
+
+        emit!(StaleThresholdSet { threshold_secs });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Configure the SOL bond posted by new agents and the withdrawal cooldown. Authority only.
+    /// A required bond of 0 disables bonding for new agents.
+    pub fn set_bond_config(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        required_bond_lamports: u64,
+        cooldown_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.required_bond_lamports = required_bond_lamports;
+        registry.bond_cooldown_secs = cooldown_secs;
+        validate_registry_config(registry)?;
+
+        emit!(BondConfigSet {
+            required_bond_lamports,
+            cooldown_secs,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Toggle enforcement of per-agent withdrawal whitelists. Authority only.
+    pub fn set_enforce_withdraw_whitelist(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        enforce: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.enforce_withdraw_whitelist = enforce;
+
+        emit!(WithdrawWhitelistEnforcementSet { enforce });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set how long a deactivated agent must wait before it can be pruned. Authority only.
+    pub fn set_prune_grace_secs(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        grace_secs: i64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.prune_grace_secs = grace_secs;
+        validate_registry_config(registry)?;
+
+        emit!(PruneGraceSet { grace_secs });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Change the registry's max tree depth. Authority only.
+    ///
+    /// Lowering the limit is allowed: it is only enforced when spawning, so
+    /// existing agents deeper than the new limit keep operating but cannot spawn.
+    pub fn set_max_depth(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        max_depth: u8,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.max_depth = max_depth;
+        validate_registry_config(registry)?;
+
+        emit!(MaxDepthSet { max_depth });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Toggle strict (URL-safe) agent name validation. Authority only.
+    pub fn set_strict_names(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        strict_names: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.strict_names = strict_names;
+
+        emit!(StrictNamesSet { strict_names });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Toggle checking `spawn_child` wallets against supplied ancestors.
+    /// Authority only.
+    pub fn set_strict_lineage_checks(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        strict: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.strict_lineage_checks = strict;
+
+        emit!(StrictLineageChecksSet { strict });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Register the root agent (no parent). Only callable by registry authority.
+    pub fn register_root_agent(
+        ctx: Context<RegisterRootAgent>,
+        _namespace: String,
+        name: String,
+        specialization: String,
+    ) -> Result<()> {
+        check_limit("name length", name.len(), MAX_NAME_LEN, HydraError::NameTooLong)?;
+        check_limit("specialization length", specialization.len(), MAX_SPEC_LEN, HydraError::SpecTooLong)?;
+        validate_name(&name, ctx.accounts.registry.strict_names)?;
+        check_name_reservation(
+            &ctx.accounts.name_reservation,
+            &[ctx.accounts.authority.key(), ctx.accounts.wallet.key()],
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let agent = &mut ctx.accounts.agent;
+        agent.set_inner(new_agent(
+            ctx.accounts.wallet.key(),
+            Pubkey::default(),
+            name,
+            specialization,
+            0,
+            ctx.accounts.authority.key(),
+            ctx.bumps.agent,
+        )?);
+
+        fund_bond(
+            &ctx.accounts.registry,
+            ctx.accounts.bond.as_mut(),
+            ctx.bumps.bond,
+            agent.key(),
+            ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
+        increment_spec_counter(&mut ctx.accounts.spec_counter, &agent.specialization, ctx.bumps.spec_counter);
+
+        let stats = &mut ctx.accounts.stats;
+        stats.total_agents = stats.total_agents.checked_add(1).unwrap();
+        stats.record_agent_at_depth(0);
+
+        if ctx.accounts.registry.compact_events {
+            emit!(AgentRegisteredCompact {
+                agent: agent.key(),
+                wallet: agent.wallet,
+                parent: Pubkey::default(),
+                depth: 0,
+                created_by: agent.created_by,
+                seq: stats.next_event_seq(),
+            });
+        } else {
+            emit!(AgentRegistered {
+                agent: agent.key(),
+                wallet: agent.wallet,
+                parent: Pubkey::default(),
+                name: agent.name.clone(),
+                specialization: agent.specialization.clone(),
+                depth: 0,
+                created_by: agent.created_by,
+                seq: stats.next_event_seq(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// `register_root_agent` with its arguments in a single struct.
+    pub fn register_root_agent_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterRootAgent<'info>>,
+        namespace: String,
+        args: RegisterRootAgentArgs,
+    ) -> Result<()> {
+        check_limit("uri length", args.uri.len(), MAX_URI_LEN, HydraError::UriTooLong)?;
+        register_root_agent(
+            Context::new(ctx.program_id, ctx.accounts, ctx.remaining_accounts, ctx.bumps),
+            namespace,
+            args.name,
+            args.specialization,
+        )?;
+
+        let agent = &mut ctx.accounts.agent;
+        agent.uri = args.uri;
+        agent.content_hash = args.content_hash;
+
+        Ok(())
+    }
+
+    /// Parent agent spawns a child agent.
+    ///
+    /// An optional secondary beneficiary (a co-incubating agent) can be given a
+    /// share alongside the parent; it can only be set here, at spawn time.
+    /// A nonzero `expires_at` makes the child inactive from that timestamp on, and a
+    /// nonzero `earning_goal` fires `GoalReached` once the child earns that much.
+    /// Under `strict_lineage_checks`, `remaining_accounts` may carry the parent's
+    /// ancestors, whose wallets the child wallet must not reuse.
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_child(
+        ctx: Context<SpawnChild>,
+        _namespace: String,
+        name: String,
+        specialization: String,
+        revenue_share_bps: u16,
+        secondary_beneficiary: Option<Pubkey>,
+        secondary_share_bps: u16,
+        expires_at: i64,
+        earning_goal: u64,
+    ) -> Result<SpawnResult> {
+        check_spawn_wallets(&ctx.accounts.parent_wallet.key(), &ctx.accounts.child_wallet.key())?;
+        require_top_level(&ctx.accounts.registry)?;
+        require!(
+            expires_at == 0 || expires_at > Clock::get()?.unix_timestamp,
+            HydraError::InvalidExpiry
+        );
+        check_limit(
+            "revenue and secondary share bps",
+            (revenue_share_bps as u32) + (secondary_share_bps as u32),
+            10_000,
+            HydraError::InvalidRevenueShare,
+        )?;
+        require!(
+            secondary_beneficiary.is_some() || secondary_share_bps == 0,
+            HydraError::InvalidRevenueShare
+        );
+
+        let parent = &ctx.accounts.parent_agent;
+        check_spawn(
+            &ctx.accounts.registry,
+            &ctx.accounts.stats,
+            parent,
+            &name,
+            &specialization,
+            revenue_share_bps,
+        )?;
+        check_ancestor_wallets(
+            &ctx.accounts.registry,
+            parent,
+            &ctx.accounts.child_wallet.key(),
+            ctx.remaining_accounts,
+        )?;
+        check_name_reservation(
+            &ctx.accounts.name_reservation,
+            &[ctx.accounts.parent_wallet.key(), ctx.accounts.child_wallet.key()],
+            Clock::get()?.unix_timestamp,
+        )?;
+        check_spawn_license(
+            &ctx.accounts.registry,
+            ctx.accounts.spawn_license.as_ref(),
+            &ctx.accounts.parent_wallet.key(),
+        )?;
+        check_quarantine(
+            &ctx.accounts.registry,
+            parent,
+            QUARANTINE_SPAWNS,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let child = &mut ctx.accounts.child_agent;
+        child.set_inner(AgentAccount {
+            revenue_share_bps,
+            secondary_beneficiary,
+            secondary_share_bps,
+            expires_at,
+            earning_goal,
+            ..new_agent(
+                ctx.accounts.child_wallet.key(),
+                parent.key(),
+                name.clone(),
+                specialization.clone(),
+                child_depth(parent.depth)?,
+                ctx.accounts.parent_wallet.key(),
+                ctx.bumps.child_agent,
+            )?
+        });
+
+        fund_bond(
+            &ctx.accounts.registry,
+            ctx.accounts.bond.as_mut(),
+            ctx.bumps.bond,
+            child.key(),
+            ctx.accounts.parent_wallet.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
+        increment_spec_counter(&mut ctx.accounts.spec_counter, &specialization, ctx.bumps.spec_counter);
+
+        let parent_agent = &mut ctx.accounts.parent_agent;
+        parent_agent.children_count = parent_agent.children_count.checked_add(1).unwrap();
+        parent_agent.children_share_sum = parent_agent
+            .children_share_sum
+            .checked_add(child.revenue_share_bps as u32)
+            .unwrap();
+
+        let stats = &mut ctx.accounts.stats;
+        stats.total_agents = stats.total_agents.checked_add(1).unwrap();
+        stats.record_spawn(Clock::get()?.epoch);
+        stats.record_agent_at_depth(child.depth);
+
+        if ctx.accounts.registry.compact_events {
+            emit!(AgentSpawnedCompact {
+                child: child.key(),
+                parent: ctx.accounts.parent_agent.key(),
+                child_wallet: child.wallet,
+                depth: child.depth,
+                revenue_share_bps,
+                created_by: child.created_by,
+                seq: stats.next_event_seq(),
+            });
+        } else {
+            emit!(AgentSpawned {
+                child: child.key(),
+                parent: ctx.accounts.parent_agent.key(),
+                child_wallet: child.wallet,
+                name,
+                specialization,
+                depth: child.depth,
+                revenue_share_bps,
+                created_by: child.created_by,
+                seq: stats.next_event_seq(),
+            });
+        }
+
+        Ok(SpawnResult {
+            child: child.key(),
+            bump: child.bump,
+            depth: child.depth,
+        })
+    }
+
+    /// `spawn_child` with its arguments in a single struct.
+    pub fn spawn_child_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, SpawnChild<'info>>,
+        namespace: String,
+        args: SpawnChildArgs,
+    ) -> Result<SpawnResult> {
+        check_limit("uri length", args.uri.len(), MAX_URI_LEN, HydraError::UriTooLong)?;
+        let result = spawn_child(
+            Context::new(ctx.program_id, ctx.accounts, ctx.remaining_accounts, ctx.bumps),
+            namespace,
+            args.name,
+            args.specialization,
+            args.revenue_share_bps,
+            args.secondary_beneficiary,
+            args.secondary_share_bps,
+            args.expires_at,
+            args.earning_goal,
+        )?;
+
+        let child = &mut ctx.accounts.child_agent;
+        child.uri = args.uri;
+        child.content_hash = args.content_hash;
+
+        Ok(result)
+    }
+
+    /// Reserve `name` for the signer for `NAME_RESERVATION_SECS`. Until it
+    /// expires, `register_root_agent` and `spawn_child` only accept the name when
+    /// the reserver is the signer or the new agent's wallet.
+    pub fn reserve_name(ctx: Context<ReserveName>, _namespace: String, name: String) -> Result<()> {
+        check_limit("name length", name.len(), MAX_NAME_LEN, HydraError::NameTooLong)?;
+
+        let reservation = &mut ctx.accounts.name_reservation;
+        reservation.reserver = ctx.accounts.reserver.key();
+        reservation.name_hash = hash(name.as_bytes()).to_bytes();
+        reservation.expires_at = Clock::get()?
+            .unix_timestamp
+            .checked_add(NAME_RESERVATION_SECS)
+            .ok_or(HydraError::Overflow)?;
+        reservation.bump = ctx.bumps.name_reservation;
+
+        emit!(NameReservationCreated {
+            reserver: reservation.reserver,
+            name,
+            expires_at: reservation.expires_at,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Close a name reservation, refunding its rent to the reserver. The
+    /// reserver may release at any time; anyone may once it has expired.
+    pub fn release_reservation(
+        ctx: Context<ReleaseReservation>,
+        _namespace: String,
+        name: String,
+    ) -> Result<()> {
+        let reservation = &ctx.accounts.name_reservation;
+        require!(
+            ctx.accounts.signer.key() == reservation.reserver
+                || reservation.expires_at <= Clock::get()?.unix_timestamp,
+            HydraError::NameReserved
+        );
+
+        emit!(NameReservationReleased {
+            reserver: reservation.reserver,
+            name,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Parent agent spawns a child at the indexed PDA `["agent", child_wallet, agent_index_le]`,
+    /// letting one wallet operate several agents. `agent_index` must be the wallet's
+    /// next index from its `WalletAgentCounter`, so indices never collide or leave gaps.
+    pub fn register_agent_indexed(
+        ctx: Context<RegisterAgentIndexed>,
+        _namespace: String,
+        agent_index: u32,
+        name: String,
+        specialization: String,
+        revenue_share_bps: u16,
+    ) -> Result<()> {
+        check_spawn_wallets(&ctx.accounts.parent_wallet.key(), &ctx.accounts.child_wallet.key())?;
+        require_top_level(&ctx.accounts.registry)?;
+
+        let parent = &ctx.accounts.parent_agent;
+        check_spawn(
+            &ctx.accounts.registry,
+            &ctx.accounts.stats,
+            parent,
+            &name,
+            &specialization,
+            revenue_share_bps,
+        )?;
+        check_ancestor_wallets(
+            &ctx.accounts.registry,
+            parent,
+            &ctx.accounts.child_wallet.key(),
+            ctx.remaining_accounts,
+        )?;
+        check_name_reservation(
+            &ctx.accounts.name_reservation,
+            &[ctx.accounts.parent_wallet.key(), ctx.accounts.child_wallet.key()],
+            Clock::get()?.unix_timestamp,
+        )?;
+        check_spawn_license(
+            &ctx.accounts.registry,
+            ctx.accounts.spawn_license.as_ref(),
+            &ctx.accounts.parent_wallet.key(),
+        )?;
+        check_quarantine(
+            &ctx.accounts.registry,
+            parent,
+            QUARANTINE_SPAWNS,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let counter = &mut ctx.accounts.wallet_agent_counter;
+        require!(agent_index == counter.next_index, HydraError::InvalidAgentIndex);
+        counter.wallet = ctx.accounts.child_wallet.key();
+        counter.next_index = counter.next_index.checked_add(1).unwrap();
+        counter.bump = ctx.bumps.wallet_agent_counter;
+
+        let child = &mut ctx.accounts.child_agent;
+        child.set_inner(AgentAccount {
+            revenue_share_bps,
+            is_indexed: true,
+            agent_index,
+            ..new_agent(
+                ctx.accounts.child_wallet.key(),
+                parent.key(),
+                name.clone(),
+                specialization.clone(),
+                child_depth(parent.depth)?,
+                ctx.accounts.parent_wallet.key(),
+                ctx.bumps.child_agent,
+            )?
+        });
+
+        fund_bond(
+            &ctx.accounts.registry,
+            ctx.accounts.bond.as_mut(),
+            ctx.bumps.bond,
+            child.key(),
+            ctx.accounts.parent_wallet.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
+        increment_spec_counter(&mut ctx.accounts.spec_counter, &specialization, ctx.bumps.spec_counter);
+
+        let parent_agent = &mut ctx.accounts.parent_agent;
+        parent_agent.children_count = parent_agent.children_count.checked_add(1).unwrap();
+        parent_agent.children_share_sum = parent_agent
+            .children_share_sum
+            .checked_add(child.revenue_share_bps as u32)
+            .unwrap();
+
+        let stats = &mut ctx.accounts.stats;
+        stats.total_agents = stats.total_agents.checked_add(1).unwrap();
+        stats.record_spawn(Clock::get()?.epoch);
+        stats.record_agent_at_depth(child.depth);
+
+        if ctx.accounts.registry.compact_events {
+            emit!(AgentSpawnedCompact {
+                child: child.key(),
+                parent: ctx.accounts.parent_agent.key(),
+                child_wallet: child.wallet,
+                depth: child.depth,
+                revenue_share_bps,
+                created_by: child.created_by,
+                seq: stats.next_event_seq(),
+            });
+        } else {
+            emit!(AgentSpawned {
+                child: child.key(),
+                parent: ctx.accounts.parent_agent.key(),
+                child_wallet: child.wallet,
+                name,
+                specialization,
+                depth: child.depth,
+                revenue_share_bps,
+                created_by: child.created_by,
+                seq: stats.next_event_seq(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parent stores reusable child parameters at `["template", parent_agent, template_id_le]`
+    /// for `spawn_from_template`, usable `max_uses` times.
+    pub fn create_spawn_template(
+        ctx: Context<CreateSpawnTemplate>,
+        _namespace: String,
+        template_id: u32,
+        name_prefix: String,
+        specialization: String,
+        revenue_share_bps: u16,
+        max_uses: u32,
+    ) -> Result<()> {
+        check_limit("name prefix length", name_prefix.len(), MAX_NAME_LEN, HydraError::NameTooLong)?;
+        check_limit("specialization length", specialization.len(), MAX_SPEC_LEN, HydraError::SpecTooLong)?;
+        require!(revenue_share_bps <= 10_000, HydraError::InvalidRevenueShare);
+        require!(max_uses > 0, HydraError::InvalidConfigValue);
+
+        let template = &mut ctx.accounts.template;
+        template.parent = ctx.accounts.parent_agent.key();
+        template.template_id = template_id;
+        template.name_prefix = name_prefix;
+        template.specialization = specialization;
+        template.revenue_share_bps = revenue_share_bps;
+        template.remaining_uses = max_uses;
+        template.bump = ctx.bumps.template;
+
+        emit!(SpawnTemplateCreated {
+            parent: template.parent,
+            template: template.key(),
+            template_id,
+            revenue_share_bps,
+            max_uses,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Spawn a child from one of the parent's templates, named `name_prefix + name_suffix`.
+    /// Otherwise behaves like `spawn_child` with no secondary beneficiary, expiry or goal.
+    pub fn spawn_from_template(
+        ctx: Context<SpawnFromTemplate>,
+        _namespace: String,
+        _template_id: u32,
+        name_suffix: String,
+    ) -> Result<()> {
+        check_spawn_wallets(&ctx.accounts.parent_wallet.key(), &ctx.accounts.child_wallet.key())?;
+        require_top_level(&ctx.accounts.registry)?;
+
+        let template = &mut ctx.accounts.template;
+        require!(template.remaining_uses > 0, HydraError::TemplateExhausted);
+        template.remaining_uses -= 1;
+        let name = template.child_name(&name_suffix);
+        let specialization = template.specialization.clone();
+        let revenue_share_bps = template.revenue_share_bps;
+
+        let parent = &ctx.accounts.parent_agent;
+        check_spawn(
+            &ctx.accounts.registry,
+            &ctx.accounts.stats,
+            parent,
+            &name,
+            &specialization,
+            revenue_share_bps,
+        )?;
+        check_ancestor_wallets(
+            &ctx.accounts.registry,
+            parent,
+            &ctx.accounts.child_wallet.key(),
+            ctx.remaining_accounts,
+        )?;
+        check_name_reservation(
+            &ctx.accounts.name_reservation,
+            &[ctx.accounts.parent_wallet.key(), ctx.accounts.child_wallet.key()],
+            Clock::get()?.unix_timestamp,
+        )?;
+        check_spawn_license(
+            &ctx.accounts.registry,
+            ctx.accounts.spawn_license.as_ref(),
+            &ctx.accounts.parent_wallet.key(),
+        )?;
+        check_quarantine(
+            &ctx.accounts.registry,
+            parent,
+            QUARANTINE_SPAWNS,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let child = &mut ctx.accounts.child_agent;
+        child.set_inner(AgentAccount {
+            revenue_share_bps,
+            ..new_agent(
+                ctx.accounts.child_wallet.key(),
+                parent.key(),
+                name.clone(),
+                specialization.clone(),
+                child_depth(parent.depth)?,
+                ctx.accounts.parent_wallet.key(),
+                ctx.bumps.child_agent,
+            )?
+        });
+
+        fund_bond(
+            &ctx.accounts.registry,
+            ctx.accounts.bond.as_mut(),
+            ctx.bumps.bond,
+            child.key(),
+            ctx.accounts.parent_wallet.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
+        increment_spec_counter(&mut ctx.accounts.spec_counter, &specialization, ctx.bumps.spec_counter);
+
+        let parent_agent = &mut ctx.accounts.parent_agent;
+        parent_agent.children_count = parent_agent.children_count.checked_add(1).unwrap();
+        parent_agent.children_share_sum = parent_agent
+            .children_share_sum
+            .checked_add(child.revenue_share_bps as u32)
+            .unwrap();
+
+        let stats = &mut ctx.accounts.stats;
+        stats.total_agents = stats.total_agents.checked_add(1).unwrap();
+        stats.record_spawn(Clock::get()?.epoch);
+        stats.record_agent_at_depth(child.depth);
+
+        if ctx.accounts.registry.compact_events {
+            emit!(AgentSpawnedCompact {
+                child: child.key(),
+                parent: ctx.accounts.parent_agent.key(),
+                child_wallet: child.wallet,
+                depth: child.depth,
+                revenue_share_bps,
+                created_by: child.created_by,
+                seq: stats.next_event_seq(),
+            });
+        } else {
+            emit!(AgentSpawned {
+                child: child.key(),
+                parent: ctx.accounts.parent_agent.key(),
+                child_wallet: child.wallet,
+                name,
+                specialization,
+                depth: child.depth,
+                revenue_share_bps,
+                created_by: child.created_by,
+                seq: stats.next_event_seq(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Close a spawn template, refunding its rent to the parent wallet.
+    pub fn close_spawn_template(
+        _ctx: Context<CloseSpawnTemplate>,
+        _namespace: String,
+        _template_id: u32,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Record earnings for an agent (called by agent's own wallet). Fails with
+    /// `OracleRequired` while the registry is in oracle-only mode.
+    ///
+    /// A nonzero `nonce` must be strictly greater than the agent's last one, so
+    /// client retries of an already-landed earning are rejected. Zero skips the check.
+    /// `category` tags the earning's source; `None` records it as `Other`.
+    pub fn record_earning(
+        ctx: Context<RecordEarning>,
+        _namespace: String,
+        amount: u64,
+        nonce: u64,
+        category: Option<EarningCategory>,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.registry.oracle_only_earnings,
+            HydraError::OracleRequired
+        );
+        check_earning_limit(&ctx.accounts.registry, amount)?;
+        let accounts = ctx.accounts;
+        credit_recorded_earning(
+            &accounts.registry,
+            &mut accounts.stats,
+            &mut accounts.agent,
+            accounts.parent_agent.as_mut(),
+            accounts.leaderboard.as_mut(),
+            accounts.memo_program.as_ref(),
+            amount,
+            nonce,
+            category,
+        )
+    }
+
+    /// Record earnings for an agent on behalf of its wallet, signed by an oracle
+    /// approved with `add_oracle`. Otherwise identical to `record_earning`,
+    /// including nonce handling and the counters it updates.
+    pub fn record_earning_by_oracle(
+        ctx: Context<RecordEarningByOracle>,
+        _namespace: String,
+        amount: u64,
+        nonce: u64,
+        category: Option<EarningCategory>,
+    ) -> Result<()> {
+        check_earning_limit(&ctx.accounts.registry, amount)?;
+        let accounts = ctx.accounts;
+        credit_recorded_earning(
+            &accounts.registry,
+            &mut accounts.stats,
+            &mut accounts.agent,
+            accounts.parent_agent.as_mut(),
+            accounts.leaderboard.as_mut(),
+            accounts.memo_program.as_ref(),
+            amount,
+            nonce,
+            category,
+        )?;
+
+        emit!(OracleEarningRecorded {
+            agent: accounts.agent.key(),
+            oracle: accounts.oracle.key(),
+            amount,
+            total_earned: accounts.agent.total_earned,
+        });
+
+        Ok(())
+    }
+
+    /// Record up to `MAX_BULK_EARNINGS` earnings for one agent in a single
+    /// counter update (agent wallet only), for agents finishing many small tasks.
+    ///
+    /// Each amount must be nonzero and within `max_single_earning`; their sum is
+    /// credited as one `category` earning. `task_hashes` is either empty or holds
+    /// one hash per amount; hashes are then rejected if repeated within the batch
+    /// or found in the agent's `TaskLog`, which must be supplied.
+    pub fn record_earnings_bulk(
+        ctx: Context<RecordEarningsBulk>,
+        _namespace: String,
+        amounts: Vec<u64>,
+        task_hashes: Vec<[u8; 32]>,
+        category: Option<EarningCategory>,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.registry.oracle_only_earnings,
+            HydraError::OracleRequired
+        );
+        require!(!amounts.is_empty(), HydraError::InvalidBatchSize);
+        check_limit("bulk earnings", amounts.len(), MAX_BULK_EARNINGS, HydraError::InvalidBatchSize)?;
+
+        let mut total: u64 = 0;
+        for &amount in &amounts {
+            require!(amount > 0, HydraError::ZeroAmount);
+            check_earning_limit(&ctx.accounts.registry, amount)?;
+            total = total.checked_add(amount).ok_or(HydraError::Overflow)?;
+        }
+
+        if !task_hashes.is_empty() {
+            require!(task_hashes.len() == amounts.len(), HydraError::LengthMismatch);
+            let task_log = ctx
+                .accounts
+                .task_log
+                .as_mut()
+                .ok_or(HydraError::MissingTaskLog)?;
+            if task_log.agent == Pubkey::default() {
+                task_log.agent = ctx.accounts.agent.key();
+                task_log.bump = ctx.bumps.task_log.unwrap();
+            }
+            for (i, task_hash) in task_hashes.iter().enumerate() {
+                require!(
+                    !task_hashes[..i].contains(task_hash) && !task_log.contains(task_hash),
+                    HydraError::DuplicateTask
+                );
+            }
+            for task_hash in &task_hashes {
+                task_log.push(*task_hash);
+            }
+        }
+
+        let accounts = ctx.accounts;
+        credit_recorded_earning(
+            &accounts.registry,
+            &mut accounts.stats,
+            &mut accounts.agent,
+            accounts.parent_agent.as_mut(),
+            accounts.leaderboard.as_mut(),
+            accounts.memo_program.as_ref(),
+            total,
+            0,
+            category,
+        )?;
+
+        emit!(BulkEarningsRecorded {
+            agent: accounts.agent.key(),
+            count: amounts.len() as u8,
+            total,
+            total_earned: accounts.agent.total_earned,
+        });
+
+        Ok(())
+    }
+
+    /// Parent vouches that its child has earned at least `attested_total`, raising
+    /// the child's `attested_earned` (never lowering it). `earning_nonce` must equal
+    /// the child's `last_earning_nonce`, pinning the attestation to the earnings the
+    /// parent reviewed; a total above `total_earned` is rejected.
+    pub fn attest_child_earning(
+        ctx: Context<AttestChildEarning>,
+        _namespace: String,
+        attested_total: u64,
+        earning_nonce: u64,
+    ) -> Result<()> {
+        let child = &mut ctx.accounts.child_agent;
+        require!(earning_nonce == child.last_earning_nonce, HydraError::StaleAttestation);
+        require!(
+            attested_total <= child.total_earned,
+            HydraError::AttestationExceedsEarnings
+        );
+
+        let delta = attested_total.saturating_sub(child.attested_earned);
+        if delta > 0 {
+            child.attested_earned = attested_total;
+            let stats = &mut ctx.accounts.stats;
+            stats.total_attested_earnings = stats.total_attested_earnings.checked_add(delta).unwrap();
+        }
+
+        emit!(EarningAttested {
+            child: child.key(),
+            parent: ctx.accounts.parent_agent.key(),
+            attested_total: child.attested_earned,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Record earnings for many agents at once (registry reporter only).
+    ///
+    /// `remaining_accounts` holds the agents in the same order as `amounts`. Each
+    /// earning is recorded as `Other` and left pending for `roll_up_earnings`.
+    pub fn batch_record_earnings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchRecordEarnings<'info>>,
+        namespace: String,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        check_batch_nodes(&ctx.accounts.registry, ctx.remaining_accounts)?;
+        require!(
+            amounts.len() == ctx.remaining_accounts.len(),
+            HydraError::LengthMismatch
+        );
+        require!(
+            !amounts.is_empty() && amounts.len() <= MAX_BATCH_LEN,
+            HydraError::InvalidBatchSize
+        );
+
+        let clock = Clock::get()?;
+        let mut total: u64 = 0;
+
+        for (i, (info, &amount)) in ctx.remaining_accounts.iter().zip(&amounts).enumerate() {
+            require!(amount > 0, HydraError::ZeroAmount);
+            check_earning_limit(&ctx.accounts.registry, amount)?;
+            require!(
+                ctx.remaining_accounts[..i].iter().all(|a| a.key() != info.key()),
+                HydraError::InvalidAgentAccount
+            );
+
+            let mut agent: Account<AgentAccount> = Account::try_from(info)?;
+            check_agent_namespace(info.key, &agent, &namespace)?;
+            require!(!agent.is_frozen, HydraError::AgentFrozen);
+            require!(agent.can_earn(&clock), HydraError::AgentInactive);
+            check_quarantine(&ctx.accounts.registry, &agent, QUARANTINE_EARNINGS, clock.unix_timestamp)?;
+
+            agent.credit_earning(amount, EarningCategory::Other, clock.epoch);
+            agent.touch(clock.unix_timestamp);
+            if agent.parent != Pubkey::default() {
+                agent.unrolled_earnings = agent.unrolled_earnings.checked_add(amount).unwrap();
+            }
+
+            emit!(EarningRecorded {
+                agent: agent.key(),
+                amount,
+                total_earned: agent.total_earned,
+                epoch_earned: agent.epoch_earned,
+                nonce: 0,
+                category: EarningCategory::Other,
+                bucket: AccountingBucket::Reported,
+                seq: ctx.accounts.stats.next_event_seq(),
+            });
+            check_earning_goal(&mut agent);
+            agent.exit(&crate::ID)?;
+
+            total = total.checked_add(amount).unwrap();
+        }
+
+        ctx.accounts.stats.record_reported(total, EarningCategory::Other, clock.epoch);
+
+        Ok(())
+    }
+
+    /// Record earnings denominated in an SPL token mint (agent wallet only).
+    /// Tracked per `(agent, mint)` in a `TokenEarnings` account, separately from SOL.
+    pub fn record_token_earning(
+        ctx: Context<RecordTokenEarning>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, HydraError::ZeroAmount);
+        require!(ctx.accounts.agent.can_earn(&Clock::get()?), HydraError::AgentInactive);
+
+        let token_earnings = &mut ctx.accounts.token_earnings;
+        token_earnings.agent = ctx.accounts.agent.key();
+        token_earnings.mint = ctx.accounts.mint.key();
+        token_earnings.total_earned = token_earnings.total_earned.checked_add(amount).unwrap();
+        token_earnings.bump = ctx.bumps.token_earnings;
+
+        emit!(TokenEarningRecorded {
+            agent: token_earnings.agent,
+            mint: token_earnings.mint,
+            amount,
+            total_earned: token_earnings.total_earned,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Move an agent's pending subtree earnings one level up to its parent. Permissionless.
+    pub fn roll_up_earnings(ctx: Context<RollUpEarnings>, namespace: String) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        check_agent_namespace(&agent.key(), agent, &namespace)?;
+        let amount = agent.unrolled_earnings;
+        if amount == 0 {
+            return Ok(());
+        }
+        agent.unrolled_earnings = 0;
+
+        credit_subtree(agent.key(), &mut ctx.accounts.parent_agent, amount);
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Distribute SOL revenue from child to parent via system transfer.
+    ///
+    /// If the parent has a grandparent and a nonzero `forward_share_bps`, the
+    /// grandparent accounts must be supplied and the forward cut is sent to the
+    /// grandparent directly; the parent receives the remainder.
+    pub fn distribute_to_parent(
+        ctx: Context<DistributeToParent>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        send_to_parent(ctx, amount, 0, false)
+    }
+
+    /// `distribute_to_parent` co-signed by the parent's wallet, which bypasses
+    /// the child's `max_single_distribution` and `max_daily_distribution` caps.
+    /// The amount still counts toward the day's total.
+    pub fn distribute_with_parent_override(
+        ctx: Context<DistributeToParent>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require!(ctx.accounts.parent_wallet.is_signer, HydraError::Unauthorized);
+        send_to_parent(ctx, amount, 0, true)
+    }
+
+    /// `distribute_to_parent` with the amount taken as `bps` of the child's
+    /// undistributed earnings (`total_earned - total_distributed_to_parent`),
+    /// rounded down. The rest stays with the child and is reported as `remainder`.
+    pub fn distribute_percentage(
+        ctx: Context<DistributeToParent>,
+        _namespace: String,
+        bps: u16,
+    ) -> Result<()> {
+        require!(bps <= 10_000, HydraError::InvalidRevenueShare);
+        let child = &ctx.accounts.child_agent;
+        let undistributed = child
+            .total_earned
+            .saturating_sub(child.total_distributed_to_parent);
+        let (share, remainder) = split_bps(undistributed, bps);
+        send_to_parent(ctx, share, remainder, false)
+    }
+
+    /// Commit the signer's agent to distributing at least `min_amount` to its
+    /// parent every `interval_secs`. A single `distribute_to_parent` (or
+    /// `distribute_percentage`) of at least `min_amount` that supplies the
+    /// commitment account satisfies the current interval.
+    pub fn commit_distribution_schedule(
+        ctx: Context<CommitDistributionSchedule>,
+        _namespace: String,
+        min_amount: u64,
+        interval_secs: i64,
+    ) -> Result<()> {
+        require!(min_amount > 0, HydraError::ZeroAmount);
+        require!(interval_secs > 0, HydraError::InvalidConfigValue);
+        let agent = &ctx.accounts.agent;
+        require!(agent.parent != Pubkey::default(), HydraError::NoParentAgent);
+
+        let commitment = &mut ctx.accounts.commitment;
+        commitment.agent = agent.key();
+        commitment.min_amount = min_amount;
+        commitment.interval_secs = interval_secs;
+        commitment.last_satisfied_at = Clock::get()?.unix_timestamp;
+        commitment.last_flagged_at = 0;
+        commitment.missed_count = 0;
+        commitment.bump = ctx.bumps.commitment;
+
+        emit!(DistributionCommitted {
+            agent: commitment.agent,
+            min_amount,
+            interval_secs,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Record a missed interval on a distribution commitment. Permissionless;
+    /// fails with `CommitmentNotDue` until a full interval has passed since the
+    /// last qualifying distribution or the last flag, whichever is later.
+    pub fn flag_missed_distribution(ctx: Context<FlagMissedDistribution>, _namespace: String) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let commitment = &mut ctx.accounts.commitment;
+        let due_at = commitment
+            .last_satisfied_at
+            .max(commitment.last_flagged_at)
+            .saturating_add(commitment.interval_secs);
+        require!(now >= due_at, HydraError::CommitmentNotDue);
+
+        commitment.missed_count = commitment.missed_count.checked_add(1).unwrap();
+        commitment.last_flagged_at = now;
+
+        emit!(CommitmentMissed {
+            agent: commitment.agent,
+            missed_count: commitment.missed_count,
+            due_at,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Revoke a distribution commitment, refunding its rent to the child wallet.
+    /// The parent's wallet must co-sign.
+    pub fn revoke_distribution_commitment(
+        ctx: Context<RevokeDistributionCommitment>,
+        _namespace: String,
+    ) -> Result<()> {
+        emit!(DistributionCommitmentRevoked {
+            agent: ctx.accounts.child_agent.key(),
+            missed_count: ctx.accounts.commitment.missed_count,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Cascade `amount` from a child's wallet up its whole ancestor chain.
+    ///
+    /// `remaining_accounts` holds `(agent, wallet)` pairs from the child's parent up
+    /// to the root. Each agent passes `revenue_share_bps` of what it receives to its
+    /// parent and keeps the rest. An inactive ancestor's kept portion rolls up to
+    /// the nearest active ancestor above it; if none exists it stays with the child.
+    pub fn distribute_up_chain<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeUpChain<'info>>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require_top_level(&ctx.accounts.registry)?;
+        require!(amount > 0, HydraError::ZeroAmount);
+
+        let clock = Clock::get()?;
+        let child = &ctx.accounts.child_agent;
+        require!(child.can_distribute(&clock), HydraError::ChildInactive);
+        require!(child.parent != Pubkey::default(), HydraError::NoParentAgent);
+        check_distribution_interval(&ctx.accounts.registry, child, clock.unix_timestamp)?;
+        check_quarantine(&ctx.accounts.registry, child, QUARANTINE_DISTRIBUTIONS, clock.unix_timestamp)?;
+        check_distribution_limits(child, amount, clock.unix_timestamp)?;
+
+        let pairs = ctx.remaining_accounts;
+        check_batch_nodes(&ctx.accounts.registry, pairs)?;
+        require!(
+            !pairs.is_empty()
+                && pairs.len().is_multiple_of(2)
+                && pairs.len() <= 2 * MAX_DEPTH_CEILING as usize,
+            HydraError::InvalidChain
+        );
+        let mut ancestors = Vec::with_capacity(pairs.len() / 2);
+        let mut expected = child.parent;
+        for pair in pairs.chunks_exact(2) {
+            let agent = Account::<AgentAccount>::try_from(&pair[0])?;
+            require_keys_eq!(agent.key(), expected, HydraError::InvalidChain);
+            require!(!agent.is_frozen, HydraError::AgentFrozen);
+            require_keys_eq!(pair[1].key(), agent.wallet, HydraError::InvalidChain);
+            require_keys_eq!(
+                *pair[1].owner,
+                system_program::ID,
+                HydraError::InvalidParentWalletOwner
+            );
+            expected = agent.parent;
+            ancestors.push(agent);
+        }
+        require_keys_eq!(expected, Pubkey::default(), HydraError::InvalidChain);
+
+        let mut shares = vec![child.revenue_share_bps];
+        shares.extend(ancestors[..ancestors.len() - 1].iter().map(|a| a.revenue_share_bps));
+        let cuts = compute_chain_cuts(amount, &shares);
+
+        let mut pending = 0u64;
+        let mut skipped: Vec<(Pubkey, u64)> = Vec::new();
+        let mut distributed = 0u64;
+        for (i, ancestor) in ancestors.iter_mut().enumerate() {
+            let retained = cuts[i] - cuts.get(i + 1).copied().unwrap_or(0);
+            if !ancestor.is_operational(&clock) {
+                pending = pending.checked_add(retained).unwrap();
+                skipped.push((ancestor.key(), retained));
+                continue;
+            }
+
+            let payout = retained.checked_add(pending).unwrap();
+            if payout > 0 {
+                let wallet = &pairs[2 * i + 1];
+                check_destination(&ctx.accounts.registry, child, wallet.key)?;
+                transfer_lamports(
+                    &ctx.accounts.system_program,
+                    ctx.accounts.child_wallet.to_account_info(),
+                    wallet.clone(),
+                    payout,
+                )?;
+                ancestor.total_received_from_children = ancestor
+                    .total_received_from_children
+                    .checked_add(payout)
+                    .unwrap();
+                ancestor.exit(&crate::ID)?;
+                distributed = distributed.checked_add(payout).unwrap();
+            }
+            for (skipped_agent, skipped_amount) in skipped.drain(..) {
+                emit!(SkippedInactiveAncestor {
+                    child: child.key(),
+                    skipped: skipped_agent,
+                    amount: skipped_amount,
+                    routed_to: Some(ancestor.key()),
+                });
+            }
+            pending = 0;
+        }
+        // No active ancestor above these: their portion never leaves the child's wallet
+        for (skipped_agent, skipped_amount) in skipped {
+            emit!(SkippedInactiveAncestor {
+                child: child.key(),
+                skipped: skipped_agent,
+                amount: skipped_amount,
+                routed_to: None,
+            });
+        }
+
+        ctx.accounts.stats.record_transfer(distributed, clock.epoch);
+
+        let child_mut = &mut ctx.accounts.child_agent;
+        child_mut.touch(clock.unix_timestamp);
+        child_mut.last_distributed_at = clock.unix_timestamp;
+        child_mut.record_daily_distribution(distributed, clock.unix_timestamp);
+        child_mut.total_distributed_to_parent = child_mut
+            .total_distributed_to_parent
+            .checked_add(distributed)
+            .unwrap();
+
+        emit!(ChainDistributed {
+            child: child_mut.key(),
+            amount,
+            remainder: amount - cuts[0],
+            distributed,
+            returned_to_child: pending,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Fund a parent's children from its wallet, splitting `amount` across the active
+    /// children in proportion to their `revenue_share_bps`.
+    ///
+    /// `remaining_accounts` holds `(child agent, child wallet)` pairs. Inactive
+    /// children are skipped and their weight spread over the rest; rounding dust
+    /// stays with the parent.
+    pub fn distribute_to_children<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeToChildren<'info>>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require_top_level(&ctx.accounts.registry)?;
+        require!(amount > 0, HydraError::ZeroAmount);
+
+        let clock = Clock::get()?;
+        let parent = &ctx.accounts.parent_agent;
+        require!(parent.is_operational(&clock), HydraError::AgentInactive);
+
+        let pairs = ctx.remaining_accounts;
+        check_batch_nodes(&ctx.accounts.registry, pairs)?;
+        require!(
+            !pairs.is_empty() && pairs.len().is_multiple_of(2),
+            HydraError::InvalidAgentAccount
+        );
+        let mut recipients = Vec::with_capacity(pairs.len() / 2);
+        let mut total_weight = 0u64;
+        for (i, pair) in pairs.chunks_exact(2).enumerate() {
+            require!(
+                pairs[..2 * i].iter().step_by(2).all(|a| a.key() != pair[0].key()),
+                HydraError::InvalidAgentAccount
+            );
+            let child = Account::<AgentAccount>::try_from(&pair[0])?;
+            require_keys_eq!(child.parent, parent.key(), HydraError::NotAChild);
+            require_keys_eq!(pair[1].key(), child.wallet, HydraError::InvalidAgentAccount);
+            if !child.is_operational(&clock) {
+                continue;
+            }
+            total_weight = total_weight.checked_add(child.revenue_share_bps as u64).unwrap();
+            recipients.push((child.key(), &pair[1], child.revenue_share_bps as u64));
+        }
+        require!(total_weight > 0, HydraError::NoFundableChildren);
+
+        let mut distributed = 0u64;
+        for (child, wallet, weight) in recipients {
+            let share = ((amount as u128) * (weight as u128) / (total_weight as u128)) as u64;
+            if share == 0 {
+                continue;
+            }
+            transfer_lamports(
+                &ctx.accounts.system_program,
+                ctx.accounts.parent_wallet.to_account_info(),
+                wallet.clone(),
+                share,
+            )?;
+            distributed = distributed.checked_add(share).unwrap();
+
+            emit!(ChildFunded {
+                parent: parent.key(),
+                child,
+                amount: share,
+            });
+        }
+
+        ctx.accounts.stats.record_transfer(distributed, clock.epoch);
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// An external payer pays an agent, with the parent's `revenue_share_bps` cut
+    /// sent straight from the payer to the parent's wallet and the remainder to the
+    /// child's wallet, so the child never holds the parent's portion. Root agents
+    /// (no parent accounts) receive the full amount.
+    pub fn pay_agent_with_split(
+        ctx: Context<PayAgentWithSplit>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, HydraError::ZeroAmount);
+        check_earning_limit(&ctx.accounts.registry, amount)?;
+
+        let clock = Clock::get()?;
+        let child = &ctx.accounts.child_agent;
+        require!(child.can_earn(&clock), HydraError::ChildInactive);
+        check_quarantine(&ctx.accounts.registry, child, QUARANTINE_EARNINGS, clock.unix_timestamp)?;
+
+        let parent_cut = if child.parent == Pubkey::default() {
+            0
+        } else {
+            let parent = ctx
+                .accounts
+                .parent_agent
+                .as_ref()
+                .ok_or(HydraError::MissingParentAccount)?;
+            if ctx.accounts.registry.require_active_lineage {
+                require!(parent.is_operational(&clock), HydraError::AncestorInactive);
+            }
+            bps_share(amount, child.revenue_share_bps)
+        };
+
+        let referral_bps = ctx.accounts.registry.referral_bps;
+        let referral_cut = match ctx.accounts.referral.as_ref() {
+            Some(referral) if referral_bps > 0 && !referral.is_expired(clock.unix_timestamp) => {
+                require!(
+                    child.revenue_share_bps as u32 + referral_bps as u32 <= 10_000,
+                    HydraError::InvalidRevenueShare
+                );
+                bps_share(amount, referral_bps)
+            }
+            _ => 0,
+        };
+
+        if referral_cut > 0 {
+            let referring_wallet = ctx
+                .accounts
+                .referring_wallet
+                .as_ref()
+                .ok_or(HydraError::MissingReferralAccounts)?;
+            require!(
+                ctx.accounts.referring_agent.is_some(),
+                HydraError::MissingReferralAccounts
+            );
+            transfer_lamports(
+                &ctx.accounts.system_program,
+                ctx.accounts.payer.to_account_info(),
+                referring_wallet.to_account_info(),
+                referral_cut,
+            )?;
+        }
+
+        if parent_cut > 0 {
+            let parent_wallet = ctx
+                .accounts
+                .parent_wallet
+                .as_ref()
+                .ok_or(HydraError::MissingParentAccount)?;
+            transfer_lamports(
+                &ctx.accounts.system_program,
+                ctx.accounts.payer.to_account_info(),
+                parent_wallet.to_account_info(),
+                parent_cut,
+            )?;
+        }
+        let child_amount = amount
+            .checked_sub(parent_cut)
+            .and_then(|rest| rest.checked_sub(referral_cut))
+            .unwrap();
+        if child_amount > 0 {
+            transfer_lamports(
+                &ctx.accounts.system_program,
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.child_wallet.to_account_info(),
+                child_amount,
+            )?;
+        }
+
+        let stats = &mut ctx.accounts.stats;
+        stats.record_reported(amount, EarningCategory::Other, clock.epoch);
+        stats.record_transfer(amount, clock.epoch);
+
+        let child_mut = &mut ctx.accounts.child_agent;
+        child_mut.credit_earning(amount, EarningCategory::Other, clock.epoch);
+        child_mut.touch(clock.unix_timestamp);
+
+        emit!(EarningRecorded {
+            agent: child_mut.key(),
+            amount,
+            total_earned: child_mut.total_earned,
+            epoch_earned: child_mut.epoch_earned,
+            nonce: 0,
+            category: EarningCategory::Other,
+            bucket: AccountingBucket::Reported,
+            seq: ctx.accounts.stats.next_event_seq(),
+        });
+        check_earning_goal(child_mut);
+
+        if let Some(parent_mut) = ctx.accounts.parent_agent.as_mut() {
+            credit_subtree(child_mut.key(), parent_mut, amount);
+
+            child_mut.total_distributed_to_parent = child_mut
+                .total_distributed_to_parent
+                .checked_add(parent_cut)
+                .unwrap();
+            parent_mut.total_received_from_children = parent_mut
+                .total_received_from_children
+                .checked_add(parent_cut)
+                .unwrap();
+
+            emit!(RevenueDistributed {
+                child: child_mut.key(),
+                parent: parent_mut.key(),
+                amount: parent_cut,
+                remainder: child_amount,
+                total_distributed: child_mut.total_distributed_to_parent,
+                total_received_by_parent: parent_mut.total_received_from_children,
+                direct_to_root: false,
+                bucket: AccountingBucket::Transferred,
+                seq: ctx.accounts.stats.next_event_seq(),
+            });
+        }
+
+        emit!(DirectSplitPayment {
+            payer: ctx.accounts.payer.key(),
+            child: child_mut.key(),
+            parent: child_mut.parent,
+            amount,
+            parent_cut,
+        });
+
+        if referral_cut > 0 {
+            let referring_agent = ctx.accounts.referring_agent.as_mut().unwrap();
+            referring_agent.total_referral_earnings = referring_agent
+                .total_referral_earnings
+                .checked_add(referral_cut)
+                .unwrap();
+
+            emit!(ReferralPaid {
+                payer: ctx.accounts.payer.key(),
+                referring_agent: referring_agent.key(),
+                agent: child_mut.key(),
+                amount: referral_cut,
+                total_referral_earnings: referring_agent.total_referral_earnings,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Record the agent that introduced the signing payer. One referral per payer;
+    /// it lapses after the registry's `referral_duration_secs`.
+    pub fn register_referral(ctx: Context<RegisterReferral>, _namespace: String) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let duration = ctx.accounts.registry.referral_duration_secs;
+
+        let referral = &mut ctx.accounts.referral;
+        referral.payer = ctx.accounts.payer.key();
+        referral.referring_agent = ctx.accounts.referring_agent.key();
+        referral.created_at = now;
+        referral.expires_at = if duration == 0 { 0 } else { now.saturating_add(duration) };
+        referral.bump = ctx.bumps.referral;
+
+        emit!(ReferralRegistered {
+            payer: referral.payer,
+            referring_agent: referral.referring_agent,
+            expires_at: referral.expires_at,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Send SOL from any agent's wallet straight to the root agent's wallet,
+    /// for flat revenue models that bypass intermediate parents.
+    pub fn distribute_to_root(
+        ctx: Context<DistributeToRoot>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require_top_level(&ctx.accounts.registry)?;
+        require!(amount > 0, HydraError::ZeroAmount);
+
+        let clock = Clock::get()?;
+        let agent = &ctx.accounts.agent;
+        require!(agent.can_distribute(&clock), HydraError::AgentInactive);
+        require!(agent.parent != Pubkey::default(), HydraError::NoParentAgent);
+        check_distribution_interval(&ctx.accounts.registry, agent, clock.unix_timestamp)?;
+        check_quarantine(&ctx.accounts.registry, agent, QUARANTINE_DISTRIBUTIONS, clock.unix_timestamp)?;
+        check_distribution_limits(agent, amount, clock.unix_timestamp)?;
+
+        let root = &ctx.accounts.root_agent;
+        require!(
+            root.parent == Pubkey::default() && root.depth == 0,
+            HydraError::NotRootAgent
+        );
+        check_destination(&ctx.accounts.registry, agent, &ctx.accounts.root_wallet.key())?;
+
+        transfer_lamports(
+            &ctx.accounts.system_program,
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.root_wallet.to_account_info(),
+            amount,
+        )?;
+
+        ctx.accounts.stats.record_transfer(amount, clock.epoch);
+
+        let agent_mut = &mut ctx.accounts.agent;
+        agent_mut.last_distributed_at = clock.unix_timestamp;
+        agent_mut.record_daily_distribution(amount, clock.unix_timestamp);
+        agent_mut.total_distributed_to_parent = agent_mut
+            .total_distributed_to_parent
+            .checked_add(amount)
+            .unwrap();
+
+        let root_mut = &mut ctx.accounts.root_agent;
+        root_mut.total_received_from_subtree = root_mut
+            .total_received_from_subtree
+            .checked_add(amount)
+            .unwrap();
+
+        emit!(RevenueDistributed {
+            child: agent_mut.key(),
+            parent: root_mut.key(),
+            amount,
+            remainder: 0,
+            total_distributed: agent_mut.total_distributed_to_parent,
+            total_received_by_parent: root_mut.total_received_from_subtree,
+            direct_to_root: true,
+            bucket: AccountingBucket::Transferred,
+            seq: ctx.accounts.stats.next_event_seq(),
+        });
+
+        Ok(())
+    }
+
+    /// Distribute SOL revenue from child to its secondary beneficiary agent.
+    pub fn distribute_to_secondary(
+        ctx: Context<DistributeToSecondary>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require_top_level(&ctx.accounts.registry)?;
+        require!(amount > 0, HydraError::ZeroAmount);
+
+        let clock = Clock::get()?;
+        let child = &ctx.accounts.child_agent;
+        require!(child.can_distribute(&clock), HydraError::AgentInactive);
+        check_distribution_interval(&ctx.accounts.registry, child, clock.unix_timestamp)?;
+        check_quarantine(&ctx.accounts.registry, child, QUARANTINE_DISTRIBUTIONS, clock.unix_timestamp)?;
+        check_distribution_limits(child, amount, clock.unix_timestamp)?;
+        require!(
+            child.secondary_beneficiary == Some(ctx.accounts.secondary_agent.key()),
+            HydraError::InvalidSecondaryBeneficiary
+        );
+        check_destination(&ctx.accounts.registry, child, &ctx.accounts.secondary_wallet.key())?;
+
+        transfer_lamports(
+            &ctx.accounts.system_program,
+            ctx.accounts.child_wallet.to_account_info(),
+            ctx.accounts.secondary_wallet.to_account_info(),
+            amount,
+        )?;
+
+        ctx.accounts.stats.record_transfer(amount, clock.epoch);
+
+        let child_mut = &mut ctx.accounts.child_agent;
+        child_mut.last_distributed_at = clock.unix_timestamp;
+        child_mut.record_daily_distribution(amount, clock.unix_timestamp);
+        child_mut.total_distributed_to_secondary = child_mut
+            .total_distributed_to_secondary
+            .checked_add(amount)
+            .unwrap();
+
+        emit!(SecondaryRevenueDistributed {
+            child: child_mut.key(),
+            secondary: ctx.accounts.secondary_agent.key(),
+            amount,
+            total_distributed: child_mut.total_distributed_to_secondary,
+            bucket: AccountingBucket::Transferred,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the agent's earning goal (0 = none) and re-arm `GoalReached` (agent wallet only).
+    pub fn set_earning_goal(
+        ctx: Context<UpdateAgent>,
+        _namespace: String,
+        earning_goal: u64,
+    ) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        agent.earning_goal = earning_goal;
+        agent.goal_reached = false;
+
+        emit!(EarningGoalSet {
+            agent: agent.key(),
+            earning_goal,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Lock the agent until `until_ts`, blocking deactivation, bond withdrawal and
+    /// reparenting meanwhile (agent wallet only). A lock can be extended but not shortened.
+    pub fn lock_agent(
+        ctx: Context<UpdateAgent>,
+        _namespace: String,
+        until_ts: i64,
+    ) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        require!(
+            until_ts > Clock::get()?.unix_timestamp && until_ts >= agent.locked_until,
+            HydraError::InvalidLockTime
+        );
+        agent.locked_until = until_ts;
+
+        emit!(AgentLocked {
+            agent: agent.key(),
+            locked_until: until_ts,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Clear an expired lock (agent wallet only).
+    pub fn unlock_agent(ctx: Context<UpdateAgent>, _namespace: String) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        require!(agent.locked_until != 0, HydraError::AgentNotLocked);
+        check_unlocked(agent, Clock::get()?.unix_timestamp)?;
+        agent.locked_until = 0;
+
+        emit!(AgentUnlocked { agent: agent.key() });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Permanently freeze the agent's profile URI, tag, forward share, split config
+    /// and withdrawal whitelist (agent wallet only). Cannot be undone.
+    pub fn finalize_agent(ctx: Context<UpdateAgent>, _namespace: String) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        check_mutable(agent)?;
+        agent.is_immutable = true;
+
+        emit!(AgentFinalized { agent: agent.key() });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Point the agent at an off-chain profile JSON, with the document's hash so
+    /// consumers can verify it. Signed by the agent wallet; an empty `uri` clears it.
+    /// Accounts created before the field existed must first go through `migrate_agent`.
+    pub fn update_agent_uri(
+        ctx: Context<UpdateAgent>,
+        _namespace: String,
+        uri: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        check_limit("uri length", uri.len(), MAX_URI_LEN, HydraError::UriTooLong)?;
+
+        let agent = &mut ctx.accounts.agent;
+        check_mutable(agent)?;
+        let old_content_hash = agent.content_hash;
+        agent.uri = uri;
+        agent.content_hash = content_hash;
+
+        emit!(AgentUriUpdated {
+            agent: agent.key(),
+            uri: agent.uri.clone(),
+            old_content_hash,
+            new_content_hash: content_hash,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the agent's free-form tag (e.g. "prod") for frontend filtering.
+    /// Signed by the agent wallet.
+    pub fn set_agent_tag(
+        ctx: Context<UpdateAgent>,
+        _namespace: String,
+        tag: [u8; 16],
+    ) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        check_mutable(agent)?;
+        let old_tag = agent.tag;
+        agent.tag = tag;
+
+        emit!(TagChanged {
+            agent: agent.key(),
+            old_tag,
+            new_tag: tag,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the share of incoming child revenue forwarded to this agent's parent.
+    pub fn set_forward_share(
+        ctx: Context<SetForwardShare>,
+        _namespace: String,
+        forward_share_bps: u16,
+    ) -> Result<()> {
+        require!(forward_share_bps <= 10_000, HydraError::InvalidRevenueShare);
+
+        let agent = &mut ctx.accounts.agent;
+        check_mutable(agent)?;
+        agent.forward_share_bps = forward_share_bps;
+
+        emit!(ForwardShareSet {
+            agent: agent.key(),
+            forward_share_bps,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Cap what this agent's wallet can send out per distribution and per UTC day
+    /// (agent wallet only). Zero means unlimited. The caps cover every
+    /// distribution the wallet signs; only `distribute_with_parent_override`
+    /// bypasses them.
+    pub fn set_distribution_limits(
+        ctx: Context<UpdateAgent>,
+        _namespace: String,
+        max_single_distribution: u64,
+        max_daily_distribution: u64,
+    ) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        check_mutable(agent)?;
+        agent.max_single_distribution = max_single_distribution;
+        agent.max_daily_distribution = max_daily_distribution;
+
+        emit!(DistributionLimitsSet {
+            agent: agent.key(),
+            max_single_distribution,
+            max_daily_distribution,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Configure custom revenue-split recipients for an agent (agent wallet only).
+    pub fn set_split_config(
+        ctx: Context<SetSplitConfig>,
+        _namespace: String,
+        recipients: Vec<SplitRecipient>,
+    ) -> Result<()> {
+        require!(
+            recipients.len() <= MAX_SPLIT_RECIPIENTS,
+            HydraError::TooManySplitRecipients
+        );
+        let total_bps = recipients
+            .iter()
+            .try_fold(0u16, |acc, r| acc.checked_add(r.bps))
+            .ok_or(HydraError::InvalidRevenueShare)?;
+        require!(total_bps <= 10_000, HydraError::InvalidRevenueShare);
+        check_mutable(&ctx.accounts.agent)?;
+
+        let split_config = &mut ctx.accounts.split_config;
+        split_config.agent = ctx.accounts.agent.key();
+        split_config.recipients = recipients;
+        split_config.bump = ctx.bumps.split_config;
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set the agent's withdrawal destination whitelist (agent wallet only).
+    /// Unused slots are `Pubkey::default()`; an all-default list means no restriction.
+    pub fn set_withdraw_whitelist(
+        ctx: Context<SetWithdrawWhitelist>,
+        _namespace: String,
+        whitelist: [Pubkey; MAX_WHITELIST_LEN],
+    ) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        check_mutable(agent)?;
+        agent.withdraw_whitelist = whitelist;
+
+        emit!(WithdrawWhitelistSet {
+            agent: agent.key(),
+            whitelist,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Distribute SOL from the agent wallet across its configured split recipients.
+    /// Recipient accounts are passed in `remaining_accounts` in config order.
+    pub fn distribute_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeSplit<'info>>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require_top_level(&ctx.accounts.registry)?;
+        require!(amount > 0, HydraError::ZeroAmount);
+        let clock = Clock::get()?;
+        require!(ctx.accounts.agent.can_distribute(&clock), HydraError::AgentInactive);
+        check_distribution_interval(&ctx.accounts.registry, &ctx.accounts.agent, clock.unix_timestamp)?;
+        check_quarantine(
+            &ctx.accounts.registry,
+            &ctx.accounts.agent,
+            QUARANTINE_DISTRIBUTIONS,
+            clock.unix_timestamp,
+        )?;
+        check_distribution_limits(&ctx.accounts.agent, amount, clock.unix_timestamp)?;
+
+        let recipients = &ctx.accounts.split_config.recipients;
+        require!(
+            ctx.remaining_accounts.len() == recipients.len(),
+            HydraError::InvalidSplitRecipient
+        );
+
+        let mut sent = 0u64;
+        for (recipient, account) in recipients.iter().zip(ctx.remaining_accounts.iter()) {
+            require_keys_eq!(
+                account.key(),
+                recipient.recipient,
+                HydraError::InvalidSplitRecipient
+            );
+
+            let share = bps_share(amount, recipient.bps);
+            if share > 0 {
+                check_destination(&ctx.accounts.registry, &ctx.accounts.agent, &account.key())?;
+                transfer_lamports(
+                    &ctx.accounts.system_program,
+                    ctx.accounts.wallet.to_account_info(),
+                    account.clone(),
+                    share,
+                )?;
+                ctx.accounts.stats.record_transfer(share, clock.epoch);
+                sent = sent.checked_add(share).unwrap();
+            }
+
+            emit!(SplitDistributed {
+                agent: ctx.accounts.agent.key(),
+                recipient: recipient.recipient,
+                bps: recipient.bps,
+                amount: share,
+                bucket: AccountingBucket::Transferred,
+            });
+        }
+        let agent = &mut ctx.accounts.agent;
+        agent.last_distributed_at = clock.unix_timestamp;
+        agent.record_daily_distribution(sent, clock.unix_timestamp);
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Dry-run of a multi-hop distribution of `amount` from a leaf up to its root.
+    ///
+    /// `remaining_accounts` holds the agent chain from leaf to root. Each agent
+    /// passes `revenue_share_bps` of what it receives to its parent. Emits a
+    /// `WaterfallReport` with what each ancestor would keep; no lamports move.
+    pub fn report_waterfall<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReportWaterfall<'info>>,
+        _namespace: String,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, HydraError::ZeroAmount);
+        check_batch_nodes(&ctx.accounts.registry, ctx.remaining_accounts)?;
+        let chain = load_chain(ctx.remaining_accounts)?;
+
+        let shares: Vec<u16> = chain[..chain.len() - 1]
+            .iter()
+            .map(|agent| agent.revenue_share_bps)
+            .collect();
+        let cuts = compute_chain_cuts(amount, &shares);
+
+        let hops = chain[1..]
+            .iter()
+            .enumerate()
+            .map(|(i, agent)| WaterfallHop {
+                agent: agent.key(),
+                received: cuts[i],
+                retained: cuts[i] - cuts.get(i + 1).copied().unwrap_or(0),
+            })
+            .collect();
+
+        emit!(WaterfallReport {
+            leaf: chain[0].key(),
+            amount,
+            leaf_residual: amount - cuts.first().copied().unwrap_or(0),
+            hops,
+        });
+
+        Ok(())
+    }
+
+    /// Emit an agent's current net position. Permissionless.
+    pub fn report_position(ctx: Context<ReportPosition>) -> Result<()> {
+        let agent = &ctx.accounts.agent;
+
+        emit!(PositionReport {
+            agent: agent.key(),
+            total_earned: agent.total_earned,
+            total_distributed_to_parent: agent.total_distributed_to_parent,
+            total_received_from_children: agent.total_received_from_children,
+            net_position: agent.net_position(),
+        });
+
+        Ok(())
+    }
+
+    /// Read-only probe: whether `candidate` is a registered agent account.
+    /// Anchor returns the bool through `set_return_data`, so simulating is enough.
+    pub fn agent_exists(ctx: Context<AgentExists>) -> Result<bool> {
+        let candidate = &ctx.accounts.candidate;
+        if candidate.owner != &crate::ID {
+            return Ok(false);
+        }
+        let data = candidate.try_borrow_data()?;
+        Ok(AgentAccount::try_deserialize(&mut &data[..]).is_ok())
+    }
+
+    /// View: summary of an agent's state, returned via `set_return_data`.
+    pub fn get_agent_summary(ctx: Context<ReportPosition>) -> Result<views::AgentSummary> {
+        let agent = &ctx.accounts.agent;
+        Ok(views::AgentSummary {
+            agent: agent.key(),
+            wallet: agent.wallet,
+            parent: agent.parent,
+            depth: agent.depth,
+            children_count: agent.children_count,
+            is_operational: agent.is_operational(&Clock::get()?),
+            total_earned: agent.total_earned,
+            net_position: agent.net_position(),
+        })
+    }
+
+    /// View: how `amount` would be divided across the agent's split recipients,
+    /// returned via `set_return_data`. No lamports move.
+    pub fn get_projected_split(ctx: Context<GetProjectedSplit>, amount: u64) -> Result<views::ProjectedSplit> {
+        let shares: Vec<views::ProjectedShare> = ctx
+            .accounts
+            .split_config
+            .recipients
+            .iter()
+            .map(|r| views::ProjectedShare {
+                recipient: r.recipient,
+                amount: bps_share(amount, r.bps),
+            })
+            .collect();
+        let distributed = shares.iter().map(|s| s.amount).sum::<u64>();
+
+        Ok(views::ProjectedSplit {
+            amount,
+            shares,
+            retained: amount - distributed,
+        })
+    }
+
+    /// View: whether `ancestor` is a strict ancestor of `descendant`, and how many
+    /// hops separate them, returned via `set_return_data`.
+    ///
+    /// `remaining_accounts` holds the agents between them, from the descendant's
+    /// parent upward (or up to the root when there is no ancestry). Reaching the
+    /// root yields `false`; a chain that breaks or runs out is `InvalidChain`.
+    pub fn is_ancestor<'info>(
+        ctx: Context<'_, '_, 'info, 'info, IsAncestor<'info>>,
+    ) -> Result<views::Ancestry> {
+        let ancestor = ctx.accounts.ancestor.key();
+        let chain = ctx.remaining_accounts;
+        require!(chain.len() < MAX_DEPTH_CEILING as usize, HydraError::InvalidChain);
+
+        let mut next = ctx.accounts.descendant.parent;
+        for hops in 1..=chain.len() as u8 + 1 {
+            if next == ancestor {
+                return Ok(views::Ancestry { is_ancestor: true, hops });
+            }
+            if next == Pubkey::default() {
+                return Ok(views::Ancestry { is_ancestor: false, hops: 0 });
+            }
+            let info = chain.get(hops as usize - 1).ok_or(HydraError::InvalidChain)?;
+            require_keys_eq!(*info.key, next, HydraError::InvalidChain);
+            next = Account::<AgentAccount>::try_from(info)?.parent;
+        }
+        err!(HydraError::InvalidChain)
+    }
+
+    /// View: wallets of the active children of `parent` among the candidate
+    /// agents in `remaining_accounts`, returned via `set_return_data`.
+    ///
+    /// Candidates that aren't agent accounts, belong to another parent, are
+    /// inactive, or repeat an earlier candidate are skipped, so a client can check
+    /// a claimed child set against `children_count` in one simulation.
+    pub fn list_children<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ListChildren<'info>>,
+    ) -> Result<Vec<Pubkey>> {
+        let candidates = ctx.remaining_accounts;
+        check_limit(
+            "candidates",
+            candidates.len(),
+            MAX_LISTED_CHILDREN,
+            HydraError::InvalidBatchSize,
+        )?;
+
+        let parent = ctx.accounts.parent.key();
+        let mut wallets = Vec::with_capacity(candidates.len());
+        for (i, info) in candidates.iter().enumerate() {
+            if info.owner != &crate::ID || candidates[..i].iter().any(|c| c.key == info.key) {
+                continue;
+            }
+            let data = info.try_borrow_data()?;
+            if let Ok(child) = AgentAccount::try_deserialize(&mut &data[..]) {
+                if child.parent == parent && child.status.is_live() {
+                    wallets.push(child.wallet);
+                }
+            }
+        }
+        Ok(wallets)
+    }
+
+    /// View: which agents' `specialization` starts with `prefix`, as a bitmask
+    /// returned via `set_return_data`. Bit 0 is `agent`; bit `i + 1` is
+    /// `remaining_accounts[i]`, left clear for accounts that aren't agents. With no
+    /// remaining accounts the result is 0 or 1, usable as a bool.
+    pub fn matches_spec_prefix<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReportPosition<'info>>,
+        prefix: String,
+    ) -> Result<u64> {
+        check_limit("prefix length", prefix.len(), MAX_SPEC_LEN, HydraError::SpecTooLong)?;
+        check_limit(
+            "candidates",
+            ctx.remaining_accounts.len(),
+            u64::BITS as usize - 1,
+            HydraError::InvalidBatchSize,
+        )?;
+
+        let mut mask = ctx.accounts.agent.specialization.starts_with(&prefix) as u64;
+        for (i, info) in ctx.remaining_accounts.iter().enumerate() {
+            if info.owner != &crate::ID {
+                continue;
+            }
+            let data = info.try_borrow_data()?;
+            if let Ok(agent) = AgentAccount::try_deserialize(&mut &data[..]) {
+                if agent.specialization.starts_with(&prefix) {
+                    mask |= 1 << (i + 1);
+                }
+            }
+        }
+        Ok(mask)
+    }
+
+    /// View: an agent's depth against the registry's max depth, returned via `set_return_data`.
+    pub fn get_lineage_depth(
+        ctx: Context<GetLineageDepth>,
+        _namespace: String,
+    ) -> Result<views::LineageDepth> {
+        let depth = ctx.accounts.agent.depth;
+        let max_depth = ctx.accounts.registry.max_depth;
+        Ok(views::LineageDepth {
+            depth,
+            max_depth,
+            levels_below: max_depth.saturating_sub(depth),
+        })
+    }
+
+    /// View: registry-wide totals and currency metadata, returned via `set_return_data`.
+    pub fn get_registry_stats(
+        ctx: Context<ReconcileReport>,
+        _namespace: String,
+    ) -> Result<views::RegistryStats> {
+        let registry = &ctx.accounts.registry;
+        let stats = &ctx.accounts.stats;
+        Ok(views::RegistryStats {
+            total_agents: stats.total_agents,
+            total_spawns: stats.total_spawns,
+            total_reported_earnings: stats.total_reported_earnings,
+            total_transferred_volume: stats.total_transferred_volume,
+            max_depth: registry.max_depth,
+            decimals: registry.decimals,
+            currency_symbol: registry.currency_symbol,
+            event_seq: stats.event_seq,
+        })
+    }
+
+    /// View: whether `agent` could spawn a child right now, returned via
+    /// `set_return_data`. A rejection carries the `HydraError` code `spawn_child`
+    /// would fail with (e.g. `MaxDepthReached`, `ParentInactive`, `TreeNotBalanced`).
+    pub fn probe_spawn_allowed(
+        ctx: Context<GetLineageDepth>,
+        _namespace: String,
+    ) -> Result<views::SpawnProbe> {
+        let clock = Clock::get()?;
+        let registry = &ctx.accounts.registry;
+        let parent = &ctx.accounts.agent;
+        let result = check_spawn_parent(registry, &ctx.accounts.stats, parent, &clock)
+            .and_then(|()| check_quarantine(registry, parent, QUARANTINE_SPAWNS, clock.unix_timestamp));
+
+        Ok(match result {
+            Ok(()) => views::SpawnProbe {
+                allowed: true,
+                error_code: 0,
+            },
+            Err(Error::AnchorError(e)) => views::SpawnProbe {
+                allowed: false,
+                error_code: e.error_code_number,
+            },
+            Err(e) => return Err(e),
+        })
+    }
+
+    /// View: canonical PDA and bump for an account kind, returned via `set_return_data`.
+    ///
+    /// Kinds: 0 registry (`seed_key` ignored), 1 agent (wallet), 2 split config
+    /// (agent), 3 bond (agent), 4 wallet agent counter (wallet), 5 leaderboard
+    /// (`seed_key` ignored), 6 referral (payer), 7 global stats (`seed_key` ignored),
+    /// 8 snapshot log (`seed_key` ignored), 9 oracle record (oracle). All but the
+    /// split config and bond, which are keyed by an agent PDA, derive under `namespace`.
+    pub fn verify_pda(
+        _ctx: Context<VerifyPda>,
+        namespace: String,
+        kind: u8,
+        seed_key: Pubkey,
+    ) -> Result<views::DerivedPda> {
+        let (address, bump) = match kind {
+            0 => Registry::find_namespaced_address(&namespace, &crate::ID),
+            1 => AgentAccount::find_address(&namespace, &seed_key, &crate::ID),
+            2 => SplitConfig::find_address(&seed_key, &crate::ID),
+            3 => Bond::find_address(&seed_key, &crate::ID),
+            4 => WalletAgentCounter::find_address(&seed_key, &namespace, &crate::ID),
+            5 => Leaderboard::find_address(&namespace, &crate::ID),
+            6 => Referral::find_address(&seed_key, &namespace, &crate::ID),
+            7 => GlobalStats::find_address(&namespace, &crate::ID),
+            8 => SnapshotLog::find_address(&namespace, &crate::ID),
+            9 => OracleRecord::find_address(&seed_key, &namespace, &crate::ID),
+            _ => return err!(HydraError::InvalidPdaKind),
+        };
+        Ok(views::DerivedPda { address, bump })
+    }
+
+    /// Deactivate an agent. The agent wallet must wait out the registry's
+    /// `min_active_secs` after creation; the registry authority may deactivate any time.
+    pub fn deactivate_agent(ctx: Context<DeactivateAgent>, _namespace: String) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let registry = &ctx.accounts.registry;
+        let agent = &mut ctx.accounts.agent;
+        check_unlocked(agent, now)?;
+        if ctx.accounts.authority.key() != registry.authority {
+            require!(
+                now.saturating_sub(agent.created_at) >= registry.min_active_secs,
+                HydraError::TooSoonToDeactivate
+            );
+        }
+
+        agent.set_status(AgentStatus::Deactivated)?;
+        agent.deactivated_at = now;
+        agent.deactivated_by_authority = ctx.accounts.authority.key() != agent.wallet;
+
+        emit!(AgentDeactivated {
+            agent: agent.key(),
+            wallet: agent.wallet,
+            seq: ctx.accounts.stats.next_event_seq(),
+        });
+
+        Ok(())
+    }
+
+    /// Archive an agent: no instruction may modify it afterwards, including
+    /// counter updates caused by its children, so nothing can be spawned under it.
+    /// The registry authority or the agent's wallet; cannot be undone.
+    pub fn freeze_agent(ctx: Context<SetAgentStatus>, _namespace: String) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        agent.set_status(AgentStatus::Frozen)?;
+
+        emit!(AgentFrozen {
+            agent: agent.key(),
+            frozen_by: ctx.accounts.authority.key(),
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Reactivate a deactivated agent, starting the registry's reactivation
+    /// quarantine. The registry authority may reactivate any agent; an agent's
+    /// wallet only one it deactivated itself.
+    pub fn reactivate_agent(ctx: Context<SetAgentStatus>, _namespace: String) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let registry = &ctx.accounts.registry;
+        let agent = &mut ctx.accounts.agent;
+        require!(!agent.status.is_live(), HydraError::AgentAlreadyActive);
+        if ctx.accounts.authority.key() != registry.authority {
+            require!(!agent.deactivated_by_authority, HydraError::Unauthorized);
+        }
+
+        let quarantined = registry.quarantine_gates != 0 && registry.reactivation_quarantine_secs > 0;
+        agent.set_status(if quarantined {
+            AgentStatus::Quarantined
+        } else {
+            AgentStatus::Active
+        })?;
+        agent.deactivated_at = 0;
+        agent.deactivated_by_authority = false;
+        agent.reactivated_at = now;
+        agent.touch(now);
+
+        emit!(AgentReactivated {
+            agent: agent.key(),
+            wallet: agent.wallet,
+            quarantine_until: now.saturating_add(registry.reactivation_quarantine_secs),
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Record a liveness heartbeat, clearing any stale flag (agent wallet only).
+    pub fn heartbeat(ctx: Context<Heartbeat>, _namespace: String) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        agent.touch(Clock::get()?.unix_timestamp);
+
+        emit!(AgentHeartbeat {
+            agent: agent.key(),
+            timestamp: agent.last_active_at,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Move a child under a new parent. Both parents' wallets sign, plus the child's
+    /// wallet when the registry requires it.
+    ///
+    /// `remaining_accounts` holds the new parent's ancestors up to its root, which
+    /// must not include the child (see `assert_not_descendant`). An agent with children keeps
+    /// its depth, so the new parent must sit at the old parent's depth; otherwise
+    /// its descendants' stored depths would go stale. The child's pending earnings
+    /// must be rolled up first; its branch then moves between the parents' subtree
+    /// totals (see `move_subtree`).
+    pub fn reparent_agent<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReparentAgent<'info>>,
+        _namespace: String,
+    ) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        if registry.reparent_requires_child {
+            require!(
+                ctx.accounts.child_wallet.as_ref().is_some_and(|w| w.is_signer),
+                HydraError::Unauthorized
+            );
+        }
+
+        let child = &ctx.accounts.child_agent;
+        check_unlocked(child, Clock::get()?.unix_timestamp)?;
+        let old_parent = &ctx.accounts.old_parent;
+        let new_parent = &ctx.accounts.new_parent;
+        require_keys_neq!(new_parent.key(), old_parent.key(), HydraError::InvalidReparent);
+        require!(new_parent.is_operational(&Clock::get()?), HydraError::ParentInactive);
+
+        check_batch_nodes(registry, ctx.remaining_accounts)?;
+        assert_not_descendant(new_parent, &child.key(), ctx.remaining_accounts)?;
+
+        let old_depth = child.depth;
+        let new_depth = child_depth(new_parent.depth)?;
+        check_limit("depth", new_depth, registry.max_depth, HydraError::MaxDepthReached)?;
+        require!(
+            child.children_count == 0 || new_depth == old_depth,
+            HydraError::InvalidReparent
+        );
+        let share = child.revenue_share_bps as u32;
+        check_sibling_share(registry, new_parent, child.revenue_share_bps)?;
+
+        let old_parent_mut = &mut ctx.accounts.old_parent;
+        old_parent_mut.children_count = old_parent_mut.children_count.saturating_sub(1);
+        old_parent_mut.children_share_sum = old_parent_mut.children_share_sum.saturating_sub(share);
+
+        let new_parent_mut = &mut ctx.accounts.new_parent;
+        new_parent_mut.children_count = new_parent_mut.children_count.checked_add(1).unwrap();
+        new_parent_mut.children_share_sum = new_parent_mut.children_share_sum.checked_add(share).unwrap();
+        move_subtree(old_parent_mut, new_parent_mut, &ctx.accounts.child_agent)?;
+
+        let child_mut = &mut ctx.accounts.child_agent;
+        child_mut.parent = new_parent_mut.key();
+        child_mut.depth = new_depth;
+
+        let stats = &mut ctx.accounts.stats;
+        stats.remove_agent_at_depth(old_depth);
+        stats.record_agent_at_depth(new_depth);
+
+        emit!(AgentReparented {
+            child: child_mut.key(),
+            old_parent: ctx.accounts.old_parent.key(),
+            new_parent: child_mut.parent,
+            new_depth,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Detach an agent from its parent and make it a root. Authority only; the
+    /// parent must be inactive unless `force` is set.
+    ///
+    /// The agent's pending earnings must be rolled up first (`roll_up_earnings`);
+    /// the departing branch is then taken out of the old parent's subtree total
+    /// (see `detach_subtree`). The agent's descendants keep their old depths
+    /// until healed one at a time with `recompute_depth`.
+    pub fn promote_to_root(
+        ctx: Context<PromoteToRoot>,
+        _namespace: String,
+        force: bool,
+    ) -> Result<()> {
+        let old_parent = &mut ctx.accounts.old_parent;
+        require!(
+            force || !old_parent.is_operational(&Clock::get()?),
+            HydraError::ParentStillActive
+        );
+        let agent = &mut ctx.accounts.agent;
+        require!(agent.unrolled_earnings == 0, HydraError::PendingRollUp);
+        old_parent.children_count = old_parent.children_count.saturating_sub(1);
+        old_parent.children_share_sum = old_parent
+            .children_share_sum
+            .saturating_sub(agent.revenue_share_bps as u32);
+        detach_subtree(old_parent, agent);
+
+        let old_depth = agent.depth;
+        agent.parent = Pubkey::default();
+        agent.depth = 0;
+        agent.revenue_share_bps = 0;
+
+        let stats = &mut ctx.accounts.stats;
+        stats.remove_agent_at_depth(old_depth);
+        stats.record_agent_at_depth(0);
+
+        emit!(AgentPromotedToRoot {
+            agent: agent.key(),
+            old_parent: old_parent.key(),
+            old_depth,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Set an agent's depth to one below its parent's, healing subtrees after
+    /// `promote_to_root`. Permissionless.
+    pub fn recompute_depth(ctx: Context<RecomputeDepth>, _namespace: String) -> Result<()> {
+        let new_depth = child_depth(ctx.accounts.parent_agent.depth)?;
+        check_limit("depth", new_depth, MAX_DEPTH_CEILING, HydraError::MaxDepthReached)?;
+
+        let agent = &mut ctx.accounts.agent;
+        let old_depth = agent.depth;
+        if old_depth == new_depth {
+            return Ok(());
+        }
+        agent.depth = new_depth;
+
+        let stats = &mut ctx.accounts.stats;
+        stats.remove_agent_at_depth(old_depth);
+        stats.record_agent_at_depth(new_depth);
+
+        emit!(AgentDepthRecomputed {
+            agent: agent.key(),
+            old_depth,
+            new_depth,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Push out a child's expiry (parent wallet only). `new_expires_at` must be
+    /// later than the current expiry, or 0 to remove it.
+    pub fn extend_agent_ttl(
+        ctx: Context<ExtendAgentTtl>,
+        _namespace: String,
+        new_expires_at: i64,
+    ) -> Result<()> {
+        let agent = &mut ctx.accounts.agent;
+        require!(agent.is_operational(&Clock::get()?), HydraError::AgentInactive);
+        require!(
+            new_expires_at == 0 || (agent.expires_at != 0 && new_expires_at > agent.expires_at),
+            HydraError::InvalidExpiry
+        );
+
+        agent.expires_at = new_expires_at;
+
+        emit!(AgentTtlExtended {
+            agent: agent.key(),
+            expires_at: new_expires_at,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Deactivate an agent past its `expires_at`, making the expiry explicit
+    /// in account state. Permissionless.
+    pub fn expire_agent(ctx: Context<ExpireAgent>, _namespace: String) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let agent = &mut ctx.accounts.agent;
+        require!(agent.status.is_live(), HydraError::AgentInactive);
+        require!(
+            agent.expires_at != 0 && now >= agent.expires_at,
+            HydraError::AgentNotExpired
+        );
+
+        agent.set_status(AgentStatus::Expired)?;
+        agent.deactivated_at = now;
+        agent.deactivated_by_authority = false;
+
+        emit!(AgentExpired {
+            agent: agent.key(),
+            wallet: agent.wallet,
+            expires_at: agent.expires_at,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Flag an agent whose last activity is older than the registry stale
+    /// threshold. The flag clears on the agent's next activity. Permissionless.
+    pub fn mark_stale(ctx: Context<MarkStale>, _namespace: String) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let agent = &mut ctx.accounts.agent;
+        require!(agent.status.is_live(), HydraError::AgentInactive);
+        require!(
+            now.saturating_sub(agent.last_active_at) > ctx.accounts.registry.stale_threshold_secs,
+            HydraError::AgentNotStale
+        );
+
+        agent.is_stale = true;
+
+        emit!(AgentMarkedStale {
+            agent: agent.key(),
+            last_active_at: agent.last_active_at,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Withdraw an agent's bond after voluntary deactivation and the cooldown.
+    /// Closes the bond account, returning bond and rent to the agent wallet.
+    pub fn withdraw_bond(ctx: Context<WithdrawBond>, _namespace: String) -> Result<()> {
+        let agent = &ctx.accounts.agent;
+        require!(
+            !agent.status.is_live() && !agent.deactivated_by_authority,
+            HydraError::BondLocked
+        );
+        check_unlocked(agent, Clock::get()?.unix_timestamp)?;
+        let unlock_at = agent
+            .deactivated_at
+            .checked_add(ctx.accounts.registry.bond_cooldown_secs)
+            .unwrap();
+        require!(
+            Clock::get()?.unix_timestamp >= unlock_at,
+            HydraError::BondCooldownActive
+        );
+
+        emit!(BondWithdrawn {
+            agent: agent.key(),
+            wallet: agent.wallet,
+            amount: ctx.accounts.bond.amount,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Slash up to `amount` from an agent's bond into the fee vault. Authority only.
+    /// Slashing more than the bond holds caps at the bond balance.
+    pub fn slash_bond(
+        ctx: Context<SlashBond>,
+        _namespace: String,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        require!(amount > 0, HydraError::ZeroAmount);
+        check_limit("reason length", reason.len(), MAX_REASON_LEN, HydraError::ReasonTooLong)?;
+
+        let bond = &mut ctx.accounts.bond;
+        let slashed = amount.min(bond.amount);
+        bond.amount = bond.amount.checked_sub(slashed).unwrap();
+
+        let bond_info = bond.to_account_info();
+        let vault_info = ctx.accounts.fee_vault.to_account_info();
+        **bond_info.try_borrow_mut_lamports()? = bond_info.lamports().checked_sub(slashed).unwrap();
+        **vault_info.try_borrow_mut_lamports()? = vault_info.lamports().checked_add(slashed).unwrap();
+
+        emit!(BondSlashed {
+            agent: bond.agent,
+            amount: slashed,
+            remaining: bond.amount,
+            reason,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Correct an agent's `category` earnings by `delta`. Authority only, at
+    /// most once per `adjustment_cooldown_secs` per agent.
+    ///
+    /// The agent's total, category and current-epoch figures move together,
+    /// mirrored on the global stats, and the delta reaches the parent's subtree
+    /// total like a recorded earning would. A downward adjustment past zero
+    /// (overall or in `category`) fails rather than clamping; `attested_earned`
+    /// is lowered to stay within the new total. `parent_agent` is required to
+    /// take back earnings already rolled up to it.
+    pub fn adjust_earning(
+        ctx: Context<AdjustEarning>,
+        _namespace: String,
+        delta: i64,
+        category: EarningCategory,
+        reason_code: u8,
+    ) -> Result<()> {
+        require!(delta != 0, HydraError::ZeroAmount);
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        let accounts = ctx.accounts;
+        let agent = &mut accounts.agent;
+        require!(
+            agent.adjustment_count == 0
+                || now.saturating_sub(agent.last_adjusted_at)
+                    >= accounts.registry.adjustment_cooldown_secs,
+            HydraError::AdjustmentCooldown
+        );
+
+        let old_total = agent.total_earned;
+        let magnitude = delta.unsigned_abs();
+        let agent_key = agent.key();
+        if delta > 0 {
+            agent.credit_earning(magnitude, category, clock.epoch);
+            accounts.stats.record_reported(magnitude, category, clock.epoch);
+            if agent.parent != Pubkey::default() {
+                match accounts.parent_agent.as_mut() {
+                    Some(parent) => credit_subtree(agent_key, parent, magnitude),
+                    None => {
+                        agent.unrolled_earnings = agent.unrolled_earnings.checked_add(magnitude).unwrap();
+                    }
+                }
+            }
+        } else {
+            agent.debit_earning(magnitude, category, clock.epoch)?;
+            withdraw_subtree_credit(agent, accounts.parent_agent.as_deref_mut(), magnitude)?;
+            accounts.stats.unrecord_reported(magnitude, category, clock.epoch);
+        }
+        agent.adjustment_count = agent.adjustment_count.checked_add(1).unwrap();
+        agent.last_adjusted_at = now;
+
+        emit!(EarningAdjusted {
+            agent: agent_key,
+            delta,
+            category,
+            reason_code,
+            old_total,
+            new_total: agent.total_earned,
+            adjustment_count: agent.adjustment_count,
+        });
+
+        accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Return SOL accidentally sent to the agent PDA (anything above rent
+    /// exemption) to the agent's wallet. Agent wallet only.
+    pub fn sweep_agent_pda(ctx: Context<SweepAgentPda>, _namespace: String) -> Result<()> {
+        let agent_info = ctx.accounts.agent.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(agent_info.data_len());
+        let surplus = agent_info.lamports().saturating_sub(rent_exempt);
+        require!(surplus > 0, HydraError::NothingToSweep);
+
+        let wallet_info = ctx.accounts.wallet.to_account_info();
+        **agent_info.try_borrow_mut_lamports()? = agent_info.lamports().checked_sub(surplus).unwrap();
+        **wallet_info.try_borrow_mut_lamports()? = wallet_info.lamports().checked_add(surplus).unwrap();
+
+        emit!(DustSwept {
+            agent: agent_info.key(),
+            amount: surplus,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Grow an agent account to the current `AgentAccount::SIZE` and bring it to
+    /// `AGENT_SCHEMA_VERSION`. New fields are zero-initialized; the payer covers
+    /// any extra rent. No-op for accounts already on the current schema.
+    pub fn migrate_agent(ctx: Context<MigrateAgent>) -> Result<()> {
+        let info = ctx.accounts.agent.to_account_info();
+        require_keys_eq!(*info.owner, crate::ID, HydraError::InvalidAgentAccount);
+        require!(
+            info.try_borrow_data()?.starts_with(AgentAccount::DISCRIMINATOR),
+            HydraError::InvalidAgentAccount
+        );
+
+        if info.data_len() < AgentAccount::SIZE {
+            let required = Rent::get()?.minimum_balance(AgentAccount::SIZE);
+            let shortfall = required.saturating_sub(info.lamports());
+            if shortfall > 0 {
+                transfer_lamports(
+                    &ctx.accounts.system_program,
+                    ctx.accounts.payer.to_account_info(),
+                    info.clone(),
+                    shortfall,
+                )?;
+            }
+            info.resize(AgentAccount::SIZE)?;
+        }
+
+        let mut agent = AgentAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        let from_version = agent.schema_version;
+        if from_version >= AGENT_SCHEMA_VERSION {
+            return Ok(());
+        }
+
+        if from_version < 1 {
+            agent.last_active_at = agent.created_at;
+        }
+        // v2 provenance fields stay default: the original creator is unknown and
+        // rent refunds fall back to the agent wallet.
+        if from_version < 23 {
+            agent.status = if agent.is_frozen {
+                AgentStatus::Frozen
+            } else if agent.is_active {
+                AgentStatus::Active
+            } else if agent.expires_at != 0 && agent.deactivated_at >= agent.expires_at {
+                AgentStatus::Expired
+            } else {
+                AgentStatus::Deactivated
+            };
+        }
+        agent.schema_version = AGENT_SCHEMA_VERSION;
+        agent.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        emit!(AgentMigrated {
+            agent: info.key(),
+            from_version,
+            to_version: AGENT_SCHEMA_VERSION,
+        });
+
+        Ok(())
+    }
+
+    /// Grow the registry to the current `Registry::SIZE`. Appended fields are
+    /// zero-initialized; the payer covers any extra rent. No-op when already current.
+    pub fn migrate_registry(ctx: Context<MigrateRegistry>) -> Result<()> {
+        let info = ctx.accounts.registry.to_account_info();
+        require_keys_eq!(*info.owner, crate::ID, HydraError::InvalidConfigValue);
+        require!(
+            info.try_borrow_data()?.starts_with(Registry::DISCRIMINATOR),
+            HydraError::InvalidConfigValue
+        );
+
+        let from_size = info.data_len();
+        if from_size >= Registry::SIZE {
+            return Ok(());
+        }
+
+        let required = Rent::get()?.minimum_balance(Registry::SIZE);
+        let shortfall = required.saturating_sub(info.lamports());
+        if shortfall > 0 {
+            transfer_lamports(
+                &ctx.accounts.system_program,
+                ctx.accounts.payer.to_account_info(),
+                info.clone(),
+                shortfall,
+            )?;
+        }
+        info.resize(Registry::SIZE)?;
+
+        emit!(RegistryMigrated {
+            from_size: from_size as u32,
+            to_size: Registry::SIZE as u32,
+        });
+
+        Ok(())
+    }
+
+    /// Emit self-reported earnings against lamports actually moved through the
+    /// program. Permissionless.
+    pub fn reconcile_report(ctx: Context<ReconcileReport>, _namespace: String) -> Result<()> {
+        let stats = &ctx.accounts.stats;
+
+        emit!(ReconciliationReport {
+            total_reported_earnings: stats.total_reported_earnings,
+            total_transferred_volume: stats.total_transferred_volume,
+            difference: stats.total_reported_earnings as i128 - stats.total_transferred_volume as i128,
+        });
+
+        Ok(())
+    }
+
+    /// Record a tamper-evident checkpoint: sha256 over the global agent, reported
+    /// earnings and spawn counters plus the current slot, appended to the
+    /// `SnapshotLog` ring buffer. Authority only, at most once per
+    /// `min_snapshot_interval_secs`.
+    pub fn take_snapshot(ctx: Context<TakeSnapshot>, _namespace: String) -> Result<()> {
+        let clock = Clock::get()?;
+        let log = &mut ctx.accounts.snapshot_log;
+        require!(
+            log.count == 0
+                || clock.unix_timestamp.saturating_sub(log.last_taken_at)
+                    >= ctx.accounts.registry.min_snapshot_interval_secs,
+            HydraError::SnapshotTooSoon
+        );
+
+        let stats = &ctx.accounts.stats;
+        let mut preimage = Vec::with_capacity(32);
+        preimage.extend_from_slice(&stats.total_agents.to_le_bytes());
+        preimage.extend_from_slice(&stats.total_reported_earnings.to_le_bytes());
+        preimage.extend_from_slice(&stats.total_spawns.to_le_bytes());
+        preimage.extend_from_slice(&clock.slot.to_le_bytes());
+        let snapshot_hash = hash(&preimage).to_bytes();
+
+        log.bump = ctx.bumps.snapshot_log;
+        log.push(snapshot_hash, clock.slot, clock.unix_timestamp);
+
+        emit!(SnapshotTaken {
+            hash: snapshot_hash,
+            slot: clock.slot,
+            total_agents: stats.total_agents,
+            total_reported_earnings: stats.total_reported_earnings,
+            total_spawns: stats.total_spawns,
+            index: log.count - 1,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Overwrite a parent's `children_count` and `children_share_sum` from the
+    /// supplied agents whose `parent` is that agent. Authority only.
+    ///
+    /// `remaining_accounts` holds all of the parent's direct children; agents
+    /// belonging to another parent are ignored.
+    pub fn reconcile_children_count<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReconcileChildrenCount<'info>>,
+        _namespace: String,
+    ) -> Result<()> {
+        check_batch_nodes(&ctx.accounts.registry, ctx.remaining_accounts)?;
+        let parent_key = ctx.accounts.parent_agent.key();
+
+        let mut count: u64 = 0;
+        let mut share_sum: u32 = 0;
+        for (i, info) in ctx.remaining_accounts.iter().enumerate() {
+            require!(
+                ctx.remaining_accounts[..i].iter().all(|a| a.key() != info.key()),
+                HydraError::InvalidAgentAccount
+            );
+            let child: Account<AgentAccount> = Account::try_from(info)?;
+            if child.parent == parent_key {
+                count = count.checked_add(1).unwrap();
+                share_sum = share_sum.checked_add(child.revenue_share_bps as u32).unwrap();
+            }
+        }
+
+        let parent = &mut ctx.accounts.parent_agent;
+        let old_count = parent.children_count;
+        parent.children_count = count;
+        parent.children_share_sum = share_sum;
+
+        emit!(ChildrenCountReconciled {
+            agent: parent_key,
+            old_count,
+            new_count: count,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Close deactivated leaf agents past the registry grace period. Authority only.
+    ///
+    /// `remaining_accounts` holds `(agent, rent_destination, parent_agent, spec_counter,
+    /// bond, wallet)` groups; rent is returned to the agent's recorded rent payer
+    /// (see `AgentAccount::rent_refund_destination`) and the agent leaves its
+    /// specialization count. A posted bond is closed to the agent's wallet, so
+    /// slash it before pruning if the agent was deactivated for cause. Root agents
+    /// pass any account as the parent slot. Agents that don't qualify are skipped
+    /// rather than failing the sweep.
+    pub fn prune_inactive<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneInactive<'info>>,
+        namespace: String,
+    ) -> Result<()> {
+        check_batch_nodes(&ctx.accounts.registry, ctx.remaining_accounts)?;
+        let groups = ctx.remaining_accounts.chunks_exact(6);
+        require!(groups.remainder().is_empty(), HydraError::InvalidPruneAccounts);
+
+        let now = Clock::get()?.unix_timestamp;
+        let grace_secs = ctx.accounts.registry.prune_grace_secs;
+        let mut pruned: u32 = 0;
+
+        for group in groups {
+            let (agent_info, destination_info, parent_info) = (&group[0], &group[1], &group[2]);
+            let (counter_info, bond_info, wallet_info) = (&group[3], &group[4], &group[5]);
+
+            let agent: Account<AgentAccount> = Account::try_from(agent_info)?;
+            check_agent_namespace(agent_info.key, &agent, &namespace)?;
+            require_keys_eq!(
+                destination_info.key(),
+                agent.rent_refund_destination(),
+                HydraError::InvalidPruneAccounts
+            );
+
+            let qualifies = matches!(agent.status, AgentStatus::Deactivated | AgentStatus::Expired)
+                && agent.children_count == 0
+                && now.saturating_sub(agent.deactivated_at) > grace_secs;
+            if !qualifies {
+                continue;
+            }
+
+            if agent.parent != Pubkey::default() {
+                require_keys_eq!(parent_info.key(), agent.parent, HydraError::InvalidPruneAccounts);
+                let mut parent: Account<AgentAccount> = Account::try_from(parent_info)?;
+                require!(!parent.is_frozen, HydraError::AgentFrozen);
+                parent.children_count = parent.children_count.saturating_sub(1);
+                parent.children_share_sum = parent
+                    .children_share_sum
+                    .saturating_sub(agent.revenue_share_bps as u32);
+                parent.exit(&crate::ID)?;
+            }
+
+            let (counter_key, _) = SpecCounter::find_address(&agent.specialization, &namespace, &crate::ID);
+            require_keys_eq!(counter_info.key(), counter_key, HydraError::InvalidPruneAccounts);
+            let mut counter: Account<SpecCounter> = Account::try_from(counter_info)?;
+            decrement_spec_counter(&mut counter);
+            counter.exit(&crate::ID)?;
+
+            let (bond_key, _) = Bond::find_address(agent_info.key, &crate::ID);
+            require_keys_eq!(bond_info.key(), bond_key, HydraError::InvalidPruneAccounts);
+            require_keys_eq!(wallet_info.key(), agent.wallet, HydraError::InvalidPruneAccounts);
+            refund_bond(&agent, bond_info.clone(), wallet_info.clone())?;
+
+            close_account(agent_info.clone(), destination_info.clone())?;
+
+            let stats = &mut ctx.accounts.stats;
+            stats.total_agents = stats.total_agents.saturating_sub(1);
+            stats.remove_agent_at_depth(agent.depth);
+
+            emit!(AgentClosed {
+                agent: agent_info.key(),
+                wallet: agent.wallet,
+                rent_destination: destination_info.key(),
+            });
+
+            pruned = pruned.checked_add(1).unwrap();
+        }
+
+        emit!(PruneCompleted { pruned });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Close an agent, first moving all of its children under `new_parent`.
+    /// Signed by the closing agent's wallet and the new parent's wallet; rent goes
+    /// to the agent's recorded rent payer and a bond, if posted, back to the
+    /// wallet. The agent leaves its specialization count.
+    ///
+    /// `remaining_accounts` holds every child of the agent (exactly
+    /// `children_count` of them), followed by `new_parent`'s ancestors up to its
+    /// root, which must not include the agent (see `assert_not_descendant`). As
+    /// with `reparent_agent`, a child with children of its own must keep its depth.
+    /// `parent_agent` is required for a non-root agent unless `new_parent` is that
+    /// parent.
+    ///
+    /// The agent's and its children's pending earnings must be rolled up first.
+    /// The agent's branch leaves its parent's subtree total and each child's
+    /// branch is credited to `new_parent` (see `move_subtree`).
+    pub fn close_with_forward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseWithForward<'info>>,
+        _namespace: String,
+    ) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        check_batch_nodes(registry, ctx.remaining_accounts)?;
+        let now = Clock::get()?.unix_timestamp;
+
+        let agent = &ctx.accounts.agent;
+        let agent_key = agent.key();
+        check_unlocked(agent, now)?;
+        require!(
+            ctx.remaining_accounts.len() as u64 >= agent.children_count,
+            HydraError::LengthMismatch
+        );
+        let (children, ancestors) = ctx.remaining_accounts.split_at(agent.children_count as usize);
+
+        let new_parent = &mut ctx.accounts.new_parent;
+        require_keys_neq!(new_parent.key(), agent_key, HydraError::InvalidReparent);
+        require!(new_parent.is_operational(&Clock::get()?), HydraError::ParentInactive);
+        assert_not_descendant(new_parent, &agent_key, ancestors)?;
+        let new_depth = child_depth(new_parent.depth)?;
+        if !children.is_empty() {
+            check_limit("depth", new_depth, registry.max_depth, HydraError::MaxDepthReached)?;
+        }
+
+        // Detach the closing agent from its own parent first, so its share no
+        // longer counts against the sibling cap when that parent is `new_parent`.
+        require!(agent.unrolled_earnings == 0, HydraError::PendingRollUp);
+        if agent.parent != Pubkey::default() {
+            let share = agent.revenue_share_bps as u32;
+            let parent = if agent.parent == new_parent.key() {
+                &mut **new_parent
+            } else {
+                ctx.accounts
+                    .parent_agent
+                    .as_deref_mut()
+                    .ok_or(HydraError::MissingParentAccount)?
+            };
+            parent.children_count = parent.children_count.saturating_sub(1);
+            parent.children_share_sum = parent.children_share_sum.saturating_sub(share);
+            detach_subtree(parent, agent);
+        }
+
+        let stats = &mut ctx.accounts.stats;
+        for (i, info) in children.iter().enumerate() {
+            require!(
+                children[..i].iter().all(|c| c.key != info.key),
+                HydraError::InvalidChain
+            );
+            let mut child: Account<AgentAccount> = Account::try_from(info)?;
+            require_keys_eq!(child.parent, agent_key, HydraError::NotAChild);
+            require!(!child.is_frozen, HydraError::AgentFrozen);
+            check_unlocked(&child, now)?;
+            require!(
+                child.children_count == 0 || child.depth == new_depth,
+                HydraError::InvalidReparent
+            );
+            check_sibling_share(registry, new_parent, child.revenue_share_bps)?;
+
+            new_parent.children_count = new_parent.children_count.checked_add(1).unwrap();
+            new_parent.children_share_sum = new_parent
+                .children_share_sum
+                .checked_add(child.revenue_share_bps as u32)
+                .unwrap();
+            move_subtree(&mut ctx.accounts.agent, new_parent, &child)?;
+
+            stats.remove_agent_at_depth(child.depth);
+            stats.record_agent_at_depth(new_depth);
+            child.parent = new_parent.key();
+            child.depth = new_depth;
+            child.exit(&crate::ID)?;
+
+            emit!(AgentReparented {
+                child: info.key(),
+                old_parent: agent_key,
+                new_parent: child.parent,
+                new_depth,
+            });
+        }
+
+        let agent = &ctx.accounts.agent;
+        stats.total_agents = stats.total_agents.saturating_sub(1);
+        stats.remove_agent_at_depth(agent.depth);
+        decrement_spec_counter(&mut ctx.accounts.spec_counter);
+        refund_bond(
+            agent,
+            ctx.accounts.bond.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+        )?;
+
+        emit!(AgentClosed {
+            agent: agent_key,
+            wallet: agent.wallet,
+            rent_destination: ctx.accounts.rent_destination.key(),
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Fold a duplicate leaf agent into a sibling leaf and close it. Authority only.
+    ///
+    /// The source's earnings and distribution totals move to `target` (see
+    /// `AgentAccount::absorb`), as do any lamports its PDA holds above rent
+    /// exemption. Rent goes to the source's recorded rent payer; a bond, if
+    /// posted, is closed to the source's wallet. The source leaves its
+    /// specialization count and, when `leaderboard` is supplied, the board.
+    /// `parent_agent` is required unless both agents are roots.
+    pub fn merge_agents(ctx: Context<MergeAgents>, _namespace: String) -> Result<()> {
+        let source = &ctx.accounts.source;
+        let target = &ctx.accounts.target;
+        require_keys_neq!(source.key(), target.key(), HydraError::InvalidAgentAccount);
+        require_keys_eq!(source.parent, target.parent, HydraError::ParentMismatch);
+        require!(
+            source.children_count == 0 && target.children_count == 0,
+            HydraError::HasActiveChildren
+        );
+        check_unlocked(source, Clock::get()?.unix_timestamp)?;
+
+        if source.parent != Pubkey::default() {
+            let parent = ctx
+                .accounts
+                .parent_agent
+                .as_deref_mut()
+                .ok_or(HydraError::MissingParentAccount)?;
+            parent.children_count = parent.children_count.saturating_sub(1);
+            parent.children_share_sum = parent
+                .children_share_sum
+                .saturating_sub(source.revenue_share_bps as u32);
+        }
+
+        let source_info = ctx.accounts.source.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(source_info.data_len());
+        let moved_lamports = source_info.lamports().saturating_sub(rent_exempt);
+        if moved_lamports > 0 {
+            let target_info = ctx.accounts.target.to_account_info();
+            **source_info.try_borrow_mut_lamports()? =
+                source_info.lamports().checked_sub(moved_lamports).unwrap();
+            **target_info.try_borrow_mut_lamports()? =
+                target_info.lamports().checked_add(moved_lamports).unwrap();
+        }
+
+        refund_bond(
+            &ctx.accounts.source,
+            ctx.accounts.bond.to_account_info(),
+            ctx.accounts.source_wallet.to_account_info(),
+        )?;
+
+        let source = &ctx.accounts.source;
+        let target = &mut ctx.accounts.target;
+        target.absorb(source);
+
+        decrement_spec_counter(&mut ctx.accounts.spec_counter);
+        if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+            leaderboard.remove(source.key());
+            if let Some(evicted) = leaderboard.update(target.key(), target.total_earned) {
+                emit!(LeaderboardChanged {
+                    entered: target.key(),
+                    evicted,
+                    total_earned: target.total_earned,
+                });
+            }
+        }
+
+        let stats = &mut ctx.accounts.stats;
+        stats.total_agents = stats.total_agents.saturating_sub(1);
+        stats.remove_agent_at_depth(source.depth);
+
+        emit!(AgentsMerged {
+            source: source.key(),
+            target: target.key(),
+            merged_earned: source.total_earned,
+            moved_lamports,
+            total_earned: target.total_earned,
+        });
+        emit!(AgentClosed {
+            agent: source.key(),
+            wallet: source.wallet,
+            rent_destination: ctx.accounts.rent_destination.key(),
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Escrow `amount` lamports in a new bounty posted by the signer's agent.
+    /// An `assignee` agent restricts who may claim; `None` leaves it open.
+    pub fn post_bounty(
+        ctx: Context<PostBounty>,
+        _namespace: String,
+        bounty_id: u64,
+        amount: u64,
+        assignee: Option<Pubkey>,
+        description_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(amount > 0, HydraError::ZeroAmount);
+        let poster = &ctx.accounts.poster_agent;
+        require!(poster.is_operational(&Clock::get()?), HydraError::AgentInactive);
+        require!(assignee != Some(poster.key()), HydraError::SelfClaim);
+
+        transfer_lamports(
+            &ctx.accounts.system_program,
+            ctx.accounts.poster_wallet.to_account_info(),
+            ctx.accounts.bounty.to_account_info(),
+            amount,
+        )?;
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.poster_agent = poster.key();
+        bounty.bounty_id = bounty_id;
+        bounty.amount = amount;
+        bounty.assignee = assignee;
+        bounty.claimant = None;
+        bounty.description_hash = description_hash;
+        bounty.status = BountyStatus::Open;
+        bounty.bump = ctx.bumps.bounty;
+
+        emit!(BountyPosted {
+            bounty: bounty.key(),
+            poster_agent: bounty.poster_agent,
+            bounty_id,
+            amount,
+            assignee,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Claim an open bounty for the signer's agent (assignee only, if one is set).
+    pub fn claim_bounty(ctx: Context<ClaimBounty>, _namespace: String) -> Result<()> {
+        let claimant = &ctx.accounts.claimant_agent;
+        require!(claimant.is_operational(&Clock::get()?), HydraError::AgentInactive);
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status.require(BountyStatus::Open)?;
+        require!(claimant.key() != bounty.poster_agent, HydraError::SelfClaim);
+        if let Some(assignee) = bounty.assignee {
+            require!(claimant.key() == assignee, HydraError::NotBountyAssignee);
+        }
+
+        bounty.claimant = Some(claimant.key());
+        bounty.status = BountyStatus::Claimed;
+
+        emit!(BountyClaimed {
+            bounty: bounty.key(),
+            claimant_agent: claimant.key(),
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Approve a claimed bounty and release the escrow to the claimant's wallet,
+    /// recording it as a `TaskCompletion` earning of the claimant. Poster wallet
+    /// only; the bounty account is closed to the poster's wallet.
+    pub fn approve_and_pay(ctx: Context<ApproveAndPay>, _namespace: String) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status.require(BountyStatus::Claimed)?;
+        let amount = bounty.amount;
+        bounty.status = BountyStatus::Paid;
+
+        let bounty_info = bounty.to_account_info();
+        let wallet_info = ctx.accounts.claimant_wallet.to_account_info();
+        **bounty_info.try_borrow_mut_lamports()? = bounty_info.lamports().checked_sub(amount).unwrap();
+        **wallet_info.try_borrow_mut_lamports()? = wallet_info.lamports().checked_add(amount).unwrap();
+
+        let accounts = ctx.accounts;
+        credit_recorded_earning(
+            &accounts.registry,
+            &mut accounts.stats,
+            &mut accounts.claimant_agent,
+            accounts.parent_agent.as_mut(),
+            accounts.leaderboard.as_mut(),
+            accounts.memo_program.as_ref(),
+            amount,
+            0,
+            Some(EarningCategory::TaskCompletion),
+        )?;
+        accounts.stats.record_transfer(amount, Clock::get()?.epoch);
+
+        emit!(BountyPaid {
+            bounty: accounts.bounty.key(),
+            poster_agent: accounts.bounty.poster_agent,
+            claimant_agent: accounts.claimant_agent.key(),
+            amount,
+            bucket: AccountingBucket::Transferred,
+        });
+
+        Ok(())
+    }
+
+    /// Send a claimed bounty back to `Open`, clearing the claimant, when the
+    /// poster will not approve the work. Poster wallet only.
+    pub fn reject_claim(ctx: Context<RejectClaim>, _namespace: String) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status.require(BountyStatus::Claimed)?;
+        let claimant_agent = bounty.claimant.take().unwrap_or_default();
+        bounty.status = BountyStatus::Open;
+
+        emit!(BountyClaimRejected {
+            bounty: bounty.key(),
+            poster_agent: bounty.poster_agent,
+            claimant_agent,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+
+    /// Cancel an unclaimed bounty, refunding the escrow and closing the bounty
+    /// account to the poster's wallet.
+    pub fn cancel_bounty(ctx: Context<CancelBounty>, _namespace: String) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status.require(BountyStatus::Open)?;
+        let amount = bounty.amount;
+        bounty.status = BountyStatus::Cancelled;
+
+        emit!(BountyCancelled {
+            bounty: bounty.key(),
+            poster_agent: bounty.poster_agent,
+            amount,
+        });
+
+        ctx.accounts.stats.next_event_seq();
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Portion of `amount` represented by `bps` basis points, rounded down.
+pub fn bps_share(amount: u64, bps: u16) -> u64 {
+    ((amount as u128) * (bps as u128) / 10_000) as u64
+}
+
+/// Split `amount` into its `bps` share (rounded down) and the remainder; the
+/// two always sum to exactly `amount`.
+pub fn split_bps(amount: u64, bps: u16) -> (u64, u64) {
+    let share = bps_share(amount, bps);
+    (share, amount - share)
+}
+
+/// Amounts passed up each hop of a chain when every agent forwards `shares[i]`
+/// basis points of what it received. `cuts[i]` is what agent `i` sends to its parent.
+pub fn compute_chain_cuts(amount: u64, shares: &[u16]) -> Vec<u64> {
+    let mut received = amount;
+    shares
+        .iter()
+        .map(|bps| {
+            received = bps_share(received, *bps);
+            received
+        })
+        .collect()
+}
+
+/// Load a leaf-to-root agent chain from `accounts`, validating each parent link.
+fn load_chain<'info>(accounts: &'info [AccountInfo<'info>]) -> Result<Vec<Account<'info, AgentAccount>>> {
+    require!(
+        !accounts.is_empty() && accounts.len() <= MAX_DEPTH_CEILING as usize + 1,
+        HydraError::InvalidChain
+    );
+
+    let chain = accounts
+        .iter()
+        .map(Account::<AgentAccount>::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    for pair in chain.windows(2) {
+        require_keys_eq!(pair[1].key(), pair[0].parent, HydraError::InvalidChain);
+    }
+    require_keys_eq!(
+        chain[chain.len() - 1].parent,
+        Pubkey::default(),
+        HydraError::InvalidChain
+    );
+
+    Ok(chain)
+}
+
+/// Reject a structural change that would make `candidate_parent` the parent of
+/// `agent` while `agent` is the candidate itself or one of its ancestors.
+///
+/// `accounts` holds the candidate's ancestors in any order (the candidate may be
+/// included). The walk follows `parent` links by key, rejects duplicate or
+/// missing accounts, and gives up after `MAX_DEPTH_CEILING` hops whatever depths
+/// the accounts claim.
+fn assert_not_descendant<'info>(
+    candidate_parent: &Account<'info, AgentAccount>,
+    agent: &Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        accounts.len() <= MAX_DEPTH_CEILING as usize + 1,
+        HydraError::InvalidChain
+    );
+    for (i, info) in accounts.iter().enumerate() {
+        require!(
+            accounts[..i].iter().all(|a| a.key != info.key),
+            HydraError::InvalidChain
+        );
+    }
+    require_keys_neq!(candidate_parent.key(), *agent, HydraError::InvalidReparent);
+
+    let mut next = candidate_parent.parent;
+    for _ in 0..=MAX_DEPTH_CEILING {
+        if next == Pubkey::default() {
+            return Ok(());
+        }
+        require_keys_neq!(next, *agent, HydraError::InvalidReparent);
+        let info = accounts
+            .iter()
+            .find(|a| *a.key == next)
+            .ok_or(HydraError::InvalidChain)?;
+        next = Account::<AgentAccount>::try_from(info)?.parent;
+    }
+    err!(HydraError::InvalidChain)
+}
+
+/// Reject control characters in agent names; in strict mode only `[a-zA-Z0-9_-]` is allowed.
+pub fn validate_name(name: &str, strict: bool) -> Result<()> {
+    require!(!name.chars().any(char::is_control), HydraError::InvalidNameChar);
+    if strict {
+        require!(
+            name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-'),
+            HydraError::InvalidNameChar
+        );
+    }
+    Ok(())
+}
+
+/// UTC day bucket of a Unix timestamp: whole days since 1970-01-01, rounding
+/// pre-epoch timestamps down.
+fn utc_day(unix_timestamp: i64) -> i64 {
+    unix_timestamp.div_euclid(SECONDS_PER_DAY)
+}
+
+/// The agent lifecycle's legal transitions. `Frozen` is terminal and no state
+/// transitions to itself.
+fn transition_status(from: AgentStatus, to: AgentStatus) -> Result<AgentStatus> {
+    use AgentStatus::*;
+    let legal = match from {
+        Active => matches!(to, Deactivated | Frozen | Expired),
+        Quarantined => matches!(to, Active | Deactivated | Frozen | Expired),
+        Deactivated => matches!(to, Active | Quarantined | Frozen),
+        Expired => matches!(to, Active | Quarantined | Deactivated | Frozen),
+        Frozen => false,
+    };
+    require!(legal, HydraError::InvalidStatusTransition);
+    Ok(to)
+}
+
+/// Move a `Quarantined` agent back to `Active` once its reactivation
+/// quarantine has elapsed.
+fn end_elapsed_quarantine(registry: &Registry, agent: &mut AgentAccount, now: i64) -> Result<()> {
+    if agent.status == AgentStatus::Quarantined
+        && now >= agent.reactivated_at.saturating_add(registry.reactivation_quarantine_secs)
+    {
+        agent.set_status(AgentStatus::Active)?;
+    }
+    Ok(())
+}
+
+/// Reject an outflow above the agent's single or daily cap (0 = unlimited).
+fn check_distribution_limits(child: &AgentAccount, amount: u64, now: i64) -> Result<()> {
+    if child.max_single_distribution > 0 {
+        check_limit(
+            "distribution",
+            amount,
+            child.max_single_distribution,
+            HydraError::DistributionLimitExceeded,
+        )?;
+    }
+    if child.max_daily_distribution > 0 {
+        check_limit(
+            "daily distribution",
+            child.distributed_on_day(now).saturating_add(amount),
+            child.max_daily_distribution,
+            HydraError::DistributionLimitExceeded,
+        )?;
+    }
+    Ok(())
+}
+
+/// Set any `MILESTONE_*` bits the agent now qualifies for, emitting
+/// `MilestoneUnlocked` once per newly set bit.
+fn award_milestones(agent: &mut Account<AgentAccount>) {
+    let reached = [
+        (MILESTONE_FIRST_EARNING, agent.total_earned > 0),
+        (MILESTONE_TEN_DISTRIBUTIONS, agent.distribution_count >= 10),
+        (MILESTONE_THIRTY_DAY_STREAK, agent.streak_days >= 30),
+    ];
+    for (milestone, qualifies) in reached {
+        if qualifies && !agent.has_milestone(milestone) {
+            agent.milestones |= 1 << milestone;
+            emit!(MilestoneUnlocked {
+                agent: agent.key(),
+                milestone,
+            });
+        }
+    }
+}
+
+/// Remove `amount` of the agent's earnings from the parent's subtree total,
+/// taking it from the agent's not-yet-rolled-up earnings first. The parent is
+/// required when part of `amount` already reached it; ancestors it has since
+/// rolled up to are not corrected.
+fn withdraw_subtree_credit(
+    agent: &mut AgentAccount,
+    parent: Option<&mut AgentAccount>,
+    amount: u64,
+) -> Result<()> {
+    if agent.parent == Pubkey::default() {
+        return Ok(());
+    }
+    let pending = agent.unrolled_earnings.min(amount);
+    let rolled = amount - pending;
+    if rolled > 0 {
+        let parent = parent.ok_or(HydraError::MissingParentAccount)?;
+        parent.subtree_earned = parent.subtree_earned.saturating_sub(rolled);
+        if parent.parent != Pubkey::default() {
+            parent.unrolled_earnings = parent.unrolled_earnings.saturating_sub(rolled);
+        }
+    }
+    agent.unrolled_earnings -= pending;
+    Ok(())
+}
+
+/// Take a departing child's branch (its own earnings plus its subtree total)
+/// out of the old parent's subtree total, and out of what the parent has yet
+/// to roll up. Ancestors above the old parent are not corrected.
+fn detach_subtree(old_parent: &mut AgentAccount, child: &AgentAccount) {
+    let branch = child.total_earned.saturating_add(child.subtree_earned);
+    old_parent.subtree_earned = old_parent.subtree_earned.saturating_sub(branch);
+    if old_parent.parent != Pubkey::default() {
+        old_parent.unrolled_earnings = old_parent.unrolled_earnings.saturating_sub(branch);
+    }
+}
+
+/// Move a child's branch from `old_parent`'s subtree total to `new_parent`'s
+/// (see `detach_subtree` and `credit_subtree`). The child's own pending
+/// earnings must have been rolled up, or they would reach the new parent twice.
+fn move_subtree(
+    old_parent: &mut AgentAccount,
+    new_parent: &mut Account<AgentAccount>,
+    child: &Account<AgentAccount>,
+) -> Result<()> {
+    require!(child.unrolled_earnings == 0, HydraError::PendingRollUp);
+    detach_subtree(old_parent, child);
+    let branch = child.total_earned.saturating_add(child.subtree_earned);
+    if branch > 0 {
+        credit_subtree(child.key(), new_parent, branch);
+    }
+    Ok(())
+}
+
+/// Emit `GoalReached` the first time an agent's `total_earned` meets its goal.
+fn check_earning_goal(agent: &mut Account<AgentAccount>) {
+    if agent.earning_goal == 0 || agent.goal_reached || agent.total_earned < agent.earning_goal {
+        return;
+    }
+    agent.goal_reached = true;
+
+    emit!(GoalReached {
+        agent: agent.key(),
+        earning_goal: agent.earning_goal,
+        total_earned: agent.total_earned,
+    });
+}
+
+/// Credit `amount` of descendant earnings to `parent`, queueing it for the next
+/// hop up unless the parent is a root.
+fn credit_subtree(from: Pubkey, parent: &mut Account<AgentAccount>, amount: u64) {
+    parent.subtree_earned = parent.subtree_earned.checked_add(amount).unwrap();
+    if parent.parent != Pubkey::default() {
+        parent.unrolled_earnings = parent.unrolled_earnings.checked_add(amount).unwrap();
+    }
+
+    emit!(SubtreeEarningRolledUp {
+        from,
+        to: parent.key(),
+        amount,
+    });
+}
+
+/// Reject `destination` if the registry enforces whitelists and the agent's
+/// non-empty whitelist doesn't include it.
+fn check_destination(registry: &Registry, agent: &AgentAccount, destination: &Pubkey) -> Result<()> {
+    if registry.enforce_withdraw_whitelist {
+        require!(
+            agent.allows_destination(destination),
+            HydraError::DestinationNotWhitelisted
+        );
+    }
+    Ok(())
+}
+
+/// Reject an action covered by `gate` while the agent is inside its reactivation
+/// quarantine. The quarantine ends exactly at `reactivated_at + reactivation_quarantine_secs`.
+fn check_quarantine(registry: &Registry, agent: &AgentAccount, gate: u8, now: i64) -> Result<()> {
+    if registry.quarantine_gates & gate != 0 && agent.reactivated_at != 0 {
+        require!(
+            now >= agent
+                .reactivated_at
+                .saturating_add(registry.reactivation_quarantine_secs),
+            HydraError::AgentInQuarantine
+        );
+    }
+    Ok(())
+}
+
+/// Reject an outflow from `agent` sooner than the registry's
+/// `min_distribution_interval_secs` after its last one.
+fn check_distribution_interval(registry: &Registry, agent: &AgentAccount, now: i64) -> Result<()> {
+    require!(
+        now.saturating_sub(agent.last_distributed_at) >= registry.min_distribution_interval_secs,
+        HydraError::DistributionTooSoon
+    );
+    Ok(())
+}
+
+/// When the registry enables memos, log `memo` through the SPL Memo program.
+fn emit_memo(registry: &Registry, memo_program: Option<&UncheckedAccount>, memo: String) -> Result<()> {
+    if !registry.emit_memos {
+        return Ok(());
+    }
+    let memo_program = memo_program.ok_or(HydraError::MissingMemoProgram)?;
+    let ix = Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: memo.into_bytes(),
+    };
+    invoke(&ix, &[memo_program.to_account_info()]).map_err(Into::into)
+}
+
+/// Reject actions on an agent whose `locked_until` is still in the future.
+fn check_unlocked(agent: &AgentAccount, now: i64) -> Result<()> {
+    require!(now >= agent.locked_until, HydraError::AgentLocked);
+    Ok(())
+}
+
+/// Fail with `error` when `value` exceeds `limit`, logging both so the rejected
+/// value shows up in the transaction logs.
+fn check_limit<T: PartialOrd + std::fmt::Display>(what: &str, value: T, limit: T, error: HydraError) -> Result<()> {
+    if value > limit {
+        msg!("{} {} exceeds limit {}", what, value, limit);
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+/// Reject an earning above the registry's `max_single_earning` (0 = unlimited).
+fn check_earning_limit(registry: &Registry, amount: u64) -> Result<()> {
+    if registry.max_single_earning == 0 {
+        return Ok(());
+    }
+    check_limit("earning", amount, registry.max_single_earning, HydraError::EarningExceedsLimit)
+}
+
+/// Reject configuration changes on a finalized agent.
+fn check_mutable(agent: &AgentAccount) -> Result<()> {
+    require!(!agent.is_immutable, HydraError::AgentImmutable);
+    Ok(())
+}
+
+/// Check that an agent taken from `remaining_accounts` is the PDA of its stored
+/// wallet and index under `namespace`. Seeded context accounts get this from
+/// their `seeds` constraint; loose accounts would otherwise reach across trees.
+fn check_agent_namespace(key: &Pubkey, agent: &AgentAccount, namespace: &str) -> Result<()> {
+    let bump = [agent.bump];
+    let index = agent.agent_index.to_le_bytes();
+    let namespace_seed = AgentAccount::namespace_seed(namespace);
+    let address = if agent.is_indexed {
+        Pubkey::create_program_address(
+            &seeds::indexed_agent_seeds(&namespace_seed, &agent.wallet, &index, &bump),
+            &crate::ID,
+        )
+    } else {
+        Pubkey::create_program_address(
+            &seeds::agent_seeds(&namespace_seed, &agent.wallet, &bump),
+            &crate::ID,
+        )
+    };
+    require!(address.ok() == Some(*key), HydraError::NamespaceMismatch);
+    Ok(())
+}
+
+/// Reject traversals over more `remaining_accounts` than the registry's
+/// `max_batch_nodes` allows.
+fn check_batch_nodes(registry: &Registry, accounts: &[AccountInfo]) -> Result<()> {
+    require!(
+        registry.max_batch_nodes == 0 || accounts.len() <= registry.max_batch_nodes as usize,
+        HydraError::BatchTooLarge
+    );
+    Ok(())
+}
+
+/// Check registry configuration invariants. Shared by `initialize` and the
+/// config setters, which apply their change first and then validate the result.
+fn validate_registry_config(registry: &Registry) -> Result<()> {
+    // Any nonzero u8 is within MAX_DEPTH_CEILING.
+    require!(registry.max_depth > 0, HydraError::InvalidMaxDepth);
+    require!(
+        registry.prune_grace_secs >= 0
+            && registry.bond_cooldown_secs >= 0
+            && registry.stale_threshold_secs >= 0
+            && registry.proposal_ttl_secs >= 0
+            && registry.min_active_secs >= 0
+            && registry.reactivation_quarantine_secs >= 0
+            && registry.referral_duration_secs >= 0
+            && registry.min_snapshot_interval_secs >= 0
+            && registry.adjustment_cooldown_secs >= 0
+            && registry.min_distribution_interval_secs >= 0,
+        HydraError::InvalidConfigValue
+    );
+    require!(
+        !registry.require_balanced || registry.branching_factor > 0,
+        HydraError::InvalidConfigValue
+    );
+    // The referral is paid on top of each child's own `revenue_share_bps`, which
+    // is chosen per agent at spawn time, so the two can only be checked together
+    // when `pay_agent_with_split` pays; here it is bounded on its own.
+    require!(registry.referral_bps <= 10_000, HydraError::InvalidRevenueShare);
+    Ok(())
+}
+
+/// Reject CPI invocations of protected instructions unless the registry allows them.
+fn require_top_level(registry: &Registry) -> Result<()> {
+    require!(
+        registry.allow_cpi || get_stack_height() <= TRANSACTION_LEVEL_STACK_HEIGHT,
+        HydraError::CpiNotAllowed
+    );
+    Ok(())
+}
+
+/// Reject a spawn whose child would be operated by the parent's own wallet.
+fn check_spawn_wallets(parent_wallet: &Pubkey, child_wallet: &Pubkey) -> Result<()> {
+    require_keys_neq!(*parent_wallet, *child_wallet, HydraError::SelfSpawn);
+    Ok(())
+}
+
+/// Validate a spawn request against the registry and the parent agent.
+fn check_spawn(
+    registry: &Registry,
+    stats: &GlobalStats,
+    parent: &AgentAccount,
+    name: &str,
+    specialization: &str,
+    revenue_share_bps: u16,
+) -> Result<()> {
+    check_limit("name length", name.len(), MAX_NAME_LEN, HydraError::NameTooLong)?;
+    check_limit("specialization length", specialization.len(), MAX_SPEC_LEN, HydraError::SpecTooLong)?;
+    check_limit("revenue share bps", revenue_share_bps, 10_000, HydraError::InvalidRevenueShare)?;
+    validate_name(name, registry.strict_names)?;
+    check_sibling_share(registry, parent, revenue_share_bps)?;
+    check_spawn_parent(registry, stats, parent, &Clock::get()?)
+}
+
+/// With the sibling cap enabled, adding a child of `revenue_share_bps` must
+/// keep the parent's `children_share_sum` within 10000.
+fn check_sibling_share(registry: &Registry, parent: &AgentAccount, revenue_share_bps: u16) -> Result<()> {
+    require!(
+        !registry.enforce_sibling_share_cap
+            || parent.children_share_sum.saturating_add(revenue_share_bps as u32) <= 10_000,
+        HydraError::SiblingShareExceeded
+    );
+    Ok(())
+}
+
+/// Depth of a child of an agent at `parent_depth`; the ceiling's last level
+/// has no room below it.
+fn child_depth(parent_depth: u8) -> Result<u8> {
+    Ok(parent_depth.checked_add(1).ok_or(HydraError::Overflow)?)
+}
+
+/// The parent-side spawn checks: active, below max depth, and (in balanced
+/// mode) its level filled.
+fn check_spawn_parent(
+    registry: &Registry,
+    stats: &GlobalStats,
+    parent: &AgentAccount,
+    clock: &Clock,
+) -> Result<()> {
+    require!(parent.can_spawn(clock), HydraError::ParentInactive);
+    check_limit(
+        "child depth",
+        parent.depth.saturating_add(1),
+        registry.max_depth,
+        HydraError::MaxDepthReached,
+    )?;
+    require!(
+        !registry.require_balanced || stats.level_filled(parent.depth, registry.branching_factor),
+        HydraError::TreeNotBalanced
+    );
+    Ok(())
+}
+
+/// Under `strict_lineage_checks`, reject a child wallet that belongs to any of
+/// `ancestors`: the parent's ancestors from its own parent upward, as many as
+/// the caller supplies. Each account must be the parent of the one before it.
+fn check_ancestor_wallets(
+    registry: &Registry,
+    parent: &AgentAccount,
+    child_wallet: &Pubkey,
+    ancestors: &[AccountInfo],
+) -> Result<()> {
+    if !registry.strict_lineage_checks {
+        return Ok(());
+    }
+    check_batch_nodes(registry, ancestors)?;
+    let mut next = parent.parent;
+    for info in ancestors {
+        require_keys_eq!(*info.key, next, HydraError::InvalidChain);
+        require_keys_eq!(*info.owner, crate::ID, HydraError::InvalidChain);
+        let ancestor = AgentAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_neq!(ancestor.wallet, *child_wallet, HydraError::AncestorWalletReuse);
+        next = ancestor.parent;
+    }
+    Ok(())
+}
+
+/// Reject a name held by an unexpired `NameReservation` unless its reserver is
+/// one of `claimants`. An uninitialized reservation PDA means the name is free.
+fn check_name_reservation(reservation: &AccountInfo, claimants: &[Pubkey], now: i64) -> Result<()> {
+    if *reservation.owner != crate::ID {
+        return Ok(());
+    }
+    let reservation = NameReservation::try_deserialize(&mut &reservation.try_borrow_data()?[..])?;
+    require!(
+        reservation.expires_at <= now || claimants.contains(&reservation.reserver),
+        HydraError::NameReserved
+    );
+    Ok(())
+}
+
+/// When the registry gates spawning on a license mint, require `license` to be
+/// an SPL Token (or Token-2022) account of that mint owned by `holder` with a
+/// nonzero balance.
+fn check_spawn_license(
+    registry: &Registry,
+    license: Option<&UncheckedAccount>,
+    holder: &Pubkey,
+) -> Result<()> {
+    if registry.spawn_license_mint == Pubkey::default() {
+        return Ok(());
+    }
+    let license = license.ok_or(HydraError::SpawnLicenseMissing)?;
+    require!(
+        *license.owner == TOKEN_PROGRAM_ID || *license.owner == TOKEN_2022_PROGRAM_ID,
+        HydraError::SpawnLicenseMissing
+    );
+
+    // SPL token account layout: mint (0..32), owner (32..64), amount (64..72)
+    let data = license.try_borrow_data()?;
+    require!(data.len() >= 72, HydraError::SpawnLicenseMissing);
+    let mint = Pubkey::try_from(&data[0..32]).unwrap();
+    let owner = Pubkey::try_from(&data[32..64]).unwrap();
+    let amount = u64::from_le_bytes(data[64..72].try_into().unwrap());
+    require!(
+        mint == registry.spawn_license_mint && owner == *holder && amount >= 1,
+        HydraError::SpawnLicenseMissing
+    );
+    Ok(())
+}
+
+/// A freshly created agent with every counter zeroed. Callers override the
+/// creation-time settings (shares, secondary beneficiary, index) as needed.
+fn new_agent(
+    wallet: Pubkey,
+    parent: Pubkey,
+    name: String,
+    specialization: String,
+    depth: u8,
+    creator: Pubkey,
+    bump: u8,
+) -> Result<AgentAccount> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(AgentAccount {
+        wallet,
+        parent,
+        name,
+        specialization,
+        total_earned: 0,
+        total_distributed_to_parent: 0,
+        children_count: 0,
+        depth,
+        revenue_share_bps: 0,
+        is_active: true,
+        created_at: now,
+        bump,
+        forward_share_bps: 0,
+        epoch_earned: 0,
+        current_epoch: 0,
+        deactivated_at: 0,
+        subtree_earned: 0,
+        unrolled_earnings: 0,
+        withdraw_whitelist: [Pubkey::default(); MAX_WHITELIST_LEN],
+        deactivated_by_authority: false,
+        last_active_at: now,
+        last_earning_nonce: 0,
+        total_received_from_children: 0,
+        schema_version: AGENT_SCHEMA_VERSION,
+        created_by: creator,
+        rent_payer: creator,
+        total_received_from_subtree: 0,
+        secondary_beneficiary: None,
+        secondary_share_bps: 0,
+        total_distributed_to_secondary: 0,
+        is_indexed: false,
+        agent_index: 0,
+        expires_at: 0,
+        earned_by_category: [0; EARNING_CATEGORIES],
+        earning_goal: 0,
+        goal_reached: false,
+        is_stale: false,
+        reactivated_at: 0,
+        locked_until: 0,
+        total_referral_earnings: 0,
+        children_share_sum: 0,
+        uri: String::new(),
+        content_hash: [0; 32],
+        attested_earned: 0,
+        adjustment_count: 0,
+        last_adjusted_at: 0,
+        tag: [0; 16],
+        is_immutable: false,
+        is_frozen: false,
+        last_distributed_at: 0,
+        distribution_count: 0,
+        milestones: 0,
+        streak_days: 0,
+        streak_last_day: 0,
+        max_single_distribution: 0,
+        max_daily_distribution: 0,
+        daily_distributed: 0,
+        daily_distribution_day: 0,
+        status: AgentStatus::Active,
+    })
+}
+
+/// Shared body of `record_earning` and `record_earning_by_oracle`: validate the
+/// earning and credit it to the agent, global stats, leaderboard and parent.
+#[allow(clippy::too_many_arguments)]
+fn credit_recorded_earning<'info>(
+    registry: &Registry,
+    stats: &mut GlobalStats,
+    agent: &mut Account<'info, AgentAccount>,
+    parent_agent: Option<&mut Account<'info, AgentAccount>>,
+    leaderboard: Option<&mut Account<'info, Leaderboard>>,
+    memo_program: Option<&UncheckedAccount<'info>>,
+    amount: u64,
+    nonce: u64,
+    category: Option<EarningCategory>,
+) -> Result<()> {
+    require_top_level(registry)?;
+    require!(amount > 0, HydraError::ZeroAmount);
+
+    require!(agent.can_earn(&Clock::get()?), HydraError::AgentInactive);
+    check_quarantine(
+        registry,
+        agent,
+        QUARANTINE_EARNINGS,
+        Clock::get()?.unix_timestamp,
+    )?;
+    end_elapsed_quarantine(registry, agent, Clock::get()?.unix_timestamp)?;
+    if registry.require_active_lineage && agent.parent != Pubkey::default() {
+        let parent = parent_agent
+            .as_deref()
+            .ok_or(HydraError::MissingParentAccount)?;
+        require!(parent.is_operational(&Clock::get()?), HydraError::AncestorInactive);
+    }
+
+    agent.accept_earning_nonce(nonce)?;
+
+    let category = category.unwrap_or(EarningCategory::Other);
+    let clock = Clock::get()?;
+    agent.credit_earning(amount, category, clock.epoch);
+    agent.touch(clock.unix_timestamp);
+    agent.extend_streak(clock.unix_timestamp);
+    stats.record_reported(amount, category, clock.epoch);
+
+    emit!(EarningRecorded {
+        agent: agent.key(),
+        amount,
+        total_earned: agent.total_earned,
+        epoch_earned: agent.epoch_earned,
+        nonce,
+        category,
+        bucket: AccountingBucket::Reported,
+        seq: stats.next_event_seq(),
+    });
+    check_earning_goal(agent);
+    award_milestones(agent);
+
+    if let Some(leaderboard) = leaderboard {
+        if let Some(evicted) = leaderboard.update(agent.key(), agent.total_earned) {
+            emit!(LeaderboardChanged {
+                entered: agent.key(),
+                evicted,
+                total_earned: agent.total_earned,
+            });
+        }
+    }
+
+    // Roll the earning into the parent's subtree total now if the parent was
+    // supplied, otherwise leave it pending for `roll_up_earnings`.
+    if agent.parent != Pubkey::default() {
+        match parent_agent {
+            Some(parent) => credit_subtree(agent.key(), parent, amount),
+            None => {
+                agent.unrolled_earnings = agent.unrolled_earnings.checked_add(amount).unwrap();
+            }
+        }
+    }
+
+    emit_memo(
+        registry,
+        memo_program,
+        format!("hydra:earn:{}:{}", agent.key(), amount),
+    )?;
+
+    Ok(())
+}
+
+/// Move `amount` from the child's wallet to its parent (and grandparent, for a
+/// forwarding parent). `remainder` is what the caller's split left with the
+/// child, reported on `RevenueDistributed`. `parent_override` skips the
+/// child's distribution caps.
+fn send_to_parent(
+    ctx: Context<DistributeToParent>,
+    amount: u64,
+    remainder: u64,
+    parent_override: bool,
+) -> Result<()> {
+    require_top_level(&ctx.accounts.registry)?;
+    require!(amount > 0, HydraError::ZeroAmount);
+    require_keys_neq!(
+        ctx.accounts.child_wallet.key(),
+        ctx.accounts.parent_wallet.key(),
+        HydraError::SelfDistribution
+    );
+
+    let child = &ctx.accounts.child_agent;
+    require!(child.can_distribute(&Clock::get()?), HydraError::ChildInactive);
+    require!(child.parent != Pubkey::default(), HydraError::NoParentAgent);
+    let now = Clock::get()?.unix_timestamp;
+    check_distribution_interval(&ctx.accounts.registry, child, now)?;
+    check_quarantine(
+        &ctx.accounts.registry,
+        child,
+        QUARANTINE_DISTRIBUTIONS,
+        Clock::get()?.unix_timestamp,
+    )?;
+    if !parent_override {
+        check_distribution_limits(child, amount, now)?;
+    }
+
+    let parent = &ctx.accounts.parent_agent;
+    if ctx.accounts.registry.require_active_lineage {
+        require!(parent.is_operational(&Clock::get()?), HydraError::AncestorInactive);
+    }
+    let (forward_amount, kept_by_parent) =
+        if parent.parent != Pubkey::default() && parent.forward_share_bps > 0 {
+            split_bps(amount, parent.forward_share_bps)
+        } else {
+            (0, amount)
+        };
+
+    if forward_amount > 0 {
+        let (Some(grandparent_agent), Some(grandparent_wallet)) = (
+            ctx.accounts.grandparent_agent.as_ref(),
+            ctx.accounts.grandparent_wallet.as_ref(),
+        ) else {
+            return err!(HydraError::MissingGrandparentAccounts);
+        };
+        require_keys_eq!(
+            grandparent_agent.key(),
+            parent.parent,
+            HydraError::InvalidGrandparent
+        );
+        require_keys_eq!(
+            grandparent_wallet.key(),
+            grandparent_agent.wallet,
+            HydraError::InvalidGrandparent
+        );
+        check_destination(&ctx.accounts.registry, child, &grandparent_wallet.key())?;
+
+        // Forward cut goes straight from child wallet to grandparent wallet
+        transfer_lamports(
+            &ctx.accounts.system_program,
+            ctx.accounts.child_wallet.to_account_info(),
+            grandparent_wallet.to_account_info(),
+            forward_amount,
+        )?;
+    }
+
+    // Remainder from child wallet to parent wallet
+    let parent_amount = amount.checked_sub(forward_amount).unwrap();
+    if parent_amount > 0 {
+        check_destination(&ctx.accounts.registry, child, &ctx.accounts.parent_wallet.key())?;
+        transfer_lamports(
+            &ctx.accounts.system_program,
+            ctx.accounts.child_wallet.to_account_info(),
+            ctx.accounts.parent_wallet.to_account_info(),
+            parent_amount,
+        )?;
+    }
+
+    ctx.accounts.stats.record_transfer(amount, Clock::get()?.epoch);
+
+    let child_mut = &mut ctx.accounts.child_agent;
+    child_mut.touch(now);
+    child_mut.extend_streak(now);
+    child_mut.last_distributed_at = now;
+    child_mut.record_daily_distribution(amount, now);
+    end_elapsed_quarantine(&ctx.accounts.registry, child_mut, now)?;
+    child_mut.distribution_count = child_mut.distribution_count.saturating_add(1);
+    award_milestones(child_mut);
+    child_mut.total_distributed_to_parent = child_mut
+        .total_distributed_to_parent
+        .checked_add(amount)
+        .unwrap();
+
+    if let Some(commitment) = ctx.accounts.commitment.as_mut() {
+        if amount >= commitment.min_amount {
+            commitment.last_satisfied_at = now;
+        }
+    }
+
+    let parent_mut = &mut ctx.accounts.parent_agent;
+    parent_mut.total_received_from_children = parent_mut
+        .total_received_from_children
+        .checked_add(amount)
+        .unwrap();
+
+    emit!(RevenueDistributed {
+        child: child_mut.key(),
+        parent: parent_mut.key(),
+        amount,
+        remainder,
+        total_distributed: child_mut.total_distributed_to_parent,
+        total_received_by_parent: parent_mut.total_received_from_children,
+        direct_to_root: false,
+        bucket: AccountingBucket::Transferred,
+        seq: ctx.accounts.stats.next_event_seq(),
+    });
+
+    if forward_amount > 0 {
+        parent_mut.total_distributed_to_parent = parent_mut
+            .total_distributed_to_parent
+            .checked_add(forward_amount)
+            .unwrap();
+
+        let grandparent_mut = ctx.accounts.grandparent_agent.as_mut().unwrap();
+        grandparent_mut.total_received_from_children = grandparent_mut
+            .total_received_from_children
+            .checked_add(forward_amount)
+            .unwrap();
+
+        emit!(RevenueDistributed {
+            child: parent_mut.key(),
+            parent: grandparent_mut.key(),
+            amount: forward_amount,
+            remainder: kept_by_parent,
+            total_distributed: parent_mut.total_distributed_to_parent,
+            total_received_by_parent: grandparent_mut.total_received_from_children,
+            direct_to_root: false,
+            bucket: AccountingBucket::Transferred,
+            seq: ctx.accounts.stats.next_event_seq(),
+        });
+    }
+
+    emit_memo(
+        &ctx.accounts.registry,
+        ctx.accounts.memo_program.as_ref(),
+        format!("hydra:dist:{}:{}:{}", child_mut.key(), parent_mut.key(), amount),
+    )?;
+
+    Ok(())
+}
+
+/// Post the registry's required bond for a newly created agent, if any.
+fn fund_bond<'info>(
+    registry: &Registry,
+    bond: Option<&mut Account<'info, Bond>>,
+    bump: Option<u8>,
+    agent: Pubkey,
+    payer: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let required = registry.required_bond_lamports;
+    if required == 0 {
+        return Ok(());
+    }
+    let (Some(bond), Some(bump)) = (bond, bump) else {
+        return err!(HydraError::MissingBondAccount);
+    };
+
+    transfer_lamports(system_program, payer, bond.to_account_info(), required)?;
+
+    bond.agent = agent;
+    bond.amount = required;
+    bond.bump = bump;
+
+    emit!(BondPosted {
+        agent,
+        amount: required,
+    });
+
+    Ok(())
+}
+
+/// Count a newly created agent under its specialization.
+fn increment_spec_counter(counter: &mut Account<SpecCounter>, specialization: &str, bump: u8) {
+    counter.spec_hash = hash(specialization.as_bytes()).to_bytes();
+    counter.count = counter.count.checked_add(1).unwrap();
+    counter.bump = bump;
+
+    emit!(SpecCountChanged {
+        spec_hash: counter.spec_hash,
+        count: counter.count,
+    });
+}
+
+/// Stop counting an agent that is leaving its specialization.
+fn decrement_spec_counter(counter: &mut Account<SpecCounter>) {
+    counter.count = counter.count.saturating_sub(1);
+
+    emit!(SpecCountChanged {
+        spec_hash: counter.spec_hash,
+        count: counter.count,
+    });
+}
+
+/// Close a program-owned account, sending its lamports to `destination`.
+fn close_account<'info>(info: AccountInfo<'info>, destination: AccountInfo<'info>) -> Result<()> {
+    let dest_starting_lamports = destination.lamports();
+    **destination.lamports.borrow_mut() = dest_starting_lamports.checked_add(info.lamports()).unwrap();
+    **info.lamports.borrow_mut() = 0;
+
+    info.assign(&system_program::ID);
+    info.resize(0).map_err(Into::into)
+}
+
+/// Close `agent`'s bond PDA to its wallet if a bond was posted. Used when the
+/// agent itself is closed, after which `withdraw_bond` could no longer reach it.
+fn refund_bond<'info>(
+    agent: &Account<AgentAccount>,
+    bond_info: AccountInfo<'info>,
+    wallet_info: AccountInfo<'info>,
+) -> Result<()> {
+    if bond_info.owner != &crate::ID {
+        return Ok(());
+    }
+    let bond = Bond::try_deserialize(&mut &bond_info.try_borrow_data()?[..])?;
+    emit!(BondWithdrawn {
+        agent: agent.key(),
+        wallet: agent.wallet,
+        amount: bond.amount,
+    });
+    close_account(bond_info, wallet_info)
+}
+
+/// System transfer from a signing wallet to any lamport-receiving account.
+fn transfer_lamports<'info>(
+    system_program: &Program<'info, System>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(system_program.to_account_info(), system_program::Transfer { from, to }),
+        amount,
+    )
+}
+
+// ============================================================================
+// Accounts
+// ============================================================================
+
+/// Registry configuration supplied to `initialize`. Defaults disable every
+/// optional feature and select `MAX_DEPTH`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InitializeParams {
+    pub max_depth: u8,
+    pub strict_names: bool,
+    pub prune_grace_secs: i64,
+    pub enforce_withdraw_whitelist: bool,
+    pub required_bond_lamports: u64,
+    pub bond_cooldown_secs: i64,
+    pub stale_threshold_secs: i64,
+    pub require_active_lineage: bool,
+    pub max_single_earning: u64,
+    pub compact_events: bool,
+    pub require_balanced: bool,
+    pub branching_factor: u16,
+    pub proposal_ttl_secs: i64,
+    pub allow_cpi: bool,
+    pub reparent_requires_child: bool,
+    pub min_active_secs: i64,
+    pub reporter: Pubkey,
+    pub reactivation_quarantine_secs: i64,
+    pub quarantine_gates: u8,
+    pub max_batch_nodes: u16,
+    pub emit_memos: bool,
+    pub decimals: u8,
+    pub currency_symbol: [u8; 8],
+    pub referral_bps: u16,
+    pub referral_duration_secs: i64,
+    pub spawn_license_mint: Pubkey,
+    pub enforce_sibling_share_cap: bool,
+    pub min_snapshot_interval_secs: i64,
+    pub adjustment_cooldown_secs: i64,
+    /// At most `MAX_NAMESPACE_LEN` bytes; fixed for the registry's lifetime
+    pub namespace: String,
+    pub oracle_only_earnings: bool,
+    pub strict_lineage_checks: bool,
+    pub min_distribution_interval_secs: i64,
+}
+
+/// Arguments to `register_root_agent_v2`.
+///
+/// `name` and `specialization` must stay the leading fields: `RegisterRootAgent`
+/// reads them through `#[instruction]` to derive the spec counter seed, and a
+/// struct's Borsh encoding starts with its fields in order.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RegisterRootAgentArgs {
+    pub name: String,
+    pub specialization: String,
+    pub uri: String,
+    pub content_hash: [u8; 32],
+}
+
+/// Return data of `spawn_child` and `spawn_child_v2`, so clients need not
+/// re-derive the child PDA or fetch it to learn its depth.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpawnResult {
+    pub child: Pubkey,
+    pub bump: u8,
+    pub depth: u8,
+}
+
+/// Arguments to `spawn_child_v2`. As with `RegisterRootAgentArgs`, `name` and
+/// `specialization` must stay the leading fields.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SpawnChildArgs {
+    pub name: String,
+    pub specialization: String,
+    pub revenue_share_bps: u16,
+    pub secondary_beneficiary: Option<Pubkey>,
+    pub secondary_share_bps: u16,
+    pub expires_at: i64,
+    pub earning_goal: u64,
+    pub uri: String,
+    pub content_hash: [u8; 32],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Registry {
+    pub authority: Pubkey,
+    /// Legacy; superseded by `GlobalStats` (see `split_stats`)
+    pub total_agents: u64,
+    /// Legacy; superseded by `GlobalStats` (see `split_stats`)
+    pub total_reported_earnings: u64,
+    /// Legacy; superseded by `GlobalStats` (see `split_stats`)
+    pub total_spawns: u64,
+    pub bump: u8,
+    /// Restrict agent names to URL-safe characters
+    pub strict_names: bool,
+    /// Max agent tree depth, at most `MAX_DEPTH_CEILING`
+    pub max_depth: u8,
+    /// Seconds a deactivated agent must wait before `prune_inactive` may close it
+    pub prune_grace_secs: i64,
+    /// Enforce per-agent withdrawal whitelists on outgoing transfers
+    pub enforce_withdraw_whitelist: bool,
+    /// SOL bond new agents must post (0 = no bond)
+    pub required_bond_lamports: u64,
+    /// Seconds after voluntary deactivation before a bond can be withdrawn
+    pub bond_cooldown_secs: i64,
+    /// Seconds without activity after which an agent may be marked stale
+    pub stale_threshold_secs: i64,
+    /// Block earnings/distributions by non-root agents whose parent is inactive
+    pub require_active_lineage: bool,
+    /// Largest amount a single earning may report (0 = unlimited)
+    pub max_single_earning: u64,
+    /// Emit compact creation events without name/specialization strings
+    pub compact_events: bool,
+    /// Reject spawns that would grow a depth level before the one above it is filled
+    pub require_balanced: bool,
+    /// Children per agent a level must average before the next level may grow
+    pub branching_factor: u16,
+    /// Legacy; superseded by `GlobalStats` (see `split_stats`)
+    pub depth_counts: [u32; LEGACY_DEPTH_LEVELS],
+    /// Legacy; superseded by `GlobalStats` (see `split_stats`)
+    pub total_transferred_volume: u64,
+    /// Proposed next authority awaiting `accept_authority`
+    pub pending_authority: Option<Pubkey>,
+    /// When the pending authority was proposed
+    pub authority_proposal_ts: i64,
+    /// Seconds a proposal stays acceptable (0 = no expiry)
+    pub proposal_ttl_secs: i64,
+    /// Allow protected instructions to be invoked via CPI
+    pub allow_cpi: bool,
+    /// `reparent_agent` also requires the child wallet's signature
+    pub reparent_requires_child: bool,
+    /// Seconds after creation before an agent's wallet may deactivate it
+    pub min_active_secs: i64,
+    /// Legacy; superseded by `GlobalStats` (see `split_stats`)
+    pub earned_by_category: [u64; EARNING_CATEGORIES],
+    /// Oracle allowed to call `batch_record_earnings` (default = none)
+    pub reporter: Pubkey,
+    /// Seconds after `reactivate_agent` during which gated actions are blocked
+    pub reactivation_quarantine_secs: i64,
+    /// `QUARANTINE_*` bits selecting which actions the quarantine blocks
+    pub quarantine_gates: u8,
+    /// Max `remaining_accounts` per traversal instruction (0 = no registry cap)
+    pub max_batch_nodes: u16,
+    /// CPI the SPL Memo program on `record_earning` and `distribute_to_parent`
+    pub emit_memos: bool,
+    /// Decimals of the unit amounts are tracked in (display only)
+    pub decimals: u8,
+    /// Currency symbol, ASCII zero-padded (display only)
+    pub currency_symbol: [u8; 8],
+    /// Share of `pay_agent_with_split` payments paid to the payer's referring agent
+    pub referral_bps: u16,
+    /// Seconds a referral stays active after registration (0 = forever)
+    pub referral_duration_secs: i64,
+    /// Mint whose token a parent wallet must hold to spawn (default = no gate)
+    pub spawn_license_mint: Pubkey,
+    /// Reject spawns and reparents that push a parent's `children_share_sum` past 10000
+    pub enforce_sibling_share_cap: bool,
+    /// Minimum seconds between `take_snapshot` calls (0 = no limit)
+    pub min_snapshot_interval_secs: i64,
+    /// Minimum seconds between `adjust_earning` calls on one agent (0 = no limit)
+    pub adjustment_cooldown_secs: i64,
+    /// Seed suffix separating independent trees under one deployment (empty = default tree)
+    #[max_len(MAX_NAMESPACE_LEN)]
+    pub namespace: String,
+    /// Only approved oracles may record earnings (see `record_earning_by_oracle`)
+    pub oracle_only_earnings: bool,
+    /// `spawn_child` rejects a child wallet reused from any ancestor supplied in
+    /// `remaining_accounts`
+    pub strict_lineage_checks: bool,
+    /// Minimum seconds between `distribute_to_parent` calls by one agent (0 = no limit)
+    pub min_distribution_interval_secs: i64,
+}
+
+impl Registry {
+    pub const SEED: &'static [u8] = b"registry";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Program-wide counters, kept apart from `Registry` so that earning, spawning
+/// and distribution instructions only read the config account.
+#[account]
+#[derive(InitSpace)]
+pub struct GlobalStats {
+    pub total_agents: u64,
+    /// Sum of self-reported `record_earning` amounts
+    pub total_reported_earnings: u64,
+    pub total_spawns: u64,
+    /// Lamports actually moved by distribution and bounty instructions
+    pub total_transferred_volume: u64,
+    /// Reported earnings per `EarningCategory`
+    pub earned_by_category: [u64; EARNING_CATEGORIES],
+    /// Live agents at each depth, maintained on creation and pruning
+    pub depth_counts: [u32; DEPTH_LEVELS],
+    pub bump: u8,
+    /// Sum of parent-attested child earnings (see `attest_child_earning`)
+    pub total_attested_earnings: u64,
+    /// Activity for the most recent epochs, newest first; `recent_epochs[0]` is
+    /// the latest epoch anything was recorded in
+    pub recent_epochs: [EpochBucket; EPOCH_BUCKETS],
+    /// Program-wide sequence number, advanced by every instruction that changes
+    /// state (other than the `migrate_*` layout upgrades, which can run before
+    /// this account exists). Events that carry a `seq` take the value at their
+    /// emission, so a gap between two of them means other changes happened in
+    /// between.
+    pub event_seq: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct EpochBucket {
+    pub epoch: u64,
+    pub earnings: u64,
+    pub spawns: u64,
+    pub distributed: u64,
+}
+
+
+impl GlobalStats {
+    pub const SEED: &'static [u8] = b"stats";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    /// Advance and return `event_seq`, for the `seq` field of ordered events.
+    fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.checked_add(1).unwrap();
+        self.event_seq
+    }
+
+    /// Fill rule for balanced trees: a parent at `parent_depth` may spawn (adding
+    /// an agent at `parent_depth + 1`) only once its own level is filled, i.e.
+    /// `depth_counts[parent_depth] >= depth_counts[parent_depth - 1] * branching_factor`.
+    /// Children of root agents are always allowed, since the root level is unbounded.
+    pub fn level_filled(&self, parent_depth: u8, branching_factor: u16) -> bool {
+        if parent_depth == 0 {
+            return true;
+        }
+        let level = self.depth_counts[parent_depth as usize] as u64;
+        let above = self.depth_counts[parent_depth as usize - 1] as u64;
+        level >= above.saturating_mul(branching_factor as u64)
+    }
+
+    /// Bucket for `epoch`, rotating older buckets down first if `epoch` is newer
+    /// than the latest one. Buckets pushed past the end are dropped, and a gap
+    /// of `EPOCH_BUCKETS` or more epochs clears the window entirely.
+    fn epoch_bucket(&mut self, epoch: u64) -> &mut EpochBucket {
+        let latest = self.recent_epochs[0].epoch;
+        if epoch > latest {
+            let shift = (epoch - latest).min(EPOCH_BUCKETS as u64) as usize;
+            self.recent_epochs.rotate_right(shift);
+            self.recent_epochs[..shift].fill(EpochBucket::default());
+            self.recent_epochs[0].epoch = epoch;
+        }
+        &mut self.recent_epochs[0]
+    }
+
+    fn record_reported(&mut self, amount: u64, category: EarningCategory, epoch: u64) {
+        self.total_reported_earnings = self.total_reported_earnings.checked_add(amount).unwrap();
+        let slot = &mut self.earned_by_category[category as usize];
+        *slot = slot.checked_add(amount).unwrap();
+        let bucket = self.epoch_bucket(epoch);
+        bucket.earnings = bucket.earnings.saturating_add(amount);
+    }
+
+    /// Undo `record_reported` for a downward `adjust_earning`. The epoch bucket
+    /// is only touched while `epoch` is still in the window.
+    fn unrecord_reported(&mut self, amount: u64, category: EarningCategory, epoch: u64) {
+        self.total_reported_earnings = self.total_reported_earnings.saturating_sub(amount);
+        let slot = &mut self.earned_by_category[category as usize];
+        *slot = slot.saturating_sub(amount);
+        if let Some(bucket) = self.recent_epochs.iter_mut().find(|b| b.epoch == epoch) {
+            bucket.earnings = bucket.earnings.saturating_sub(amount);
+        }
+    }
+
+    fn record_transfer(&mut self, amount: u64, epoch: u64) {
+        self.total_transferred_volume = self.total_transferred_volume.checked_add(amount).unwrap();
+        let bucket = self.epoch_bucket(epoch);
+        bucket.distributed = bucket.distributed.saturating_add(amount);
+    }
+
+    fn record_spawn(&mut self, epoch: u64) {
+        self.total_spawns = self.total_spawns.checked_add(1).unwrap();
+        let bucket = self.epoch_bucket(epoch);
+        bucket.spawns = bucket.spawns.saturating_add(1);
+    }
+
+    fn record_agent_at_depth(&mut self, depth: u8) {
+        let count = &mut self.depth_counts[depth as usize];
+        *count = count.checked_add(1).unwrap();
+    }
+
+    fn remove_agent_at_depth(&mut self, depth: u8) {
+        let count = &mut self.depth_counts[depth as usize];
+        *count = count.saturating_sub(1);
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AgentAccount {
+    pub wallet: Pubkey,
+    pub parent: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_SPEC_LEN)]
+    pub specialization: String,
+    pub total_earned: u64,
+    pub total_distributed_to_parent: u64,
+    pub children_count: u64,
+    pub depth: u8,
+    pub revenue_share_bps: u16,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+    /// Share of revenue received from children that is forwarded to this agent's parent
+    pub forward_share_bps: u16,
+    /// Earnings recorded during `current_epoch`
+    pub epoch_earned: u64,
+    /// Epoch of the most recent earning
+    pub current_epoch: u64,
+    /// Unix timestamp of deactivation (0 while active)
+    pub deactivated_at: i64,
+    /// Gross earnings of all descendants rolled up so far
+    pub subtree_earned: u64,
+    /// Subtree earnings not yet rolled up to the parent
+    pub unrolled_earnings: u64,
+    /// Approved withdrawal destinations; all-default means unrestricted
+    pub withdraw_whitelist: [Pubkey; MAX_WHITELIST_LEN],
+    /// Whether the registry authority (rather than the agent) deactivated this agent
+    pub deactivated_by_authority: bool,
+    /// Unix timestamp of the last heartbeat, earning or distribution (creation time until then)
+    pub last_active_at: i64,
+    /// Highest nonzero nonce accepted by `record_earning`
+    pub last_earning_nonce: u64,
+    /// Cumulative revenue received from children via `distribute_to_parent`
+    pub total_received_from_children: u64,
+    /// Layout version, see `AGENT_SCHEMA_VERSION` and `migrate_agent`
+    pub schema_version: u8,
+    /// Signer that initiated this agent's creation (default for pre-v2 accounts)
+    pub created_by: Pubkey,
+    /// Account that paid creation rent and receives it back on close
+    pub rent_payer: Pubkey,
+    /// Revenue received directly from descendants via `distribute_to_root` (roots only)
+    pub total_received_from_subtree: u64,
+    /// Co-incubating agent owed a share alongside the parent, fixed at spawn
+    pub secondary_beneficiary: Option<Pubkey>,
+    /// Share owed to the secondary beneficiary (basis points)
+    pub secondary_share_bps: u16,
+    /// Total revenue sent to the secondary beneficiary
+    pub total_distributed_to_secondary: u64,
+    /// Whether the PDA is `["agent", wallet, agent_index_le]` rather than `["agent", wallet]`
+    pub is_indexed: bool,
+    /// Per-wallet index for indexed agents
+    pub agent_index: u32,
+    /// Timestamp from which the agent is treated as inactive (0 = never)
+    pub expires_at: i64,
+    /// Earnings per `EarningCategory`
+    pub earned_by_category: [u64; EARNING_CATEGORIES],
+    /// `total_earned` at which `GoalReached` fires (0 = no goal)
+    pub earning_goal: u64,
+    /// Whether `GoalReached` has fired for the current goal
+    pub goal_reached: bool,
+    /// Set by `mark_stale`, cleared by the agent's next activity
+    pub is_stale: bool,
+    /// Last `reactivate_agent` timestamp (0 = never reactivated)
+    pub reactivated_at: i64,
+    /// Deactivation, bond withdrawal and reparenting are blocked until this timestamp (0 = unlocked)
+    pub locked_until: i64,
+    /// Lamports received as a referring agent
+    pub total_referral_earnings: u64,
+    /// Sum of the current children's `revenue_share_bps`
+    pub children_share_sum: u32,
+    /// Off-chain profile JSON (empty = none)
+    #[max_len(MAX_URI_LEN)]
+    pub uri: String,
+    /// Hash of the document at `uri`
+    pub content_hash: [u8; 32],
+    /// Portion of `total_earned` vouched for by the parent
+    pub attested_earned: u64,
+    /// Authority corrections applied via `adjust_earning`
+    pub adjustment_count: u32,
+    /// Last `adjust_earning` timestamp (0 = never adjusted)
+    pub last_adjusted_at: i64,
+    /// Mutable off-chain categorization label (zeros = untagged)
+    pub tag: [u8; 16],
+    /// Set once by `finalize_agent`; freezes the agent's configuration
+    pub is_immutable: bool,
+    /// Set once by `freeze_agent`; the account is read-only from then on
+    pub is_frozen: bool,
+    /// Last `distribute_to_parent` timestamp (0 = never distributed)
+    pub last_distributed_at: i64,
+    /// Successful `distribute_to_parent` calls
+    pub distribution_count: u32,
+    /// Unlocked `MILESTONE_*` bits; never cleared
+    pub milestones: u32,
+    /// Consecutive UTC days with an earning or distribution, ending at `streak_last_day`
+    pub streak_days: u16,
+    /// UTC day (days since the Unix epoch) of the latest streak activity
+    pub streak_last_day: i64,
+    /// Cap on a single outflow from the agent's wallet, set by that wallet (0 = unlimited)
+    pub max_single_distribution: u64,
+    /// Cap on outflows from the agent's wallet per UTC day (0 = unlimited)
+    pub max_daily_distribution: u64,
+    /// Sent out during `daily_distribution_day`
+    pub daily_distributed: u64,
+    /// UTC day that `daily_distributed` covers
+    pub daily_distribution_day: i64,
+    /// Lifecycle state, changed only through `transition_status`. `is_active`
+    /// and `is_frozen` are kept in lockstep for existing readers.
+    pub status: AgentStatus,
+}
+
+impl AgentAccount {
+    pub const SEED: &'static [u8] = b"agent";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    /// Earnings plus revenue received from descendants, less distributions to
+    /// the parent and secondary beneficiary.
+    /// Negative when the agent distributed more than it took in (e.g. distributions
+    /// funded from outside earnings).
+    pub fn net_position(&self) -> i128 {
+        (self.total_earned as i128) + (self.total_received_from_children as i128)
+            + (self.total_received_from_subtree as i128)
+            - (self.total_distributed_to_parent as i128)
+            - (self.total_distributed_to_secondary as i128)
+    }
+
+    /// Add a reported earning to the lifetime, per-category and per-epoch totals.
+    fn credit_earning(&mut self, amount: u64, category: EarningCategory, epoch: u64) {
+        self.total_earned = self.total_earned.checked_add(amount).unwrap();
+        let slot = &mut self.earned_by_category[category as usize];
+        *slot = slot.checked_add(amount).unwrap();
+
+        if epoch > self.current_epoch {
+            self.current_epoch = epoch;
+            self.epoch_earned = amount;
+        } else {
+            self.epoch_earned = self.epoch_earned.checked_add(amount).unwrap();
+        }
+    }
+
+    /// Take back `amount` of `category` earnings, the reverse of `credit_earning`.
+    /// Fails without changing anything if the total or the category holds less.
+    fn debit_earning(&mut self, amount: u64, category: EarningCategory, epoch: u64) -> Result<()> {
+        let slot = self.earned_by_category[category as usize];
+        require!(
+            self.total_earned >= amount && slot >= amount,
+            HydraError::AdjustmentUnderflow
+        );
+        self.total_earned -= amount;
+        self.earned_by_category[category as usize] = slot - amount;
+        if epoch == self.current_epoch {
+            self.epoch_earned = self.epoch_earned.saturating_sub(amount);
+        }
+        self.attested_earned = self.attested_earned.min(self.total_earned);
+        Ok(())
+    }
+
+    /// Fold a merged agent's earnings and distribution totals into this one.
+    /// Epoch earnings combine when both are in the same epoch; otherwise the
+    /// later epoch's figure wins.
+    fn absorb(&mut self, source: &AgentAccount) {
+        self.total_earned = self.total_earned.checked_add(source.total_earned).unwrap();
+        for (slot, earned) in self.earned_by_category.iter_mut().zip(source.earned_by_category) {
+            *slot = slot.checked_add(earned).unwrap();
+        }
+        self.attested_earned = self.attested_earned.checked_add(source.attested_earned).unwrap();
+        self.subtree_earned = self.subtree_earned.checked_add(source.subtree_earned).unwrap();
+        self.unrolled_earnings = self
+            .unrolled_earnings
+            .checked_add(source.unrolled_earnings)
+            .unwrap();
+        self.total_distributed_to_parent = self
+            .total_distributed_to_parent
+            .checked_add(source.total_distributed_to_parent)
+            .unwrap();
+        self.total_distributed_to_secondary = self
+            .total_distributed_to_secondary
+            .checked_add(source.total_distributed_to_secondary)
+            .unwrap();
+
+        if source.current_epoch > self.current_epoch {
+            self.current_epoch = source.current_epoch;
+            self.epoch_earned = source.epoch_earned;
+        } else if source.current_epoch == self.current_epoch {
+            self.epoch_earned = self.epoch_earned.checked_add(source.epoch_earned).unwrap();
+        }
+    }
+
+    /// Accept a `record_earning` nonce: a nonzero one must exceed the last and
+    /// becomes the new last; zero skips the check.
+    fn accept_earning_nonce(&mut self, nonce: u64) -> Result<()> {
+        if nonce != 0 {
+            require!(nonce > self.last_earning_nonce, HydraError::DuplicateNonce);
+            self.last_earning_nonce = nonce;
+        }
+        Ok(())
+    }
+
+    /// Record activity at `now`, clearing the stale flag.
+    fn touch(&mut self, now: i64) {
+        self.last_active_at = now;
+        self.is_stale = false;
+    }
+
+    /// Count `now`'s UTC day toward the activity streak: the same day is a
+    /// no-op, the next day extends the streak, and any later day restarts it.
+    fn extend_streak(&mut self, now: i64) {
+        let day = utc_day(now);
+        if day == self.streak_last_day && self.streak_days > 0 {
+            return;
+        }
+        self.streak_days = if day == self.streak_last_day.saturating_add(1) {
+            self.streak_days.saturating_add(1)
+        } else {
+            1
+        };
+        self.streak_last_day = day;
+    }
+
+    /// Amount already sent out on the UTC day of `now`.
+    pub fn distributed_on_day(&self, now: i64) -> u64 {
+        if self.daily_distribution_day == utc_day(now) {
+            self.daily_distributed
+        } else {
+            0
+        }
+    }
+
+    /// Add a distribution to the current UTC day's total, starting a new day's
+    /// window when `now` has moved past the tracked one.
+    fn record_daily_distribution(&mut self, amount: u64, now: i64) {
+        self.daily_distributed = self.distributed_on_day(now).saturating_add(amount);
+        self.daily_distribution_day = utc_day(now);
+    }
+
+    pub fn has_milestone(&self, milestone: u8) -> bool {
+        self.milestones & (1 << milestone) != 0
+    }
+
+    /// Live status and not past its `expires_at`. Liveness checks not tied to
+    /// earning, spawning or distributing go through here.
+    pub fn is_operational(&self, clock: &Clock) -> bool {
+        self.status.is_live() && self.within_ttl(clock)
+    }
+
+    /// Whether the agent may record earnings now.
+    pub fn can_earn(&self, clock: &Clock) -> bool {
+        self.status.allows_earning() && self.within_ttl(clock)
+    }
+
+    /// Whether the agent may spawn children now.
+    pub fn can_spawn(&self, clock: &Clock) -> bool {
+        self.status.allows_spawning() && self.within_ttl(clock)
+    }
+
+    /// Whether the agent may distribute revenue now.
+    pub fn can_distribute(&self, clock: &Clock) -> bool {
+        self.status.allows_distribution() && self.within_ttl(clock)
+    }
+
+    fn within_ttl(&self, clock: &Clock) -> bool {
+        self.expires_at == 0 || clock.unix_timestamp < self.expires_at
+    }
+
+    /// Move to `to` if `transition_status` allows it, updating the
+    /// `is_active` and `is_frozen` compatibility fields with it.
+    fn set_status(&mut self, to: AgentStatus) -> Result<()> {
+        self.status = transition_status(self.status, to)?;
+        self.is_active = self.status.is_live();
+        self.is_frozen = self.status == AgentStatus::Frozen;
+        Ok(())
+    }
+
+    /// Extra PDA seed for agents created via `register_agent_indexed`; empty for
+    /// legacy `["agent", wallet]` agents (an empty seed doesn't change the address).
+    /// The namespace's part of an agent PDA's seeds: empty for the default
+    /// namespace, otherwise its SHA-256. The namespace precedes the wallet and
+    /// the optional index, so a variable-length seed there would let two
+    /// different (namespace, wallet, index) triples concatenate to one address.
+    pub fn namespace_seed(namespace: &str) -> Vec<u8> {
+        if namespace.is_empty() {
+            Vec::new()
+        } else {
+            hash(namespace.as_bytes()).to_bytes().to_vec()
+        }
+    }
+
+    pub fn index_seed(&self) -> Vec<u8> {
+        if self.is_indexed {
+            self.agent_index.to_le_bytes().to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Where rent goes when this agent is closed: the recorded rent payer, or
+    /// the agent's wallet for accounts created before provenance was tracked.
+    pub fn rent_refund_destination(&self) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            self.wallet
+        } else {
+            self.rent_payer
+        }
+    }
+
+    /// Whether funds may be sent to `destination` under this agent's whitelist.
+    pub fn allows_destination(&self, destination: &Pubkey) -> bool {
+        self.withdraw_whitelist.iter().all(|k| *k == Pubkey::default())
+            || (*destination != Pubkey::default() && self.withdraw_whitelist.contains(destination))
+    }
+}
+
+/// Custom revenue-split recipients for an agent.
+#[account]
+#[derive(InitSpace)]
+pub struct SplitConfig {
+    pub agent: Pubkey,
+    #[max_len(MAX_SPLIT_RECIPIENTS)]
+    pub recipients: Vec<SplitRecipient>,
+    pub bump: u8,
+}
+
+impl SplitConfig {
+    pub const SEED: &'static [u8] = b"split";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct SplitRecipient {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+
+/// SOL bond posted for an agent; lamports above rent are the bond.
+#[account]
+#[derive(InitSpace)]
+pub struct Bond {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Bond {
+    pub const SEED: &'static [u8] = b"bond";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Number of agents registered under one specialization.
+#[account]
+#[derive(InitSpace)]
+pub struct SpecCounter {
+    /// SHA-256 of the specialization string
+    pub spec_hash: [u8; 32],
+    pub count: u64,
+    pub bump: u8,
+}
+
+impl SpecCounter {
+    pub const SEED: &'static [u8] = b"spec";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Next free agent index for a wallet operating indexed agents.
+#[account]
+#[derive(InitSpace)]
+pub struct WalletAgentCounter {
+    pub wallet: Pubkey,
+    pub next_index: u32,
+    pub bump: u8,
+}
+
+impl WalletAgentCounter {
+    pub const SEED: &'static [u8] = b"agent_counter";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Child parameters a parent can stamp out repeatedly with `spawn_from_template`.
+#[account]
+#[derive(InitSpace)]
+pub struct SpawnTemplate {
+    pub parent: Pubkey,
+    pub template_id: u32,
+    /// Prepended to each spawn's `name_suffix`
+    #[max_len(MAX_NAME_LEN)]
+    pub name_prefix: String,
+    #[max_len(MAX_SPEC_LEN)]
+    pub specialization: String,
+    pub revenue_share_bps: u16,
+    pub remaining_uses: u32,
+    pub bump: u8,
+}
+
+impl SpawnTemplate {
+    pub const SEED: &'static [u8] = b"template";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    /// Name of a child spawned from this template: `name_prefix + name_suffix`.
+    pub fn child_name(&self, name_suffix: &str) -> String {
+        format!("{}{}", self.name_prefix, name_suffix)
+    }
+}
+
+/// Marks `oracle` as approved to record earnings on agents' behalf. Exists only
+/// while approved; `remove_oracle` closes it.
+#[account]
+#[derive(InitSpace)]
+pub struct OracleRecord {
+    pub oracle: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl OracleRecord {
+    pub const SEED: &'static [u8] = b"oracle";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Holds an agent name for `reserver` until `expires_at`.
+#[account]
+#[derive(InitSpace)]
+pub struct NameReservation {
+    pub reserver: Pubkey,
+    /// SHA-256 of the reserved name
+    pub name_hash: [u8; 32],
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl NameReservation {
+    pub const SEED: &'static [u8] = b"reservation";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Top earners by `total_earned`, sorted descending; empty slots are default entries.
+#[account]
+#[derive(InitSpace)]
+pub struct Leaderboard {
+    pub entries: [LeaderboardEntry; LEADERBOARD_LEN],
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const SEED: &'static [u8] = b"leaderboard";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    /// Update `agent`'s total, keeping entries sorted. Returns the displaced
+    /// agent when `agent` newly enters the board (default key for an empty slot).
+    pub fn update(&mut self, agent: Pubkey, total_earned: u64) -> Option<Pubkey> {
+        let evicted = match self.entries.iter().position(|e| e.agent == agent) {
+            Some(i) => {
+                self.entries[i].total_earned = total_earned;
+                None
+            }
+            None => {
+                let last = &mut self.entries[LEADERBOARD_LEN - 1];
+                if total_earned <= last.total_earned {
+                    return None;
+                }
+                let evicted = last.agent;
+                *last = LeaderboardEntry { agent, total_earned };
+                Some(evicted)
+            }
+        };
+        self.entries.sort_by_key(|e| std::cmp::Reverse(e.total_earned));
+        evicted
+    }
+
+    /// Drop `agent`'s entry, if any, freeing the last slot.
+    pub fn remove(&mut self, agent: Pubkey) {
+        if let Some(i) = self.entries.iter().position(|e| e.agent == agent) {
+            self.entries[i] = LeaderboardEntry::default();
+            self.entries.sort_by_key(|e| std::cmp::Reverse(e.total_earned));
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct LeaderboardEntry {
+    pub agent: Pubkey,
+    pub total_earned: u64,
+}
+
+
+/// The agent that introduced an external payer.
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    pub payer: Pubkey,
+    pub referring_agent: Pubkey,
+    pub created_at: i64,
+    /// Timestamp from which the referral pays nothing (0 = never)
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const SEED: &'static [u8] = b"referral";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+}
+
+/// Ring buffer of recent `take_snapshot` hashes.
+#[account]
+#[derive(InitSpace)]
+pub struct SnapshotLog {
+    pub entries: [SnapshotEntry; SNAPSHOT_LOG_LEN],
+    /// Snapshots taken so far; the next one goes to `entries[count % SNAPSHOT_LOG_LEN]`
+    pub count: u64,
+    pub last_taken_at: i64,
+    pub bump: u8,
+}
+
+impl SnapshotLog {
+    pub const SEED: &'static [u8] = b"snapshots";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    /// Append a snapshot, overwriting the oldest once the buffer is full.
+    pub fn push(&mut self, hash: [u8; 32], slot: u64, now: i64) {
+        let i = (self.count % SNAPSHOT_LOG_LEN as u64) as usize;
+        self.entries[i] = SnapshotEntry { hash, slot };
+        self.count = self.count.checked_add(1).unwrap();
+        self.last_taken_at = now;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct SnapshotEntry {
+    pub hash: [u8; 32],
+    pub slot: u64,
+}
+
+/// Ring buffer of an agent's recent `record_earnings_bulk` task hashes.
+#[account]
+#[derive(InitSpace)]
+pub struct TaskLog {
+    pub agent: Pubkey,
+    pub hashes: [[u8; 32]; TASK_LOG_LEN],
+    /// Hashes recorded so far; the next one goes to `hashes[count % TASK_LOG_LEN]`
+    pub count: u64,
+    pub bump: u8,
+}
+
+impl TaskLog {
+    pub const SEED: &'static [u8] = b"tasks";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+
+    /// Whether `hash` is among the retained hashes.
+    pub fn contains(&self, hash: &[u8; 32]) -> bool {
+        let retained = self.count.min(TASK_LOG_LEN as u64) as usize;
+        self.hashes[..retained].contains(hash)
+    }
+
+    /// Append a hash, overwriting the oldest once the buffer is full.
+    pub fn push(&mut self, hash: [u8; 32]) {
+        let i = (self.count % TASK_LOG_LEN as u64) as usize;
+        self.hashes[i] = hash;
+        self.count = self.count.checked_add(1).unwrap();
+    }
+}
+
+
+/// Per-mint earnings for one agent, kept apart from SOL `total_earned`.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenEarnings {
+    pub agent: Pubkey,
+    pub mint: Pubkey,
+    pub total_earned: u64,
+    pub total_distributed: u64,
+    pub bump: u8,
+}
+
+impl TokenEarnings {
+    pub const SEED: &'static [u8] = b"token_earnings";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Lamports escrowed by one agent for work by another; lamports above rent are the escrow.
+#[account]
+#[derive(InitSpace)]
+pub struct Bounty {
+    pub poster_agent: Pubkey,
+    pub bounty_id: u64,
+    pub amount: u64,
+    /// Only this agent may claim; `None` means open to any agent
+    pub assignee: Option<Pubkey>,
+    pub claimant: Option<Pubkey>,
+    pub description_hash: [u8; 32],
+    pub status: BountyStatus,
+    pub bump: u8,
+}
+
+impl Bounty {
+    pub const SEED: &'static [u8] = b"bounty";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Bounty lifecycle: `Open -> Claimed -> Paid`, or `Open -> Cancelled`; a
+/// rejected claim returns `Claimed -> Open`. Paying or cancelling closes the
+/// account, so `Paid` and `Cancelled` are only seen within that instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum BountyStatus {
+    Open,
+    Claimed,
+    Paid,
+    Cancelled,
+}
+
+impl BountyStatus {
+    /// Require the bounty to be in `expected`, failing with the error that
+    /// names the state it is actually in.
+    fn require(self, expected: BountyStatus) -> Result<()> {
+        if self == expected {
+            return Ok(());
+        }
+        Err(match self {
+            BountyStatus::Open => HydraError::BountyNotClaimed,
+            BountyStatus::Claimed => HydraError::BountyAlreadyClaimed,
+            BountyStatus::Paid => HydraError::BountyAlreadyPaid,
+            BountyStatus::Cancelled => HydraError::BountyAlreadyCancelled,
+        }
+        .into())
+    }
+}
+
+/// Agent lifecycle; see `transition_status` for the legal moves.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AgentStatus {
+    Active,
+    Deactivated,
+    Frozen,
+    /// Reactivated and inside the registry's reactivation quarantine; which
+    /// actions it blocks is up to `quarantine_gates` (see `check_quarantine`)
+    Quarantined,
+    /// Deactivated by `expire_agent`
+    Expired,
+}
+
+impl AgentStatus {
+    /// Counts as active: `Active` or `Quarantined`.
+    pub fn is_live(self) -> bool {
+        matches!(self, AgentStatus::Active | AgentStatus::Quarantined)
+    }
+
+    pub fn allows_earning(self) -> bool {
+        self.is_live()
+    }
+
+    pub fn allows_spawning(self) -> bool {
+        self.is_live()
+    }
+
+    pub fn allows_distribution(self) -> bool {
+        self.is_live()
+    }
+}
+
+/// SPL Token program, owner of classic spawn license token accounts.
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// SPL Token-2022 program, owner of extension-enabled spawn license token accounts.
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// SPL Memo program (v2), the target of `emit_memo`.
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Seed of the system-owned fee vault PDA that receives slashed bonds.
+pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+
+/// A child's promise to distribute at least `min_amount` to its parent every
+/// `interval_secs`; see `commit_distribution_schedule`.
+#[account]
+#[derive(InitSpace)]
+pub struct DistributionCommitment {
+    pub agent: Pubkey,
+    pub min_amount: u64,
+    pub interval_secs: i64,
+    /// Last qualifying distribution, or the commitment's creation time
+    pub last_satisfied_at: i64,
+    /// Last `flag_missed_distribution` (0 = never)
+    pub last_flagged_at: i64,
+    pub missed_count: u32,
+    pub bump: u8,
+}
+
+impl DistributionCommitment {
+    pub const SEED: &'static [u8] = b"commitment";
+    pub const SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+// ============================================================================
+// PDA Derivation
+// ============================================================================
+
+impl Registry {
+    /// Canonical registry PDA: `["registry"]`.
+    pub fn find_address(program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED], program_id)
+    }
+
+    /// Registry PDA for a namespace: `["registry", namespace]`. The empty
+    /// namespace derives the same address as `find_address`, so registries
+    /// created before namespaces existed are the default tree.
+    pub fn find_namespaced_address(namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, namespace.as_bytes()], program_id)
+    }
+}
+
+impl GlobalStats {
+    /// Canonical global stats PDA: `["stats", namespace]`.
+    pub fn find_address(namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, namespace.as_bytes()], program_id)
+    }
+}
+
+impl SnapshotLog {
+    /// Canonical snapshot log PDA: `["snapshots", namespace]`.
+    pub fn find_address(namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, namespace.as_bytes()], program_id)
+    }
+}
+
+impl AgentAccount {
+    /// Canonical agent PDA: `["agent", namespace_seed, wallet]` (see `namespace_seed`).
+    pub fn find_address(namespace: &str, wallet: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, &Self::namespace_seed(namespace), wallet.as_ref()],
+            program_id,
+        )
+    }
+
+    /// Indexed agent PDA: `["agent", namespace_seed, wallet, agent_index_le]`.
+    pub fn find_indexed_address(
+        namespace: &str,
+        wallet: &Pubkey,
+        agent_index: u32,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, &Self::namespace_seed(namespace), wallet.as_ref(), &agent_index.to_le_bytes()],
+            program_id,
+        )
+    }
+}
+
+impl WalletAgentCounter {
+    /// Canonical per-wallet agent counter PDA: `["agent_counter", wallet, namespace]`.
+    pub fn find_address(wallet: &Pubkey, namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, wallet.as_ref(), namespace.as_bytes()], program_id)
+    }
+}
+
+impl SpawnTemplate {
+    /// Canonical spawn template PDA: `["template", parent_agent, template_id_le]`.
+    pub fn find_address(parent_agent: &Pubkey, template_id: u32, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, parent_agent.as_ref(), &template_id.to_le_bytes()],
+            program_id,
+        )
+    }
+}
+
+impl OracleRecord {
+    /// Canonical oracle record PDA: `["oracle", oracle, namespace]`.
+    pub fn find_address(oracle: &Pubkey, namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, oracle.as_ref(), namespace.as_bytes()], program_id)
+    }
+}
+
+impl TaskLog {
+    /// Canonical task log PDA: `["tasks", agent]`.
+    pub fn find_address(agent: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, agent.as_ref()], program_id)
+    }
+}
+
+impl NameReservation {
+    /// Canonical name reservation PDA: `["reservation", sha256(name), namespace]`.
+    pub fn find_address(name: &str, namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, hash(name.as_bytes()).as_ref(), namespace.as_bytes()],
+            program_id,
+        )
+    }
+}
+
+impl SplitConfig {
+    /// Canonical split config PDA: `["split", agent]`.
+    pub fn find_address(agent: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, agent.as_ref()], program_id)
+    }
+}
+
+impl Bond {
+    /// Canonical bond PDA: `["bond", agent]`.
+    pub fn find_address(agent: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, agent.as_ref()], program_id)
+    }
+}
+
+impl SpecCounter {
+    /// Canonical spec counter PDA: `["spec", sha256(specialization), namespace]`.
+    pub fn find_address(specialization: &str, namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, hash(specialization.as_bytes()).as_ref(), namespace.as_bytes()],
+            program_id,
+        )
+    }
+}
+
+impl Leaderboard {
+    /// Canonical leaderboard PDA: `["leaderboard", namespace]`.
+    pub fn find_address(namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, namespace.as_bytes()], program_id)
+    }
+}
+
+impl Referral {
+    /// Canonical referral PDA: `["referral", payer, namespace]`.
+    pub fn find_address(payer: &Pubkey, namespace: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, payer.as_ref(), namespace.as_bytes()], program_id)
+    }
+}
+
+impl TokenEarnings {
+    /// Canonical token earnings PDA: `["token_earnings", agent, mint]`.
+    pub fn find_address(agent: &Pubkey, mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, agent.as_ref(), mint.as_ref()], program_id)
+    }
+}
+
+impl Bounty {
+    /// Canonical bounty PDA: `["bounty", poster_agent, bounty_id_le]`.
+    pub fn find_address(poster_agent: &Pubkey, bounty_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, poster_agent.as_ref(), &bounty_id.to_le_bytes()],
+            program_id,
+        )
+    }
+}
+
+impl DistributionCommitment {
+    /// Canonical distribution commitment PDA: `["commitment", agent]`.
+    pub fn find_address(agent: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, agent.as_ref()], program_id)
+    }
+}
+
+/// Canonical fee vault PDA: `["fee_vault"]`.
+pub fn find_fee_vault_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_VAULT_SEED], program_id)
+}
+
+/// Signer seeds for program-signed CPIs, matching the derivations above. Also
+/// used to re-derive an account's address from its stored bump.
+///
+/// Always pass the bump stored on the account (or returned by `find_address`),
+/// never one supplied by the caller:
+/// `let bump = [agent.bump]; let seeds = seeds::registry_seeds(namespace, &bump);`
+/// then `CpiContext::new_with_signer(program, accounts, &[&seeds])`.
+pub mod seeds {
+    use super::*;
+
+    pub fn registry_seeds<'a>(namespace: &'a str, bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
+        [Registry::SEED, namespace.as_bytes(), bump]
+    }
+
+    pub fn stats_seeds<'a>(namespace: &'a str, bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
+        [GlobalStats::SEED, namespace.as_bytes(), bump]
+    }
+
+    /// `namespace_seed` is `AgentAccount::namespace_seed(namespace)`.
+    pub fn agent_seeds<'a>(namespace_seed: &'a [u8], wallet: &'a Pubkey, bump: &'a [u8; 1]) -> [&'a [u8]; 4] {
+        [AgentAccount::SEED, namespace_seed, wallet.as_ref(), bump]
+    }
+
+    /// `agent_index_le` is the agent index as little-endian bytes.
+    pub fn indexed_agent_seeds<'a>(
+        namespace_seed: &'a [u8],
+        wallet: &'a Pubkey,
+        agent_index_le: &'a [u8; 4],
+        bump: &'a [u8; 1],
+    ) -> [&'a [u8]; 5] {
+        [AgentAccount::SEED, namespace_seed, wallet.as_ref(), agent_index_le, bump]
+    }
+
+    pub fn bond_seeds<'a>(agent: &'a Pubkey, bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
+        [Bond::SEED, agent.as_ref(), bump]
+    }
+
+    pub fn fee_vault_seeds(bump: &[u8; 1]) -> [&[u8]; 2] {
+        [FEE_VAULT_SEED, bump]
+    }
+}
+
+/// Every program address associated with a single agent wallet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProgramAddresses {
+    pub registry: Pubkey,
+    pub agent: Pubkey,
+    pub split_config: Pubkey,
+    pub bond: Pubkey,
+}
+
+impl ProgramAddresses {
+    /// Derive all PDAs for `wallet` in `namespace` under this program's id.
+    pub fn derive_all_for_wallet(namespace: &str, wallet: &Pubkey) -> Self {
+        let (registry, _) = Registry::find_namespaced_address(namespace, &crate::ID);
+        let (agent, _) = AgentAccount::find_address(namespace, wallet, &crate::ID);
+        let (split_config, _) = SplitConfig::find_address(&agent, &crate::ID);
+        let (bond, _) = Bond::find_address(&agent, &crate::ID);
+        Self {
+            registry,
+            agent,
+            split_config,
+            bond,
+        }
+    }
+}
+
+// ============================================================================
+// Instruction Contexts
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(params: InitializeParams)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Registry::SIZE + RESERVED,
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", params.namespace.as_bytes()],
+        bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalStats::SIZE + RESERVED,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", params.namespace.as_bytes()],
+        bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SplitStats<'info> {
+    #[account(
+        mut,
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalStats::SIZE + RESERVED,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        init,
+        payer = authority,
+        space = Leaderboard::SIZE + RESERVED,
+        // Derivation: Leaderboard::find_address
+        seeds = [b"leaderboard", namespace.as_bytes()],
+        bump,
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, oracle: Pubkey)]
+pub struct AddOracle<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        init,
+        payer = authority,
+        space = OracleRecord::SIZE + RESERVED,
+        // Derivation: OracleRecord::find_address
+        seeds = [b"oracle", oracle.as_ref(), namespace.as_bytes()],
+        bump,
+    )]
+    pub oracle_record: Account<'info, OracleRecord>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, oracle: Pubkey)]
+pub struct RemoveOracle<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        close = authority,
+        // Derivation: OracleRecord::find_address
+        seeds = [b"oracle", oracle.as_ref(), namespace.as_bytes()],
+        bump = oracle_record.bump,
+    )]
+    pub oracle_record: Account<'info, OracleRecord>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct UpdateRegistryConfig<'info> {
+    #[account(
+        mut,
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, name: String, specialization: String)]
+pub struct RegisterRootAgent<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        init,
+        payer = authority,
+        space = AgentAccount::SIZE + RESERVED,
+        // Derivation: AgentAccount::find_address
+        seeds = [b"agent", &AgentAccount::namespace_seed(&namespace)[..], wallet.key().as_ref()],
+        bump,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    /// Required when the registry demands a bond
+    #[account(
+        init,
+        payer = authority,
+        space = Bond::SIZE + RESERVED,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", agent.key().as_ref()],
+        bump,
+    )]
+    pub bond: Option<Account<'info, Bond>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SpecCounter::SIZE + RESERVED,
+        // Derivation: SpecCounter::find_address
+        seeds = [b"spec", &hash(specialization.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub spec_counter: Account<'info, SpecCounter>,
+    /// CHECK: Agent's operating wallet, validated by PDA seed
+    pub wallet: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Name reservation PDA, possibly uninitialized; parsed in
+    /// `check_name_reservation`
+    #[account(
+        // Derivation: NameReservation::find_address
+        seeds = [b"reservation", &hash(name.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub name_reservation: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, name: String, specialization: String)]
+pub struct SpawnChild<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(
+        init,
+        payer = parent_wallet,
+        space = AgentAccount::SIZE + RESERVED,
+        // Derivation: AgentAccount::find_address
+        seeds = [b"agent", &AgentAccount::namespace_seed(&namespace)[..], child_wallet.key().as_ref()],
+        bump,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    /// Required when the registry demands a bond
+    #[account(
+        init,
+        payer = parent_wallet,
+        space = Bond::SIZE + RESERVED,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", child_agent.key().as_ref()],
+        bump,
+    )]
+    pub bond: Option<Account<'info, Bond>>,
+    #[account(
+        init_if_needed,
+        payer = parent_wallet,
+        space = SpecCounter::SIZE + RESERVED,
+        // Derivation: SpecCounter::find_address
+        seeds = [b"spec", &hash(specialization.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub spec_counter: Account<'info, SpecCounter>,
+    #[account(mut)]
+    pub parent_wallet: Signer<'info>,
+    /// CHECK: New child agent's wallet, validated by PDA seed
+    pub child_wallet: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Parent wallet's token account for the spawn license mint; required
+    /// when the registry gates spawning, parsed in `check_spawn_license`
+    pub spawn_license: Option<UncheckedAccount<'info>>,
+    /// CHECK: Name reservation PDA, possibly uninitialized; parsed in
+    /// `check_name_reservation`
+    #[account(
+        // Derivation: NameReservation::find_address
+        seeds = [b"reservation", &hash(name.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub name_reservation: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, name: String)]
+pub struct ReserveName<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        init,
+        payer = reserver,
+        space = NameReservation::SIZE + RESERVED,
+        // Derivation: NameReservation::find_address
+        seeds = [b"reservation", &hash(name.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub name_reservation: Account<'info, NameReservation>,
+    #[account(mut)]
+    pub reserver: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, name: String)]
+pub struct ReleaseReservation<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        close = reserver,
+        has_one = reserver,
+        // Derivation: NameReservation::find_address
+        seeds = [b"reservation", &hash(name.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump = name_reservation.bump,
+    )]
+    pub name_reservation: Account<'info, NameReservation>,
+    /// CHECK: Receives the reservation's rent, validated by `has_one`
+    #[account(mut)]
+    pub reserver: UncheckedAccount<'info>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, agent_index: u32, name: String, specialization: String)]
+pub struct RegisterAgentIndexed<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(
+        init,
+        payer = parent_wallet,
+        space = AgentAccount::SIZE + RESERVED,
+        // Derivation: AgentAccount::find_indexed_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_wallet.key().as_ref(),
+            &agent_index.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    #[account(
+        init_if_needed,
+        payer = parent_wallet,
+        space = WalletAgentCounter::SIZE + RESERVED,
+        // Derivation: WalletAgentCounter::find_address
+        seeds = [b"agent_counter", child_wallet.key().as_ref(), namespace.as_bytes()],
+        bump,
+    )]
+    pub wallet_agent_counter: Account<'info, WalletAgentCounter>,
+    /// Required when the registry demands a bond
+    #[account(
+        init,
+        payer = parent_wallet,
+        space = Bond::SIZE + RESERVED,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", child_agent.key().as_ref()],
+        bump,
+    )]
+    pub bond: Option<Account<'info, Bond>>,
+    #[account(
+        init_if_needed,
+        payer = parent_wallet,
+        space = SpecCounter::SIZE + RESERVED,
+        // Derivation: SpecCounter::find_address
+        seeds = [b"spec", &hash(specialization.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub spec_counter: Account<'info, SpecCounter>,
+    #[account(mut)]
+    pub parent_wallet: Signer<'info>,
+    /// CHECK: Wallet operating the new indexed agent, validated by PDA seed
+    pub child_wallet: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Parent wallet's token account for the spawn license mint; required
+    /// when the registry gates spawning, parsed in `check_spawn_license`
+    pub spawn_license: Option<UncheckedAccount<'info>>,
+    /// CHECK: Name reservation PDA, possibly uninitialized; parsed in
+    /// `check_name_reservation`
+    #[account(
+        // Derivation: NameReservation::find_address
+        seeds = [b"reservation", &hash(name.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub name_reservation: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, template_id: u32)]
+pub struct CreateSpawnTemplate<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(
+        init,
+        payer = parent_wallet,
+        space = SpawnTemplate::SIZE + RESERVED,
+        // Derivation: SpawnTemplate::find_address
+        seeds = [b"template", parent_agent.key().as_ref(), &template_id.to_le_bytes()],
+        bump,
+    )]
+    pub template: Account<'info, SpawnTemplate>,
+    #[account(mut)]
+    pub parent_wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, template_id: u32, name_suffix: String)]
+pub struct SpawnFromTemplate<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        // Derivation: SpawnTemplate::find_address
+        seeds = [b"template", parent_agent.key().as_ref(), &template_id.to_le_bytes()],
+        bump = template.bump,
+    )]
+    pub template: Account<'info, SpawnTemplate>,
+    #[account(
+        init,
+        payer = parent_wallet,
+        space = AgentAccount::SIZE + RESERVED,
+        // Derivation: AgentAccount::find_address
+        seeds = [b"agent", &AgentAccount::namespace_seed(&namespace)[..], child_wallet.key().as_ref()],
+        bump,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    /// Required when the registry demands a bond
+    #[account(
+        init,
+        payer = parent_wallet,
+        space = Bond::SIZE + RESERVED,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", child_agent.key().as_ref()],
+        bump,
+    )]
+    pub bond: Option<Account<'info, Bond>>,
+    #[account(
+        init_if_needed,
+        payer = parent_wallet,
+        space = SpecCounter::SIZE + RESERVED,
+        // Derivation: SpecCounter::find_address
+        seeds = [b"spec", &hash(template.specialization.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub spec_counter: Account<'info, SpecCounter>,
+    #[account(mut)]
+    pub parent_wallet: Signer<'info>,
+    /// CHECK: New child agent's wallet, validated by PDA seed
+    pub child_wallet: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Parent wallet's token account for the spawn license mint; required
+    /// when the registry gates spawning, parsed in `check_spawn_license`
+    pub spawn_license: Option<UncheckedAccount<'info>>,
+    /// CHECK: Name reservation PDA, possibly uninitialized; parsed in
+    /// `check_name_reservation`
+    #[account(
+        // Derivation: NameReservation::find_address
+        seeds = [b"reservation", &hash(template.child_name(&name_suffix).as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump,
+    )]
+    pub name_reservation: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, template_id: u32)]
+pub struct CloseSpawnTemplate<'info> {
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        close = parent_wallet,
+        // Derivation: SpawnTemplate::find_address
+        seeds = [b"template", parent_agent.key().as_ref(), &template_id.to_le_bytes()],
+        bump = template.bump,
+    )]
+    pub template: Account<'info, SpawnTemplate>,
+    #[account(mut)]
+    pub parent_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecordEarning<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    pub wallet: Signer<'info>,
+    /// Direct parent; when supplied its subtree total is credited immediately.
+    /// Required for non-root agents when the registry requires an active lineage.
+    #[account(
+        mut,
+        address = agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+    /// Updated with the agent's new total when supplied
+    #[account(
+        mut,
+        // Derivation: Leaderboard::find_address
+        seeds = [b"leaderboard", namespace.as_bytes()],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+    /// CHECK: SPL Memo program; required when the registry emits memos
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecordEarningsBulk<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    /// Direct parent; when supplied its subtree total is credited immediately.
+    /// Required for non-root agents when the registry requires an active lineage.
+    #[account(
+        mut,
+        address = agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+    /// Updated with the agent's new total when supplied
+    #[account(
+        mut,
+        // Derivation: Leaderboard::find_address
+        seeds = [b"leaderboard", namespace.as_bytes()],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+    /// CHECK: SPL Memo program; required when the registry emits memos
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+    /// Required when `task_hashes` is non-empty; created on first use
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = TaskLog::SIZE + RESERVED,
+        // Derivation: TaskLog::find_address
+        seeds = [b"tasks", agent.key().as_ref()],
+        bump,
+    )]
+    pub task_log: Option<Box<Account<'info, TaskLog>>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecordEarningByOracle<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    pub oracle: Signer<'info>,
+    #[account(
+        // Derivation: OracleRecord::find_address
+        seeds = [b"oracle", oracle.key().as_ref(), namespace.as_bytes()],
+        bump = oracle_record.bump,
+    )]
+    pub oracle_record: Account<'info, OracleRecord>,
+    /// Direct parent; when supplied its subtree total is credited immediately.
+    /// Required for non-root agents when the registry requires an active lineage.
+    #[account(
+        mut,
+        address = agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+    /// Updated with the agent's new total when supplied
+    #[account(
+        mut,
+        // Derivation: Leaderboard::find_address
+        seeds = [b"leaderboard", namespace.as_bytes()],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+    /// CHECK: SPL Memo program; required when the registry emits memos
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct AttestChildEarning<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_agent.wallet.as_ref(),
+            &child_agent.index_seed()[..],
+        ],
+        bump = child_agent.bump,
+        constraint = !child_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+        address = child_agent.parent @ HydraError::ParentMismatch,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    pub parent_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct BatchRecordEarnings<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = reporter @ HydraError::Unauthorized,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    pub reporter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecordTokenEarning<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = TokenEarnings::SIZE + RESERVED,
+        // Derivation: TokenEarnings::find_address
+        seeds = [b"token_earnings", agent.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub token_earnings: Account<'info, TokenEarnings>,
+    /// CHECK: Token mint the earning is denominated in; only its address is used
+    pub mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RollUpEarnings<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        address = agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DistributeToParent<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_wallet.key().as_ref(),
+            &child_agent.index_seed()[..],
+        ],
+        bump = child_agent.bump,
+        constraint = !child_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_agent.wallet.as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+        address = child_agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub child_wallet: Signer<'info>,
+    /// CHECK: Parent's wallet; must be `parent_agent.wallet` and system-owned
+    /// (a keypair wallet or a vault PDA such as a Squads multisig vault).
+    #[account(
+        mut,
+        address = parent_agent.wallet @ HydraError::ParentMismatch,
+        owner = system_program::ID @ HydraError::InvalidParentWalletOwner,
+    )]
+    pub parent_wallet: UncheckedAccount<'info>,
+    /// Parent's parent; required when the parent forwards a share upstream
+    #[account(
+        mut,
+        constraint = !grandparent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub grandparent_agent: Option<Account<'info, AgentAccount>>,
+    /// CHECK: Grandparent's wallet, validated against grandparent_agent in the handler
+    #[account(mut, owner = system_program::ID @ HydraError::InvalidParentWalletOwner)]
+    pub grandparent_wallet: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: SPL Memo program; required when the registry emits memos
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+    /// The child's distribution commitment; a large enough amount satisfies it
+    #[account(
+        mut,
+        // Derivation: DistributionCommitment::find_address
+        seeds = [b"commitment", child_agent.key().as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Option<Account<'info, DistributionCommitment>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CommitDistributionSchedule<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        init,
+        payer = wallet,
+        space = DistributionCommitment::SIZE + RESERVED,
+        // Derivation: DistributionCommitment::find_address
+        seeds = [b"commitment", agent.key().as_ref()],
+        bump,
+    )]
+    pub commitment: Account<'info, DistributionCommitment>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct FlagMissedDistribution<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: DistributionCommitment::find_address
+        seeds = [b"commitment", commitment.agent.as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, DistributionCommitment>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RevokeDistributionCommitment<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_wallet.key().as_ref(),
+            &child_agent.index_seed()[..],
+        ],
+        bump = child_agent.bump,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    #[account(address = child_agent.parent @ HydraError::ParentMismatch)]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        close = child_wallet,
+        // Derivation: DistributionCommitment::find_address
+        seeds = [b"commitment", child_agent.key().as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, DistributionCommitment>,
+    #[account(mut)]
+    pub child_wallet: Signer<'info>,
+    #[account(address = parent_agent.wallet @ HydraError::Unauthorized)]
+    pub parent_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DistributeUpChain<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_wallet.key().as_ref(),
+            &child_agent.index_seed()[..],
+        ],
+        bump = child_agent.bump,
+        constraint = !child_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub child_wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DistributeToChildren<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub parent_wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct PayAgentWithSplit<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_wallet.key().as_ref(),
+            &child_agent.index_seed()[..],
+        ],
+        bump = child_agent.bump,
+        constraint = !child_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    /// CHECK: Child's wallet, validated by child_agent PDA
+    #[account(mut)]
+    pub child_wallet: UncheckedAccount<'info>,
+    /// Required unless the child is a root agent
+    #[account(
+        mut,
+        address = child_agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+    /// CHECK: Parent's wallet, must match parent_agent
+    #[account(
+        mut,
+        constraint = parent_agent.as_ref().is_some_and(|p| p.wallet == parent_wallet.key())
+            @ HydraError::ParentMismatch,
+    )]
+    pub parent_wallet: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// The payer's referral record; pays the referring agent while unexpired
+    #[account(
+        // Derivation: Referral::find_address
+        seeds = [b"referral", payer.key().as_ref(), namespace.as_bytes()],
+        bump = referral.bump,
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+    /// Required with an active referral
+    #[account(
+        mut,
+        constraint = referral.as_ref().is_some_and(|r| r.referring_agent == referring_agent.key())
+            @ HydraError::ReferralMismatch,
+        constraint = !referring_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub referring_agent: Option<Account<'info, AgentAccount>>,
+    /// CHECK: Referring agent's wallet, must match referring_agent
+    #[account(
+        mut,
+        constraint = referring_agent.as_ref().is_some_and(|a| a.wallet == referring_wallet.key())
+            @ HydraError::ReferralMismatch,
+    )]
+    pub referring_wallet: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RegisterReferral<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        init,
+        payer = payer,
+        space = Referral::SIZE + RESERVED,
+        // Derivation: Referral::find_address
+        seeds = [b"referral", payer.key().as_ref(), namespace.as_bytes()],
+        bump,
+    )]
+    pub referral: Account<'info, Referral>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            referring_agent.wallet.as_ref(),
+            &referring_agent.index_seed()[..],
+        ],
+        bump = referring_agent.bump,
+    )]
+    pub referring_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DistributeToRoot<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            root_wallet.key().as_ref(),
+            &root_agent.index_seed()[..],
+        ],
+        bump = root_agent.bump,
+        constraint = !root_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub root_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    /// CHECK: Root agent's wallet, validated by root_agent PDA
+    #[account(mut)]
+    pub root_wallet: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DistributeToSecondary<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_wallet.key().as_ref(),
+            &child_agent.index_seed()[..],
+        ],
+        bump = child_agent.bump,
+        constraint = !child_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            secondary_wallet.key().as_ref(),
+            &secondary_agent.index_seed()[..],
+        ],
+        bump = secondary_agent.bump,
+    )]
+    pub secondary_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub child_wallet: Signer<'info>,
+    /// CHECK: Secondary beneficiary's wallet, validated by secondary_agent PDA
+    #[account(mut)]
+    pub secondary_wallet: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct UpdateAgent<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    pub wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetForwardShare<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    pub wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetSplitConfig<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = SplitConfig::SIZE + RESERVED,
+        // Derivation: SplitConfig::find_address
+        seeds = [b"split", agent.key().as_ref()],
+        bump,
+    )]
+    pub split_config: Account<'info, SplitConfig>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetWithdrawWhitelist<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    pub wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DistributeSplit<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        // Derivation: SplitConfig::find_address
+        seeds = [b"split", agent.key().as_ref()],
+        bump = split_config.bump,
+    )]
+    pub split_config: Account<'info, SplitConfig>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ReportWaterfall<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+}
+
+#[derive(Accounts)]
+pub struct ReportPosition<'info> {
+    pub agent: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+pub struct GetProjectedSplit<'info> {
+    #[account(
+        // Derivation: SplitConfig::find_address
+        seeds = [b"split", split_config.agent.as_ref()],
+        bump = split_config.bump,
+    )]
+    pub split_config: Account<'info, SplitConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetLineageDepth<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    pub agent: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+pub struct IsAncestor<'info> {
+    pub ancestor: Account<'info, AgentAccount>,
+    pub descendant: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+pub struct ListChildren<'info> {
+    pub parent: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyPda {}
+
+#[derive(Accounts)]
+pub struct AgentExists<'info> {
+    /// CHECK: Probed for program ownership and an agent discriminator; never written
+    pub candidate: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct Heartbeat<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    pub wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct MarkStale<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct WithdrawBond<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        close = wallet,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", agent.key().as_ref()],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SlashBond<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", bond.agent.as_ref()],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+    /// CHECK: System-owned fee vault PDA, validated by seeds
+    #[account(
+        mut,
+        // Derivation: find_fee_vault_address
+        seeds = [FEE_VAULT_SEED],
+        bump,
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct AdjustEarning<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    pub authority: Signer<'info>,
+    /// Direct parent; receives the delta in its subtree total
+    #[account(
+        mut,
+        address = agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SweepAgentPda<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAgent<'info> {
+    /// CHECK: May predate the current layout; owner and discriminator checked in the handler
+    #[account(mut)]
+    pub agent: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateRegistry<'info> {
+    /// CHECK: May predate the current layout; owner and discriminator checked in the handler
+    #[account(
+        mut,
+        // Derivation: Registry::find_address
         seeds = [b"registry"],
+        bump,
+    )]
+    pub registry: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ReconcileReport<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct TakeSnapshot<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SnapshotLog::SIZE + RESERVED,
+        // Derivation: SnapshotLog::find_address
+        seeds = [b"snapshots", namespace.as_bytes()],
+        bump,
+    )]
+    pub snapshot_log: Account<'info, SnapshotLog>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ReconcileChildrenCount<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_agent.wallet.as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct PruneInactive<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CloseWithForward<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        close = rent_destination,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            wallet.key().as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    /// Also receives the agent's bond
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    /// The closing agent's own parent; omitted for roots and when it is `new_parent`
+    #[account(
+        mut,
+        address = agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            new_parent_wallet.key().as_ref(),
+            &new_parent.index_seed()[..],
+        ],
+        bump = new_parent.bump,
+        constraint = !new_parent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub new_parent: Account<'info, AgentAccount>,
+    pub new_parent_wallet: Signer<'info>,
+    /// CHECK: Receives the closed agent's rent; must be its recorded rent payer
+    #[account(mut, address = agent.rent_refund_destination() @ HydraError::InvalidPruneAccounts)]
+    pub rent_destination: UncheckedAccount<'info>,
+    /// CHECK: The agent's bond PDA; closed to `wallet` if it holds a bond
+    #[account(
+        mut,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", agent.key().as_ref()],
+        bump,
+    )]
+    pub bond: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        // Derivation: SpecCounter::find_address
+        seeds = [b"spec", &hash(agent.specialization.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump = spec_counter.bump,
+    )]
+    pub spec_counter: Account<'info, SpecCounter>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct MergeAgents<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        close = rent_destination,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            source.wallet.as_ref(),
+            &source.index_seed()[..],
+        ],
+        bump = source.bump,
+        constraint = !source.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub source: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            target.wallet.as_ref(),
+            &target.index_seed()[..],
+        ],
+        bump = target.bump,
+        constraint = !target.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub target: Account<'info, AgentAccount>,
+    /// The agents' shared parent; omitted when both are roots
+    #[account(
+        mut,
+        address = source.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+    /// CHECK: Receives the source's rent; must be its recorded rent payer
+    #[account(mut, address = source.rent_refund_destination() @ HydraError::InvalidPruneAccounts)]
+    pub rent_destination: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: The source's bond PDA; closed to `source_wallet` if it holds a bond
+    #[account(
+        mut,
+        // Derivation: Bond::find_address
+        seeds = [b"bond", source.key().as_ref()],
+        bump,
+    )]
+    pub bond: UncheckedAccount<'info>,
+    /// CHECK: Receives the source's bond; must be the source agent's wallet
+    #[account(mut, address = source.wallet)]
+    pub source_wallet: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        // Derivation: SpecCounter::find_address
+        seeds = [b"spec", &hash(source.specialization.as_bytes()).to_bytes()[..], namespace.as_bytes()],
+        bump = spec_counter.bump,
+    )]
+    pub spec_counter: Account<'info, SpecCounter>,
+    /// Loses the source's entry when supplied
+    #[account(
+        mut,
+        // Derivation: Leaderboard::find_address
+        seeds = [b"leaderboard", namespace.as_bytes()],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        constraint = registry.pending_authority == Some(new_authority.key()) @ HydraError::Unauthorized,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ReparentAgent<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            child_agent.wallet.as_ref(),
+            &child_agent.index_seed()[..],
+        ],
+        bump = child_agent.bump,
+        constraint = !child_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub child_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        address = child_agent.parent @ HydraError::ParentMismatch,
+        constraint = !old_parent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub old_parent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            new_parent_wallet.key().as_ref(),
+            &new_parent.index_seed()[..],
+        ],
+        bump = new_parent.bump,
+        constraint = !new_parent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub new_parent: Account<'info, AgentAccount>,
+    #[account(address = old_parent.wallet @ HydraError::Unauthorized)]
+    pub old_parent_wallet: Signer<'info>,
+    pub new_parent_wallet: Signer<'info>,
+    /// CHECK: Must sign when the registry requires child consent; checked in the handler
+    #[account(address = child_agent.wallet @ HydraError::Unauthorized)]
+    pub child_wallet: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct PromoteToRoot<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        address = agent.parent @ HydraError::ParentMismatch,
+        constraint = !old_parent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub old_parent: Account<'info, AgentAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecomputeDepth<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(address = agent.parent @ HydraError::ParentMismatch)]
+    pub parent_agent: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ExtendAgentTtl<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = agent.parent == parent_agent.key() @ HydraError::ParentMismatch,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            parent_wallet.key().as_ref(),
+            &parent_agent.index_seed()[..],
+        ],
+        bump = parent_agent.bump,
+    )]
+    pub parent_agent: Account<'info, AgentAccount>,
+    pub parent_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ExpireAgent<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DeactivateAgent<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    /// Registry authority (deactivation for cause) or the agent's own wallet
+    #[account(
+        constraint = authority.key() == registry.authority
+            || authority.key() == agent.wallet @ HydraError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetAgentStatus<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            agent.wallet.as_ref(),
+            &agent.index_seed()[..],
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub agent: Account<'info, AgentAccount>,
+    /// Registry authority or the agent's own wallet
+    #[account(
+        constraint = authority.key() == registry.authority
+            || authority.key() == agent.wallet @ HydraError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, bounty_id: u64)]
+pub struct PostBounty<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            poster_wallet.key().as_ref(),
+            &poster_agent.index_seed()[..],
+        ],
+        bump = poster_agent.bump,
+    )]
+    pub poster_agent: Account<'info, AgentAccount>,
+    #[account(
+        init,
+        payer = poster_wallet,
+        space = Bounty::SIZE + RESERVED,
+        // Derivation: Bounty::find_address
+        seeds = [b"bounty", poster_agent.key().as_ref(), &bounty_id.to_le_bytes()],
+        bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(mut)]
+    pub poster_wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ClaimBounty<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            claimant_wallet.key().as_ref(),
+            &claimant_agent.index_seed()[..],
+        ],
+        bump = claimant_agent.bump,
+    )]
+    pub claimant_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        // Derivation: Bounty::find_address
+        seeds = [b"bounty", bounty.poster_agent.as_ref(), &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    pub claimant_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ApproveAndPay<'info> {
+    #[account(
+        // Derivation: Registry::find_namespaced_address
+        seeds = [b"registry", namespace.as_bytes()],
         bump = registry.bump,
     )]
     pub registry: Account<'info, Registry>,
     #[account(
         mut,
-        seeds = [b"agent", agent.wallet.as_ref()],
-        bump = agent.bump,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
     )]
-    pub agent: Account<'info, AgentAccount>,
-    pub authority: Signer<'info>,
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            poster_wallet.key().as_ref(),
+            &poster_agent.index_seed()[..],
+        ],
+        bump = poster_agent.bump,
+    )]
+    pub poster_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        close = poster_wallet,
+        has_one = poster_agent,
+        // Derivation: Bounty::find_address
+        seeds = [b"bounty", poster_agent.key().as_ref(), &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            claimant_agent.wallet.as_ref(),
+            &claimant_agent.index_seed()[..],
+        ],
+        bump = claimant_agent.bump,
+        constraint = Some(claimant_agent.key()) == bounty.claimant @ HydraError::BountyNotClaimed,
+        constraint = !claimant_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub claimant_agent: Account<'info, AgentAccount>,
+    /// CHECK: Receives the escrow; must be the claimant agent's wallet
+    #[account(mut, address = claimant_agent.wallet)]
+    pub claimant_wallet: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub poster_wallet: Signer<'info>,
+    /// Claimant's direct parent; when supplied its subtree total is credited
+    /// immediately. Required for non-root claimants when the registry requires
+    /// an active lineage.
+    #[account(
+        mut,
+        address = claimant_agent.parent @ HydraError::ParentMismatch,
+        constraint = !parent_agent.is_frozen @ HydraError::AgentFrozen,
+    )]
+    pub parent_agent: Option<Account<'info, AgentAccount>>,
+    /// Updated with the claimant's new total when supplied
+    #[account(
+        mut,
+        // Derivation: Leaderboard::find_address
+        seeds = [b"leaderboard", namespace.as_bytes()],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+    /// CHECK: SPL Memo program; required when the registry emits memos
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RejectClaim<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            poster_wallet.key().as_ref(),
+            &poster_agent.index_seed()[..],
+        ],
+        bump = poster_agent.bump,
+    )]
+    pub poster_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        has_one = poster_agent,
+        // Derivation: Bounty::find_address
+        seeds = [b"bounty", poster_agent.key().as_ref(), &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    pub poster_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CancelBounty<'info> {
+    #[account(
+        mut,
+        // Derivation: GlobalStats::find_address
+        seeds = [b"stats", namespace.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Box<Account<'info, GlobalStats>>,
+    #[account(
+        // Derivation: AgentAccount::find_address
+        seeds = [
+            b"agent",
+            &AgentAccount::namespace_seed(&namespace)[..],
+            poster_wallet.key().as_ref(),
+            &poster_agent.index_seed()[..],
+        ],
+        bump = poster_agent.bump,
+    )]
+    pub poster_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        close = poster_wallet,
+        has_one = poster_agent,
+        // Derivation: Bounty::find_address
+        seeds = [b"bounty", poster_agent.key().as_ref(), &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(mut)]
+    pub poster_wallet: Signer<'info>,
+}
+
+// ============================================================================
+// Views
+// ============================================================================
+
+/// Return types of view instructions, shared with off-chain Rust clients.
+pub mod views {
+    use super::*;
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+    pub struct AgentSummary {
+        pub agent: Pubkey,
+        pub wallet: Pubkey,
+        pub parent: Pubkey,
+        pub depth: u8,
+        pub children_count: u64,
+        pub is_operational: bool,
+        pub total_earned: u64,
+        pub net_position: i128,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct ProjectedShare {
+        pub recipient: Pubkey,
+        pub amount: u64,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+    pub struct ProjectedSplit {
+        pub amount: u64,
+        pub shares: Vec<ProjectedShare>,
+        /// Left with the agent after all recipients
+        pub retained: u64,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct DerivedPda {
+        pub address: Pubkey,
+        pub bump: u8,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct LineageDepth {
+        pub depth: u8,
+        pub max_depth: u8,
+        /// Further generations that may still be spawned below this agent
+        pub levels_below: u8,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Ancestry {
+        pub is_ancestor: bool,
+        /// Parent links from descendant to ancestor (0 when not an ancestor)
+        pub hops: u8,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct SpawnProbe {
+        pub allowed: bool,
+        /// `HydraError` code of the first failed check (0 when allowed)
+        pub error_code: u32,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct RegistryStats {
+        pub total_agents: u64,
+        pub total_spawns: u64,
+        pub total_reported_earnings: u64,
+        pub total_transferred_volume: u64,
+        pub max_depth: u8,
+        pub decimals: u8,
+        /// ASCII, zero-padded
+        pub currency_symbol: [u8; 8],
+        pub event_seq: u64,
+    }
+}
+
+// ============================================================================
+// Client
+// ============================================================================
+
+/// Typed instruction builders for off-chain Rust clients, behind the `client`
+/// feature. Each builder derives every PDA from wallets and the registry
+/// namespace, and lists accounts in the same order as its on-chain context.
+/// Agents are taken to live at their wallet's canonical PDA unless
+/// `OptionalAccounts` gives their `agent_index`.
+#[cfg(feature = "client")]
+pub mod client {
+    use super::*;
+    use anchor_lang::InstructionData;
+
+    /// Optional accounts to pass, and the indices of agents that live at indexed
+    /// PDAs; each builder documents the fields it reads and ignores the rest.
+    /// `Default` omits every optional account and assumes canonical agent PDAs.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct OptionalAccounts {
+        /// The new agent's bond PDA, for registries that require a bond
+        pub bond: bool,
+        /// The SPL Memo program, for registries that emit memos
+        pub memo: bool,
+        /// The namespace's leaderboard PDA
+        pub leaderboard: bool,
+        /// The child's `DistributionCommitment` PDA
+        pub commitment: bool,
+        /// The agent's parent's wallet, so its parent PDA can be credited
+        pub parent_wallet: Option<Pubkey>,
+        /// The parent's parent's wallet, for parents that forward a share
+        pub grandparent_wallet: Option<Pubkey>,
+        /// The parent wallet's token account for the spawn license mint
+        pub spawn_license: Option<Pubkey>,
+        /// `agent_index` of the signing agent, if it was registered with
+        /// `register_agent_indexed`
+        pub agent_index: Option<u32>,
+        /// `agent_index` of the parent agent, if it is indexed
+        pub parent_index: Option<u32>,
+        /// `agent_index` of the grandparent agent, if it is indexed
+        pub grandparent_index: Option<u32>,
+    }
+
+    fn registry(namespace: &str) -> Pubkey {
+        Registry::find_namespaced_address(namespace, &crate::ID).0
+    }
+
+    fn stats(namespace: &str) -> Pubkey {
+        GlobalStats::find_address(namespace, &crate::ID).0
+    }
+
+    fn leaderboard(namespace: &str) -> Pubkey {
+        Leaderboard::find_address(namespace, &crate::ID).0
+    }
+
+    fn agent(namespace: &str, wallet: &Pubkey, index: Option<u32>) -> Pubkey {
+        match index {
+            Some(index) => AgentAccount::find_indexed_address(namespace, wallet, index, &crate::ID).0,
+            None => AgentAccount::find_address(namespace, wallet, &crate::ID).0,
+        }
+    }
+
+    fn bond(agent: &Pubkey, optional: &OptionalAccounts) -> Option<Pubkey> {
+        optional.bond.then(|| Bond::find_address(agent, &crate::ID).0)
+    }
+
+    fn memo(optional: &OptionalAccounts) -> Option<Pubkey> {
+        optional.memo.then_some(MEMO_PROGRAM_ID)
+    }
+
+    fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+        Instruction {
+            program_id: crate::ID,
+            accounts: accounts.to_account_metas(None),
+            data: data.data(),
+        }
+    }
+
+    /// `register_root_agent`, signed by the registry authority. Reads `bond`.
+    pub fn build_register_root_agent_ix(
+        namespace: &str,
+        authority: &Pubkey,
+        wallet: &Pubkey,
+        name: String,
+        specialization: String,
+        optional: &OptionalAccounts,
+    ) -> Instruction {
+        let agent = agent(namespace, wallet, None);
+        let name_reservation = NameReservation::find_address(&name, namespace, &crate::ID).0;
+        instruction(
+            crate::accounts::RegisterRootAgent {
+                registry: registry(namespace),
+                stats: stats(namespace),
+                agent,
+                bond: bond(&agent, optional),
+                spec_counter: SpecCounter::find_address(&specialization, namespace, &crate::ID).0,
+                wallet: *wallet,
+                authority: *authority,
+                system_program: system_program::ID,
+                name_reservation,
+            },
+            crate::instruction::RegisterRootAgent {
+                _namespace: namespace.to_string(),
+                name,
+                specialization,
+            },
+        )
+    }
+
+    /// `spawn_child`, signed by the parent wallet. Reads `bond`, `spawn_license`
+    /// and `parent_index`; the child always gets its wallet's canonical PDA.
+    pub fn build_spawn_child_ix(
+        namespace: &str,
+        parent_wallet: &Pubkey,
+        child_wallet: &Pubkey,
+        args: crate::instruction::SpawnChild,
+        optional: &OptionalAccounts,
+    ) -> Instruction {
+        let child_agent = agent(namespace, child_wallet, None);
+        instruction(
+            crate::accounts::SpawnChild {
+                registry: registry(namespace),
+                stats: stats(namespace),
+                parent_agent: agent(namespace, parent_wallet, optional.parent_index),
+                child_agent,
+                bond: bond(&child_agent, optional),
+                spec_counter: SpecCounter::find_address(&args.specialization, namespace, &crate::ID).0,
+                parent_wallet: *parent_wallet,
+                child_wallet: *child_wallet,
+                system_program: system_program::ID,
+                spawn_license: optional.spawn_license,
+                name_reservation: NameReservation::find_address(&args.name, namespace, &crate::ID).0,
+            },
+            crate::instruction::SpawnChild {
+                _namespace: namespace.to_string(),
+                ..args
+            },
+        )
+    }
+
+    /// `record_earning`, signed by the agent's wallet. Reads `parent_wallet`,
+    /// `leaderboard`, `memo`, `agent_index` and `parent_index`.
+    pub fn build_record_earning_ix(
+        namespace: &str,
+        wallet: &Pubkey,
+        amount: u64,
+        nonce: u64,
+        category: Option<EarningCategory>,
+        optional: &OptionalAccounts,
+    ) -> Instruction {
+        instruction(
+            crate::accounts::RecordEarning {
+                registry: registry(namespace),
+                stats: stats(namespace),
+                agent: agent(namespace, wallet, optional.agent_index),
+                wallet: *wallet,
+                parent_agent: optional
+                    .parent_wallet
+                    .as_ref()
+                    .map(|w| agent(namespace, w, optional.parent_index)),
+                leaderboard: optional.leaderboard.then(|| leaderboard(namespace)),
+                memo_program: memo(optional),
+            },
+            crate::instruction::RecordEarning {
+                _namespace: namespace.to_string(),
+                amount,
+                nonce,
+                category,
+            },
+        )
+    }
+
+    /// `distribute_to_parent`, signed by the child's wallet. Reads
+    /// `grandparent_wallet`, `memo`, `commitment`, and `agent_index` for the
+    /// child, `parent_index` and `grandparent_index`.
+    pub fn build_distribute_to_parent_ix(
+        namespace: &str,
+        child_wallet: &Pubkey,
+        parent_wallet: &Pubkey,
+        amount: u64,
+        optional: &OptionalAccounts,
+    ) -> Instruction {
+        let child_agent = agent(namespace, child_wallet, optional.agent_index);
+        instruction(
+            crate::accounts::DistributeToParent {
+                registry: registry(namespace),
+                stats: stats(namespace),
+                child_agent,
+                parent_agent: agent(namespace, parent_wallet, optional.parent_index),
+                child_wallet: *child_wallet,
+                parent_wallet: *parent_wallet,
+                grandparent_agent: optional
+                    .grandparent_wallet
+                    .as_ref()
+                    .map(|w| agent(namespace, w, optional.grandparent_index)),
+                grandparent_wallet: optional.grandparent_wallet,
+                system_program: system_program::ID,
+                memo_program: memo(optional),
+                commitment: optional
+                    .commitment
+                    .then(|| DistributionCommitment::find_address(&child_agent, &crate::ID).0),
+            },
+            crate::instruction::DistributeToParent {
+                _namespace: namespace.to_string(),
+                amount,
+            },
+        )
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct StrictNamesSet {
+    pub strict_names: bool,
+}
+
+#[event]
+pub struct StrictLineageChecksSet {
+    pub strict: bool,
+}
+
+#[event]
+pub struct PruneGraceSet {
+    pub grace_secs: i64,
+}
+
+#[event]
+pub struct BondConfigSet {
+    pub required_bond_lamports: u64,
+    pub cooldown_secs: i64,
+}
+
+#[event]
+pub struct StaleThresholdSet {
+    pub threshold_secs: i64,
+}
+
+#[event]
+pub struct RequireActiveLineageSet {
+    pub require_active_lineage: bool,
+}
+
+#[event]
+pub struct MaxSingleEarningSet {
+    pub max_single_earning: u64,
+}
+
+#[event]
+pub struct RegistryInitialized {
+    pub authority: Pubkey,
+    /// Effective configuration, with defaults applied
+    pub config: InitializeParams,
+}
+
+#[event]
+pub struct ReporterSet {
+    pub reporter: Pubkey,
+}
+
+#[event]
+pub struct OracleAdded {
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct OracleRemoved {
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct NameReservationCreated {
+    pub reserver: Pubkey,
+    pub name: String,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct NameReservationReleased {
+    pub reserver: Pubkey,
+    pub name: String,
+}
+
+#[event]
+pub struct OracleOnlyEarningsSet {
+    pub oracle_only: bool,
+}
+
+#[event]
+pub struct ReactivationQuarantineSet {
+    pub reactivation_quarantine_secs: i64,
+    pub quarantine_gates: u8,
+}
+
+#[event]
+pub struct MaxBatchNodesSet {
+    pub max_batch_nodes: u16,
+}
+
+#[event]
+pub struct MinActiveSecsSet {
+    pub min_active_secs: i64,
+}
+
+#[event]
+pub struct ReparentPolicySet {
+    pub reparent_requires_child: bool,
+}
+
+#[event]
+pub struct AllowCpiSet {
+    pub allow_cpi: bool,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityProposalCancelled {
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct ProposalTtlSet {
+    pub ttl_secs: i64,
+}
+
+#[event]
+pub struct TreeBalanceSet {
+    pub require_balanced: bool,
+    pub branching_factor: u16,
+}
+
+#[event]
+pub struct SpawnLicenseMintSet {
+    pub spawn_license_mint: Pubkey,
+}
+
+#[event]
+pub struct SiblingShareCapSet {
+    pub enforce: bool,
+}
+
+#[event]
+pub struct MinSnapshotIntervalSet {
+    pub min_snapshot_interval_secs: i64,
+}
+
+#[event]
+pub struct MinDistributionIntervalSet {
+    pub min_distribution_interval_secs: i64,
+}
+
+#[event]
+pub struct AdjustmentCooldownSet {
+    pub adjustment_cooldown_secs: i64,
+}
+
+#[event]
+pub struct ReferralConfigSet {
+    pub referral_bps: u16,
+    pub referral_duration_secs: i64,
+}
+
+#[event]
+pub struct CurrencyMetadataSet {
+    pub decimals: u8,
+    pub currency_symbol: [u8; 8],
+}
+
+#[event]
+pub struct EmitMemosSet {
+    pub emit_memos: bool,
+}
+
+#[event]
+pub struct CompactEventsSet {
+    pub compact_events: bool,
+}
+
+#[event]
+pub struct MaxDepthSet {
+    pub max_depth: u8,
+}
+
+#[event]
+pub struct AgentRegistered {
+    pub agent: Pubkey,
+    pub wallet: Pubkey,
+    pub parent: Pubkey,
+    pub name: String,
+    pub specialization: String,
+    pub depth: u8,
+    pub created_by: Pubkey,
+    pub seq: u64,
+}
+
+#[event]
+pub struct AgentSpawned {
+    pub child: Pubkey,
+    pub parent: Pubkey,
+    pub child_wallet: Pubkey,
+    pub name: String,
+    pub specialization: String,
+    pub depth: u8,
+    pub revenue_share_bps: u16,
+    pub created_by: Pubkey,
+    pub seq: u64,
+}
+
+/// `AgentRegistered` without name/specialization, emitted when `Registry.compact_events` is set
+#[event]
+pub struct AgentRegisteredCompact {
+    pub agent: Pubkey,
+    pub wallet: Pubkey,
+    pub parent: Pubkey,
+    pub depth: u8,
+    pub created_by: Pubkey,
+    pub seq: u64,
+}
+
+/// `AgentSpawned` without name/specialization, emitted when `Registry.compact_events` is set
+#[event]
+pub struct AgentSpawnedCompact {
+    pub child: Pubkey,
+    pub parent: Pubkey,
+    pub child_wallet: Pubkey,
+    pub depth: u8,
+    pub revenue_share_bps: u16,
+    pub created_by: Pubkey,
+    pub seq: u64,
+}
+
+#[event]
+pub struct SpawnTemplateCreated {
+    pub parent: Pubkey,
+    pub template: Pubkey,
+    pub template_id: u32,
+    pub revenue_share_bps: u16,
+    pub max_uses: u32,
+}
+
+#[event]
+pub struct OracleEarningRecorded {
+    pub agent: Pubkey,
+    pub oracle: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct EarningAttested {
+    pub child: Pubkey,
+    pub parent: Pubkey,
+    pub attested_total: u64,
+}
+
+#[event]
+pub struct BulkEarningsRecorded {
+    pub agent: Pubkey,
+    pub count: u8,
+    pub total: u64,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct EarningRecorded {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+    pub epoch_earned: u64,
+    pub nonce: u64,
+    pub category: EarningCategory,
+    pub bucket: AccountingBucket,
+    pub seq: u64,
+}
+
+#[event]
+pub struct LeaderboardChanged {
+    pub entered: Pubkey,
+    /// Default key when the entrant took an empty slot
+    pub evicted: Pubkey,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct EarningGoalSet {
+    pub agent: Pubkey,
+    pub earning_goal: u64,
+}
+
+#[event]
+pub struct MilestoneUnlocked {
+    pub agent: Pubkey,
+    /// `MILESTONE_*` bit index
+    pub milestone: u8,
+}
+
+#[event]
+pub struct GoalReached {
+    pub agent: Pubkey,
+    pub earning_goal: u64,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct TokenEarningRecorded {
+    pub agent: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct SubtreeEarningRolledUp {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RevenueDistributed {
+    pub child: Pubkey,
+    pub parent: Pubkey,
+    pub amount: u64,
+    /// Part of the split's base kept by `child` (rounding dust included)
+    pub remainder: u64,
+    pub total_distributed: u64,
+    pub total_received_by_parent: u64,
+    pub direct_to_root: bool,
+    pub bucket: AccountingBucket,
+    pub seq: u64,
+}
+
+#[event]
+pub struct DirectSplitPayment {
+    pub payer: Pubkey,
+    pub child: Pubkey,
+    /// Default key for root agents
+    pub parent: Pubkey,
+    pub amount: u64,
+    pub parent_cut: u64,
+}
+
+#[event]
+pub struct SecondaryRevenueDistributed {
+    pub child: Pubkey,
+    pub secondary: Pubkey,
+    pub amount: u64,
+    pub total_distributed: u64,
+    pub bucket: AccountingBucket,
+}
+
+#[event]
+pub struct ForwardShareSet {
+    pub agent: Pubkey,
+    pub forward_share_bps: u16,
+}
+
+#[event]
+pub struct DistributionLimitsSet {
+    pub agent: Pubkey,
+    pub max_single_distribution: u64,
+    pub max_daily_distribution: u64,
+}
+
+#[event]
+pub struct WithdrawWhitelistEnforcementSet {
+    pub enforce: bool,
+}
+
+#[event]
+pub struct WithdrawWhitelistSet {
+    pub agent: Pubkey,
+    pub whitelist: [Pubkey; MAX_WHITELIST_LEN],
+}
+
+#[event]
+pub struct SplitDistributed {
+    pub agent: Pubkey,
+    pub recipient: Pubkey,
+    pub bps: u16,
+    pub amount: u64,
+    pub bucket: AccountingBucket,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WaterfallHop {
+    pub agent: Pubkey,
+    /// Amount passed up to this agent
+    pub received: u64,
+    /// Amount this agent keeps after passing its own share upward
+    pub retained: u64,
+}
+
+#[event]
+pub struct SkippedInactiveAncestor {
+    pub child: Pubkey,
+    pub skipped: Pubkey,
+    pub amount: u64,
+    /// Active ancestor that received the portion; `None` if it stayed with the child
+    pub routed_to: Option<Pubkey>,
+}
+
+#[event]
+pub struct ChildFunded {
+    pub parent: Pubkey,
+    pub child: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ChainDistributed {
+    pub child: Pubkey,
+    pub amount: u64,
+    /// Child's own share: `amount` minus what it passed to its parent
+    pub remainder: u64,
+    pub distributed: u64,
+    pub returned_to_child: u64,
+}
+
+#[event]
+pub struct WaterfallReport {
+    pub leaf: Pubkey,
+    pub amount: u64,
+    pub leaf_residual: u64,
+    pub hops: Vec<WaterfallHop>,
+}
+
+#[event]
+pub struct PositionReport {
+    pub agent: Pubkey,
+    pub total_earned: u64,
+    pub total_distributed_to_parent: u64,
+    pub total_received_from_children: u64,
+    pub net_position: i128,
+}
+
+#[event]
+pub struct AgentDeactivated {
+    pub agent: Pubkey,
+    pub wallet: Pubkey,
+    pub seq: u64,
+}
+
+#[event]
+pub struct AgentFrozen {
+    pub agent: Pubkey,
+    pub frozen_by: Pubkey,
+}
+
+#[event]
+pub struct AgentFinalized {
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct AgentLocked {
+    pub agent: Pubkey,
+    pub locked_until: i64,
+}
+
+#[event]
+pub struct AgentUnlocked {
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct TagChanged {
+    pub agent: Pubkey,
+    pub old_tag: [u8; 16],
+    pub new_tag: [u8; 16],
+}
+
+#[event]
+pub struct AgentUriUpdated {
+    pub agent: Pubkey,
+    pub uri: String,
+    pub old_content_hash: [u8; 32],
+    pub new_content_hash: [u8; 32],
+}
+
+#[event]
+pub struct AgentReactivated {
+    pub agent: Pubkey,
+    pub wallet: Pubkey,
+    /// Gated actions are blocked until this timestamp
+    pub quarantine_until: i64,
+}
+
+#[event]
+pub struct AgentReparented {
+    pub child: Pubkey,
+    pub old_parent: Pubkey,
+    pub new_parent: Pubkey,
+    pub new_depth: u8,
+}
+
+#[event]
+pub struct AgentPromotedToRoot {
+    pub agent: Pubkey,
+    pub old_parent: Pubkey,
+    pub old_depth: u8,
+}
+
+#[event]
+pub struct AgentDepthRecomputed {
+    pub agent: Pubkey,
+    pub old_depth: u8,
+    pub new_depth: u8,
+}
+
+#[event]
+pub struct AgentTtlExtended {
+    pub agent: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct AgentExpired {
+    pub agent: Pubkey,
+    pub wallet: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct AgentHeartbeat {
+    pub agent: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AgentMarkedStale {
+    pub agent: Pubkey,
+    pub last_active_at: i64,
+}
+
+#[event]
+pub struct ReferralRegistered {
+    pub payer: Pubkey,
+    pub referring_agent: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct ReferralPaid {
+    pub payer: Pubkey,
+    pub referring_agent: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub total_referral_earnings: u64,
+}
+
+#[event]
+pub struct BondPosted {
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BondWithdrawn {
+    pub agent: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EarningAdjusted {
+    pub agent: Pubkey,
+    pub delta: i64,
+    pub category: EarningCategory,
+    pub reason_code: u8,
+    pub old_total: u64,
+    pub new_total: u64,
+    pub adjustment_count: u32,
+}
+
+#[event]
+pub struct BondSlashed {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+    pub reason: String,
+}
+
+#[event]
+pub struct DustSwept {
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AgentMigrated {
+    pub agent: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct SpecCountChanged {
+    pub spec_hash: [u8; 32],
+    pub count: u64,
+}
+
+#[event]
+pub struct AgentClosed {
+    pub agent: Pubkey,
+    pub wallet: Pubkey,
+    pub rent_destination: Pubkey,
+}
+
+#[event]
+pub struct AgentsMerged {
+    pub source: Pubkey,
+    pub target: Pubkey,
+    pub merged_earned: u64,
+    pub moved_lamports: u64,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct PruneCompleted {
+    pub pruned: u32,
+}
+
+#[event]
+pub struct BountyPosted {
+    pub bounty: Pubkey,
+    pub poster_agent: Pubkey,
+    pub bounty_id: u64,
+    pub amount: u64,
+    pub assignee: Option<Pubkey>,
+}
+
+#[event]
+pub struct BountyClaimed {
+    pub bounty: Pubkey,
+    pub claimant_agent: Pubkey,
+}
+
+#[event]
+pub struct BountyPaid {
+    pub bounty: Pubkey,
+    pub poster_agent: Pubkey,
+    pub claimant_agent: Pubkey,
+    pub amount: u64,
+    pub bucket: AccountingBucket,
+}
+
+/// Source of a recorded earning; indexes `earned_by_category`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EarningCategory {
+    TaskCompletion,
+    Referral,
+    Subscription,
+    Other,
+}
+
+/// Global stats total an event's amount was counted in.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountingBucket {
+    /// `GlobalStats.total_reported_earnings`
+    Reported,
+    /// `GlobalStats.total_transferred_volume`
+    Transferred,
+}
+
+#[event]
+pub struct ChildrenCountReconciled {
+    pub agent: Pubkey,
+    pub old_count: u64,
+    pub new_count: u64,
 }
 
-// ============================================================================
-// Events
-// ============================================================================
+#[event]
+pub struct StatsSplit {
+    pub total_agents: u64,
+    pub total_reported_earnings: u64,
+    pub total_spawns: u64,
+    pub total_transferred_volume: u64,
+}
 
 #[event]
-pub struct AgentRegistered {
+pub struct DistributionCommitted {
     pub agent: Pubkey,
-    pub wallet: Pubkey,
-    pub parent: Pubkey,
-    pub name: String,
-    pub specialization: String,
-    pub depth: u8,
+    pub min_amount: u64,
+    pub interval_secs: i64,
 }
 
 #[event]
-pub struct AgentSpawned {
-    pub child: Pubkey,
-    pub parent: Pubkey,
-    pub child_wallet: Pubkey,
-    pub name: String,
-    pub specialization: String,
-    pub depth: u8,
-    pub revenue_share_bps: u16,
+pub struct CommitmentMissed {
+    pub agent: Pubkey,
+    pub missed_count: u32,
+    /// When the missed interval ended
+    pub due_at: i64,
 }
 
 #[event]
-pub struct EarningRecorded {
+pub struct DistributionCommitmentRevoked {
     pub agent: Pubkey,
-    pub amount: u64,
-    pub total_earned: u64,
+    pub missed_count: u32,
 }
 
 #[event]
-pub struct RevenueDistributed {
-    pub child: Pubkey,
-    pub parent: Pubkey,
-    pub amount: u64,
-    pub total_distributed: u64,
+pub struct SnapshotTaken {
+    pub hash: [u8; 32],
+    pub slot: u64,
+    pub total_agents: u64,
+    pub total_reported_earnings: u64,
+    pub total_spawns: u64,
+    /// Sequence number of this snapshot in the log
+    pub index: u64,
 }
 
 #[event]
-pub struct AgentDeactivated {
-    pub agent: Pubkey,
-    pub wallet: Pubkey,
+pub struct ReconciliationReport {
+    pub total_reported_earnings: u64,
+    pub total_transferred_volume: u64,
+    /// Reported minus transferred
+    pub difference: i128,
+}
+
+#[event]
+pub struct RegistryMigrated {
+    pub from_size: u32,
+    pub to_size: u32,
+}
+
+#[event]
+pub struct BountyClaimRejected {
+    pub bounty: Pubkey,
+    pub poster_agent: Pubkey,
+    pub claimant_agent: Pubkey,
+}
+
+#[event]
+pub struct BountyCancelled {
+    pub bounty: Pubkey,
+    pub poster_agent: Pubkey,
+    pub amount: u64,
 }
 
 // ============================================================================
@@ -414,4 +9618,1474 @@ pub enum HydraError {
     ZeroAmount,
     #[msg("Agent has no parent")]
     NoParentAgent,
+    #[msg("Parent forwards a share but grandparent accounts were not supplied")]
+    MissingGrandparentAccounts,
+    #[msg("Grandparent accounts do not match the parent's lineage")]
+    InvalidGrandparent,
+    #[msg("Agent name contains a disallowed character")]
+    InvalidNameChar,
+    #[msg("Too many split recipients")]
+    TooManySplitRecipients,
+    #[msg("Split recipient account does not match the split config")]
+    InvalidSplitRecipient,
+    #[msg("Max depth must be between 1 and the program ceiling")]
+    InvalidMaxDepth,
+    #[msg("Config value is out of range")]
+    InvalidConfigValue,
+    #[msg("Prune accounts must be (agent, rent destination, parent) triples matching each agent")]
+    InvalidPruneAccounts,
+    #[msg("Parent agent account does not match the agent's parent")]
+    ParentMismatch,
+    #[msg("Destination is not in the agent's withdrawal whitelist")]
+    DestinationNotWhitelisted,
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+    #[msg("Registry requires a bond but no bond account was supplied")]
+    MissingBondAccount,
+    #[msg("Bond can only be withdrawn after voluntary deactivation")]
+    BondLocked,
+    #[msg("Bond withdrawal cooldown has not elapsed")]
+    BondCooldownActive,
+    #[msg("Reason exceeds maximum length")]
+    ReasonTooLong,
+    #[msg("Agent has a recent heartbeat and is not stale")]
+    AgentNotStale,
+    #[msg("Earning nonce must be greater than the last recorded nonce")]
+    DuplicateNonce,
+    #[msg("Agent chain must link leaf to root through parent accounts")]
+    InvalidChain,
+    #[msg("Account is not a Hydra agent account")]
+    InvalidAgentAccount,
+    #[msg("Parent agent account is required")]
+    MissingParentAccount,
+    #[msg("An ancestor agent is not active")]
+    AncestorInactive,
+    #[msg("No lamports above rent exemption to sweep")]
+    NothingToSweep,
+    #[msg("Earning exceeds the registry's single-earning limit")]
+    EarningExceedsLimit,
+    #[msg("Account is not a root agent")]
+    NotRootAgent,
+    #[msg("Account is not the agent's secondary beneficiary")]
+    InvalidSecondaryBeneficiary,
+    #[msg("Child and parent wallets must differ")]
+    SelfDistribution,
+    #[msg("Agent index must be the wallet's next unused index")]
+    InvalidAgentIndex,
+    #[msg("An agent cannot claim its own bounty")]
+    SelfClaim,
+    #[msg("Bounty is assigned to a different agent")]
+    NotBountyAssignee,
+    #[msg("Bounty has not been claimed")]
+    BountyNotClaimed,
+    #[msg("Bounty has already been claimed")]
+    BountyAlreadyClaimed,
+    #[msg("Bounty has already been paid")]
+    BountyAlreadyPaid,
+    #[msg("Bounty has already been cancelled")]
+    BountyAlreadyCancelled,
+    #[msg("Parent's depth level is not yet filled to the branching factor")]
+    TreeNotBalanced,
+    #[msg("Authority proposal has expired")]
+    ProposalExpired,
+    #[msg("No authority handoff is pending")]
+    NoPendingAuthority,
+    #[msg("Instruction may not be invoked via CPI")]
+    CpiNotAllowed,
+    #[msg("Expiry must be in the future and later than the current one")]
+    InvalidExpiry,
+    #[msg("Agent has not expired")]
+    AgentNotExpired,
+    #[msg("Parent and child wallets must differ")]
+    SelfSpawn,
+    #[msg("New parent would create a cycle, repeat the old parent, or shift the child's subtree depth")]
+    InvalidReparent,
+    #[msg("Agent has not been active long enough to deactivate")]
+    TooSoonToDeactivate,
+    #[msg("Amounts and agent accounts differ in length")]
+    LengthMismatch,
+    #[msg("Batch must hold between 1 and MAX_BATCH_LEN entries")]
+    InvalidBatchSize,
+    #[msg("Unknown PDA kind")]
+    InvalidPdaKind,
+    #[msg("Agent is already active")]
+    AgentAlreadyActive,
+    #[msg("Agent is in its reactivation quarantine")]
+    AgentInQuarantine,
+    #[msg("Too many accounts for the registry's batch limit")]
+    BatchTooLarge,
+    #[msg("Parent wallet must be owned by the System Program")]
+    InvalidParentWalletOwner,
+    #[msg("Agent is locked")]
+    AgentLocked,
+    #[msg("Lock must end in the future and not before the current lock")]
+    InvalidLockTime,
+    #[msg("Agent is not locked")]
+    AgentNotLocked,
+    #[msg("Memo program account required when memos are enabled")]
+    MissingMemoProgram,
+    #[msg("Parent is still active; pass force to promote anyway")]
+    ParentStillActive,
+    #[msg("Referring agent accounts required for an active referral")]
+    MissingReferralAccounts,
+    #[msg("Referring agent does not match the referral")]
+    ReferralMismatch,
+    #[msg("Parent wallet does not hold the spawn license")]
+    SpawnLicenseMissing,
+    #[msg("Children's combined revenue share would exceed 10000 bps")]
+    SiblingShareExceeded,
+    #[msg("URI too long")]
+    UriTooLong,
+    #[msg("Snapshot taken too soon after the previous one")]
+    SnapshotTooSoon,
+    #[msg("Attested total exceeds the child's recorded earnings")]
+    AttestationExceedsEarnings,
+    #[msg("Attestation does not reference the child's latest earning nonce")]
+    StaleAttestation,
+    #[msg("Adjustment would take total earnings below zero")]
+    AdjustmentUnderflow,
+    #[msg("Agent was adjusted too recently")]
+    AdjustmentCooldown,
+    #[msg("Spawn template has no uses left")]
+    TemplateExhausted,
+    #[msg("Account is not a child of this parent")]
+    NotAChild,
+    #[msg("No active child with a nonzero revenue share to fund")]
+    NoFundableChildren,
+    #[msg("Agent is finalized and its configuration cannot change")]
+    AgentImmutable,
+    #[msg("Agent is frozen")]
+    AgentFrozen,
+    #[msg("Child agent is inactive")]
+    ChildInactive,
+    #[msg("Parent agent is inactive")]
+    ParentInactive,
+    #[msg("Namespace too long")]
+    NamespaceTooLong,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Earnings may only be recorded by an approved oracle")]
+    OracleRequired,
+    #[msg("Distribution commitment interval has not elapsed")]
+    CommitmentNotDue,
+    #[msg("Child wallet is already an ancestor's wallet")]
+    AncestorWalletReuse,
+    #[msg("Distribution interval has not elapsed since the agent's last distribution")]
+    DistributionTooSoon,
+    #[msg("Name is reserved by another wallet")]
+    NameReserved,
+    #[msg("Task hash was already recorded")]
+    DuplicateTask,
+    #[msg("Task log account is required when task hashes are given")]
+    MissingTaskLog,
+    #[msg("Distribution exceeds the child's single or daily distribution limit")]
+    DistributionLimitExceeded,
+    #[msg("Agent has children")]
+    HasActiveChildren,
+    #[msg("Agent status cannot move to the requested state")]
+    InvalidStatusTransition,
+    #[msg("Agent belongs to a different namespace")]
+    NamespaceMismatch,
+    #[msg("Agent has subtree earnings not yet rolled up to its parent")]
+    PendingRollUp,
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An account of type `T` read from zeroed data, as freshly allocated
+    /// space deserializes before any field is set.
+    fn zeroed<T: AccountDeserialize + Discriminator>(size: usize) -> T {
+        let mut data = vec![0u8; size];
+        data[..8].copy_from_slice(T::DISCRIMINATOR);
+        T::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    fn agent() -> AgentAccount {
+        zeroed(AgentAccount::SIZE)
+    }
+
+    fn assert_err<T: std::fmt::Debug, E: Into<u32> + std::fmt::Debug + Copy>(result: Result<T>, expected: E) {
+        match result {
+            Err(Error::AnchorError(e)) => assert_eq!(e.error_code_number, expected.into()),
+            other => panic!("expected {expected:?}, got {other:?}"),
+        }
+    }
+
+    /// Backing storage for one `AccountInfo` handed to `try_accounts`.
+    #[derive(Clone)]
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        data: Vec<u8>,
+        signer: bool,
+        executable: bool,
+    }
+
+    fn program_account<T: AccountSerialize>(key: Pubkey, account: &T, size: usize) -> TestAccount {
+        let mut data = Vec::with_capacity(size);
+        account.try_serialize(&mut data).unwrap();
+        data.resize(size, 0);
+        TestAccount {
+            key,
+            owner: crate::ID,
+            data,
+            signer: false,
+            executable: false,
+        }
+    }
+
+    fn signer(key: Pubkey) -> TestAccount {
+        TestAccount {
+            key,
+            owner: system_program::ID,
+            data: Vec::new(),
+            signer: true,
+            executable: false,
+        }
+    }
+
+    /// An omitted optional account, which Anchor reads as the program id.
+    fn absent() -> TestAccount {
+        TestAccount {
+            key: crate::ID,
+            owner: crate::ID,
+            data: Vec::new(),
+            signer: false,
+            executable: false,
+        }
+    }
+
+    fn system_program_account() -> TestAccount {
+        TestAccount {
+            key: system_program::ID,
+            owner: Pubkey::default(),
+            data: Vec::new(),
+            signer: false,
+            executable: true,
+        }
+    }
+
+    /// Leak `account` into an `AccountInfo`, which borrows its storage.
+    fn account_info(account: TestAccount) -> AccountInfo<'static> {
+        let TestAccount {
+            key,
+            owner,
+            data,
+            signer,
+            executable,
+        } = Box::leak(Box::new(account));
+        let lamports = Box::leak(Box::new(1_000_000_000u64));
+        AccountInfo::new(key, *signer, true, lamports, data, owner, *executable, 0)
+    }
+
+    /// Run a context's account validation over `accounts`, in field order.
+    fn try_accounts<T, B>(accounts: Vec<TestAccount>, ix_args: &impl AnchorSerialize) -> Result<T>
+    where
+        T: Accounts<'static, B>,
+        B: Default,
+    {
+        let infos: Vec<AccountInfo<'static>> = accounts.into_iter().map(account_info).collect();
+        let mut infos: &'static [AccountInfo<'static>] = infos.leak();
+        let mut ix_data = Vec::new();
+        ix_args.serialize(&mut ix_data).unwrap();
+        T::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &ix_data,
+            &mut B::default(),
+            &mut std::collections::BTreeSet::new(),
+        )
+    }
+
+    const NAMESPACE: &str = "alpha";
+
+    fn registry_account(namespace: &str) -> TestAccount {
+        registry_account_with_authority(namespace, Pubkey::default())
+    }
+
+    fn registry_account_with_authority(namespace: &str, authority: Pubkey) -> TestAccount {
+        let (key, bump) = Registry::find_namespaced_address(namespace, &crate::ID);
+        let mut registry: Registry = zeroed(Registry::SIZE);
+        registry.authority = authority;
+        registry.bump = bump;
+        registry.namespace = namespace.to_string();
+        program_account(key, &registry, Registry::SIZE)
+    }
+
+    fn stats_account(namespace: &str) -> TestAccount {
+        let (key, bump) = GlobalStats::find_address(namespace, &crate::ID);
+        let mut stats: GlobalStats = zeroed(GlobalStats::SIZE);
+        stats.bump = bump;
+        program_account(key, &stats, GlobalStats::SIZE)
+    }
+
+    /// An agent for `wallet` at its helper-derived address; indexed when
+    /// `agent_index` is set.
+    fn agent_account(namespace: &str, wallet: &Pubkey, agent_index: Option<u32>) -> TestAccount {
+        agent_account_with(namespace, wallet, agent_index, |_| {})
+    }
+
+    /// `agent_account`, with `edit` applied to the agent before it is serialized.
+    fn agent_account_with(
+        namespace: &str,
+        wallet: &Pubkey,
+        agent_index: Option<u32>,
+        edit: impl FnOnce(&mut AgentAccount),
+    ) -> TestAccount {
+        let (key, bump) = match agent_index {
+            Some(index) => AgentAccount::find_indexed_address(namespace, wallet, index, &crate::ID),
+            None => AgentAccount::find_address(namespace, wallet, &crate::ID),
+        };
+        let mut agent = agent();
+        agent.wallet = *wallet;
+        agent.bump = bump;
+        agent.is_indexed = agent_index.is_some();
+        agent.agent_index = agent_index.unwrap_or_default();
+        edit(&mut agent);
+        program_account(key, &agent, AgentAccount::SIZE)
+    }
+
+    #[test]
+    fn earning_nonce_rejects_repeat() {
+        let mut agent = agent();
+        agent.accept_earning_nonce(5).unwrap();
+        assert_err(agent.accept_earning_nonce(5), HydraError::DuplicateNonce);
+        assert_eq!(agent.last_earning_nonce, 5);
+    }
+
+    #[test]
+    fn earning_nonce_rejects_lower() {
+        let mut agent = agent();
+        agent.accept_earning_nonce(5).unwrap();
+        assert_err(agent.accept_earning_nonce(4), HydraError::DuplicateNonce);
+        agent.accept_earning_nonce(6).unwrap();
+        assert_eq!(agent.last_earning_nonce, 6);
+    }
+
+    #[test]
+    fn zero_earning_nonce_skips_check() {
+        let mut agent = agent();
+        agent.accept_earning_nonce(5).unwrap();
+        agent.accept_earning_nonce(0).unwrap();
+        agent.accept_earning_nonce(0).unwrap();
+        assert_eq!(agent.last_earning_nonce, 5);
+    }
+
+    #[test]
+    fn record_earning_accepts_helper_addresses() {
+        for agent_index in [None, Some(3)] {
+            let wallet = Pubkey::new_unique();
+            let (leaderboard_key, bump) = Leaderboard::find_address(NAMESPACE, &crate::ID);
+            let mut leaderboard: Leaderboard = zeroed(Leaderboard::SIZE);
+            leaderboard.bump = bump;
+            let accounts = vec![
+                registry_account(NAMESPACE),
+                stats_account(NAMESPACE),
+                agent_account(NAMESPACE, &wallet, agent_index),
+                signer(wallet),
+                absent(),
+                program_account(leaderboard_key, &leaderboard, Leaderboard::SIZE),
+                absent(),
+            ];
+            try_accounts::<RecordEarning, RecordEarningBumps>(accounts, &NAMESPACE).unwrap();
+        }
+    }
+
+    #[test]
+    fn record_earning_rejects_agent_from_other_namespace() {
+        let wallet = Pubkey::new_unique();
+        let accounts = vec![
+            registry_account(NAMESPACE),
+            stats_account(NAMESPACE),
+            agent_account("beta", &wallet, None),
+            signer(wallet),
+            absent(),
+            absent(),
+            absent(),
+        ];
+        assert_err(
+            try_accounts::<RecordEarning, RecordEarningBumps>(accounts, &NAMESPACE).map(|_| ()),
+            ErrorCode::ConstraintSeeds,
+        );
+    }
+
+    #[test]
+    fn remove_oracle_accepts_helper_address() {
+        let authority = Pubkey::new_unique();
+        let oracle = Pubkey::new_unique();
+        let (record_key, bump) = OracleRecord::find_address(&oracle, NAMESPACE, &crate::ID);
+        let mut record: OracleRecord = zeroed(OracleRecord::SIZE);
+        record.oracle = oracle;
+        record.bump = bump;
+        let accounts = vec![
+            registry_account_with_authority(NAMESPACE, authority),
+            program_account(record_key, &record, OracleRecord::SIZE),
+            signer(authority),
+        ];
+        try_accounts::<RemoveOracle, RemoveOracleBumps>(accounts, &(NAMESPACE, oracle)).unwrap();
+    }
+
+    #[test]
+    fn release_reservation_accepts_helper_address() {
+        let reserver = Pubkey::new_unique();
+        let name = "scout";
+        let (key, bump) = NameReservation::find_address(name, NAMESPACE, &crate::ID);
+        let mut reservation: NameReservation = zeroed(NameReservation::SIZE);
+        reservation.reserver = reserver;
+        reservation.bump = bump;
+        let accounts = vec![
+            stats_account(NAMESPACE),
+            program_account(key, &reservation, NameReservation::SIZE),
+            signer(reserver),
+            signer(reserver),
+        ];
+        try_accounts::<ReleaseReservation, ReleaseReservationBumps>(accounts, &(NAMESPACE, name)).unwrap();
+    }
+
+    #[test]
+    fn withdraw_bond_accepts_helper_address() {
+        let wallet = Pubkey::new_unique();
+        let agent = agent_account(NAMESPACE, &wallet, None);
+        let (key, bump) = Bond::find_address(&agent.key, &crate::ID);
+        let mut bond: Bond = zeroed(Bond::SIZE);
+        bond.agent = agent.key;
+        bond.bump = bump;
+        let accounts = vec![
+            registry_account(NAMESPACE),
+            stats_account(NAMESPACE),
+            agent,
+            program_account(key, &bond, Bond::SIZE),
+            signer(wallet),
+        ];
+        try_accounts::<WithdrawBond, WithdrawBondBumps>(accounts, &NAMESPACE).unwrap();
+    }
+
+    #[test]
+    fn distribute_split_accepts_helper_address() {
+        let wallet = Pubkey::new_unique();
+        let agent = agent_account(NAMESPACE, &wallet, Some(1));
+        let (key, bump) = SplitConfig::find_address(&agent.key, &crate::ID);
+        let mut split: SplitConfig = zeroed(SplitConfig::SIZE);
+        split.agent = agent.key;
+        split.bump = bump;
+        let accounts = vec![
+            registry_account(NAMESPACE),
+            stats_account(NAMESPACE),
+            agent,
+            program_account(key, &split, SplitConfig::SIZE),
+            signer(wallet),
+            system_program_account(),
+        ];
+        try_accounts::<DistributeSplit, DistributeSplitBumps>(accounts, &NAMESPACE).unwrap();
+    }
+
+    #[test]
+    fn close_spawn_template_accepts_helper_address() {
+        let wallet = Pubkey::new_unique();
+        let parent = agent_account(NAMESPACE, &wallet, None);
+        let (key, bump) = SpawnTemplate::find_address(&parent.key, 7, &crate::ID);
+        let mut template: SpawnTemplate = zeroed(SpawnTemplate::SIZE);
+        template.parent = parent.key;
+        template.template_id = 7;
+        template.bump = bump;
+        let accounts = vec![
+            parent,
+            program_account(key, &template, SpawnTemplate::SIZE),
+            signer(wallet),
+        ];
+        try_accounts::<CloseSpawnTemplate, CloseSpawnTemplateBumps>(accounts, &(NAMESPACE, 7u32)).unwrap();
+    }
+
+    #[test]
+    fn claim_bounty_accepts_helper_address() {
+        let claimant = Pubkey::new_unique();
+        let poster_agent = Pubkey::new_unique();
+        let (key, bump) = Bounty::find_address(&poster_agent, 9, &crate::ID);
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.poster_agent = poster_agent;
+        bounty.bounty_id = 9;
+        bounty.bump = bump;
+        let accounts = vec![
+            stats_account(NAMESPACE),
+            agent_account(NAMESPACE, &claimant, None),
+            program_account(key, &bounty, Bounty::SIZE),
+            signer(claimant),
+        ];
+        try_accounts::<ClaimBounty, ClaimBountyBumps>(accounts, &NAMESPACE).unwrap();
+    }
+
+    #[test]
+    fn revoke_commitment_accepts_helper_address() {
+        let child_wallet = Pubkey::new_unique();
+        let parent_wallet = Pubkey::new_unique();
+        let parent = agent_account(NAMESPACE, &parent_wallet, None);
+        let mut child = agent_account(NAMESPACE, &child_wallet, None);
+        let mut child_state = AgentAccount::try_deserialize(&mut &child.data[..]).unwrap();
+        child_state.parent = parent.key;
+        child = program_account(child.key, &child_state, AgentAccount::SIZE);
+        let (key, bump) = DistributionCommitment::find_address(&child.key, &crate::ID);
+        let mut commitment: DistributionCommitment = zeroed(DistributionCommitment::SIZE);
+        commitment.agent = child.key;
+        commitment.bump = bump;
+        let accounts = vec![
+            stats_account(NAMESPACE),
+            child,
+            parent,
+            program_account(key, &commitment, DistributionCommitment::SIZE),
+            signer(child_wallet),
+            signer(parent_wallet),
+        ];
+        try_accounts::<RevokeDistributionCommitment, RevokeDistributionCommitmentBumps>(accounts, &NAMESPACE)
+            .unwrap();
+    }
+
+    /// Split a `seeds = [...]` body into its top-level expressions.
+    fn split_seeds(body: &str) -> Vec<String> {
+        let mut seeds = Vec::new();
+        let (mut depth, mut current) = (0, String::new());
+        for c in body.chars() {
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    seeds.push(current.trim().to_string());
+                    current.clear();
+                    continue;
+                }
+                _ => {}
+            }
+            current.push(c);
+        }
+        if !current.trim().is_empty() {
+            seeds.push(current.trim().to_string());
+        }
+        seeds
+    }
+
+    /// Contexts that init their accounts can't be run off-chain, so every
+    /// `seeds` constraint is also checked against the helper its `Derivation`
+    /// comment names: same prefix, seed count and namespace position.
+    #[test]
+    fn context_seeds_match_derivation_helpers() {
+        // (helper, prefix, allowed seed counts, namespace position)
+        type Shape = (&'static str, &'static [u8], &'static [usize], Option<usize>);
+        let helpers: &[Shape] = &[
+            ("Registry::find_address", Registry::SEED, &[1], None),
+            ("Registry::find_namespaced_address", Registry::SEED, &[2], Some(1)),
+            ("GlobalStats::find_address", GlobalStats::SEED, &[2], Some(1)),
+            ("SnapshotLog::find_address", SnapshotLog::SEED, &[2], Some(1)),
+            ("Leaderboard::find_address", Leaderboard::SEED, &[2], Some(1)),
+            ("OracleRecord::find_address", OracleRecord::SEED, &[3], Some(2)),
+            // Existing agents append `index_seed()`, which is empty unless indexed.
+            ("AgentAccount::find_address", AgentAccount::SEED, &[3, 4], Some(1)),
+            ("AgentAccount::find_indexed_address", AgentAccount::SEED, &[4], Some(1)),
+            ("WalletAgentCounter::find_address", WalletAgentCounter::SEED, &[3], Some(2)),
+            ("SpecCounter::find_address", SpecCounter::SEED, &[3], Some(2)),
+            ("NameReservation::find_address", NameReservation::SEED, &[3], Some(2)),
+            ("Referral::find_address", Referral::SEED, &[3], Some(2)),
+            ("SpawnTemplate::find_address", SpawnTemplate::SEED, &[3], None),
+            ("TaskLog::find_address", TaskLog::SEED, &[2], None),
+            ("SplitConfig::find_address", SplitConfig::SEED, &[2], None),
+            ("Bond::find_address", Bond::SEED, &[2], None),
+            ("TokenEarnings::find_address", TokenEarnings::SEED, &[3], None),
+            ("Bounty::find_address", Bounty::SEED, &[3], None),
+            ("DistributionCommitment::find_address", DistributionCommitment::SEED, &[2], None),
+            ("find_fee_vault_address", FEE_VAULT_SEED, &[1], None),
+        ];
+
+        let source = include_str!("lib.rs");
+        let lines: Vec<&str> = source.lines().take_while(|line| *line != "#[cfg(test)]").collect();
+        let mut checked = 0;
+        for (i, line) in lines.iter().enumerate() {
+            let Some(helper) = line.trim().strip_prefix("// Derivation: ") else {
+                continue;
+            };
+            let seeds_line = lines[i + 1].trim();
+            let body = if seeds_line == "seeds = [" {
+                let end = (i + 2..lines.len()).find(|&j| lines[j].trim() == "],").unwrap();
+                lines[i + 2..end].join(" ")
+            } else {
+                seeds_line
+                    .strip_prefix("seeds = [")
+                    .and_then(|rest| rest.strip_suffix("],"))
+                    .unwrap_or_else(|| panic!("line {}: no seeds after derivation comment", i + 2))
+                    .to_string()
+            };
+            let seeds = split_seeds(&body);
+
+            let &(_, prefix, counts, namespace_at) = helpers
+                .iter()
+                .find(|(name, ..)| *name == helper)
+                .unwrap_or_else(|| panic!("line {}: unknown helper {helper}", i + 1));
+            let literal = match seeds[0].as_str() {
+                "FEE_VAULT_SEED" => FEE_VAULT_SEED,
+                first => first.strip_prefix("b\"").and_then(|s| s.strip_suffix('"')).unwrap().as_bytes(),
+            };
+            assert_eq!(literal, prefix, "line {}: prefix differs from {helper}", i + 1);
+            assert!(counts.contains(&seeds.len()), "line {}: seed count differs from {helper}", i + 1);
+            // Agent PDAs take the fixed-width namespace seed; everywhere else the
+            // namespace is the last variable-length seed and goes in as is
+            let namespace_seed = if prefix == AgentAccount::SEED {
+                "&AgentAccount::namespace_seed(&namespace)[..]"
+            } else {
+                "namespace.as_bytes()"
+            };
+            for (at, seed) in seeds.iter().enumerate() {
+                assert_eq!(
+                    seed.ends_with(namespace_seed),
+                    Some(at) == namespace_at,
+                    "line {}: namespace position differs from {helper}",
+                    i + 1
+                );
+            }
+            checked += 1;
+        }
+        let constraints = lines.iter().filter(|line| line.trim().starts_with("seeds = [")).count();
+        assert_eq!(checked, constraints);
+    }
+
+    fn derive(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::create_program_address(seeds, &crate::ID).unwrap()
+    }
+
+    /// The highest bump below `canonical` that still derives a valid address.
+    fn non_canonical_bump(seeds: &[&[u8]], canonical: u8) -> u8 {
+        (0..canonical)
+            .rev()
+            .find(|&bump| Pubkey::create_program_address(&[seeds, &[&[bump]]].concat(), &crate::ID).is_ok())
+            .unwrap()
+    }
+
+    #[test]
+    fn seed_builders_match_find_address() {
+        let wallet = Pubkey::new_unique();
+
+        let (registry, bump) = Registry::find_namespaced_address(NAMESPACE, &crate::ID);
+        assert_eq!(derive(&seeds::registry_seeds(NAMESPACE, &[bump])), registry);
+
+        let (stats, bump) = GlobalStats::find_address(NAMESPACE, &crate::ID);
+        assert_eq!(derive(&seeds::stats_seeds(NAMESPACE, &[bump])), stats);
+
+        let (agent, bump) = AgentAccount::find_address(NAMESPACE, &wallet, &crate::ID);
+        let namespace_seed = AgentAccount::namespace_seed(NAMESPACE);
+        assert_eq!(derive(&seeds::agent_seeds(&namespace_seed, &wallet, &[bump])), agent);
+
+        let (indexed, bump) = AgentAccount::find_indexed_address(NAMESPACE, &wallet, 4, &crate::ID);
+        assert_eq!(
+            derive(&seeds::indexed_agent_seeds(&namespace_seed, &wallet, &4u32.to_le_bytes(), &[bump])),
+            indexed
+        );
+
+        let (bond, bump) = Bond::find_address(&agent, &crate::ID);
+        assert_eq!(derive(&seeds::bond_seeds(&agent, &[bump])), bond);
+
+        let (vault, bump) = find_fee_vault_address(&crate::ID);
+        assert_eq!(derive(&seeds::fee_vault_seeds(&[bump])), vault);
+    }
+
+    #[test]
+    fn empty_namespace_matches_legacy_seeds() {
+        let wallet = Pubkey::new_unique();
+        assert_eq!(
+            Registry::find_namespaced_address("", &crate::ID),
+            Registry::find_address(&crate::ID)
+        );
+        assert_eq!(
+            AgentAccount::find_address("", &wallet, &crate::ID),
+            Pubkey::find_program_address(&[AgentAccount::SEED, wallet.as_ref()], &crate::ID)
+        );
+    }
+
+    #[test]
+    fn namespaced_agent_addresses_do_not_collide_across_namespaces() {
+        // With the raw namespace as a seed, "ns" + wallet + index concatenates to
+        // the same bytes as "nsaaaa" + (the rest of the wallet + index)
+        let wallet = Pubkey::new_from_array([b'a'; 32]);
+        let index = 7u32;
+        let mut shifted = [0u8; 32];
+        shifted[..28].copy_from_slice(&wallet.as_ref()[4..]);
+        shifted[28..].copy_from_slice(&index.to_le_bytes());
+        let squatter = Pubkey::new_from_array(shifted);
+        assert_eq!(
+            [b"ns".as_ref(), wallet.as_ref(), &index.to_le_bytes()].concat(),
+            [b"nsaaaa".as_ref(), squatter.as_ref()].concat()
+        );
+
+        assert_ne!(
+            AgentAccount::find_indexed_address("ns", &wallet, index, &crate::ID),
+            AgentAccount::find_address("nsaaaa", &squatter, &crate::ID)
+        );
+        assert_eq!(AgentAccount::namespace_seed("ns").len(), 32);
+        assert!(AgentAccount::namespace_seed("").is_empty());
+    }
+
+    #[test]
+    fn non_canonical_bump_derives_another_address() {
+        let wallet = Pubkey::new_unique();
+        let (agent, bump) = AgentAccount::find_address(NAMESPACE, &wallet, &crate::ID);
+        let namespace_seed = AgentAccount::namespace_seed(NAMESPACE);
+        let other = non_canonical_bump(&[AgentAccount::SEED, &namespace_seed, wallet.as_ref()], bump);
+        assert_ne!(derive(&seeds::agent_seeds(&namespace_seed, &wallet, &[other])), agent);
+
+        let (bond, bump) = Bond::find_address(&agent, &crate::ID);
+        let other = non_canonical_bump(&[Bond::SEED, agent.as_ref()], bump);
+        assert_ne!(derive(&seeds::bond_seeds(&agent, &[other])), bond);
+    }
+
+    #[test]
+    fn context_rejects_non_canonical_stored_bump() {
+        let wallet = Pubkey::new_unique();
+        let (key, bump) = AgentAccount::find_address(NAMESPACE, &wallet, &crate::ID);
+        let mut agent = agent();
+        agent.wallet = wallet;
+        agent.bump = non_canonical_bump(
+            &[AgentAccount::SEED, &AgentAccount::namespace_seed(NAMESPACE), wallet.as_ref()],
+            bump,
+        );
+        let accounts = vec![
+            registry_account(NAMESPACE),
+            stats_account(NAMESPACE),
+            program_account(key, &agent, AgentAccount::SIZE),
+            signer(wallet),
+            absent(),
+            absent(),
+            absent(),
+        ];
+        assert_err(
+            try_accounts::<RecordEarning, RecordEarningBumps>(accounts, &NAMESPACE).map(|_| ()),
+            ErrorCode::ConstraintSeeds,
+        );
+    }
+
+    #[test]
+    fn loose_agent_checked_against_namespace_and_stored_bump() {
+        let wallet = Pubkey::new_unique();
+        let (key, bump) = AgentAccount::find_indexed_address(NAMESPACE, &wallet, 2, &crate::ID);
+        let mut agent = agent();
+        agent.wallet = wallet;
+        agent.is_indexed = true;
+        agent.agent_index = 2;
+        agent.bump = bump;
+        check_agent_namespace(&key, &agent, NAMESPACE).unwrap();
+        assert_err(check_agent_namespace(&key, &agent, "beta"), HydraError::NamespaceMismatch);
+
+        agent.bump = non_canonical_bump(
+            &[AgentAccount::SEED, &AgentAccount::namespace_seed(NAMESPACE), wallet.as_ref(), &2u32.to_le_bytes()],
+            bump,
+        );
+        assert_err(check_agent_namespace(&key, &agent, NAMESPACE), HydraError::NamespaceMismatch);
+    }
+
+    #[test]
+    fn name_reservation_blocks_other_spawners_until_expiry() {
+        let reserver = Pubkey::new_unique();
+        let spawner = Pubkey::new_unique();
+        let (key, bump) = NameReservation::find_address("scout", NAMESPACE, &crate::ID);
+        let mut reservation: NameReservation = zeroed(NameReservation::SIZE);
+        reservation.reserver = reserver;
+        reservation.expires_at = 100;
+        reservation.bump = bump;
+        let info = account_info(program_account(key, &reservation, NameReservation::SIZE));
+
+        assert_err(check_name_reservation(&info, &[spawner], 99), HydraError::NameReserved);
+        check_name_reservation(&info, &[spawner, reserver], 99).unwrap();
+        check_name_reservation(&info, &[spawner], 100).unwrap();
+
+        let unreserved = account_info(TestAccount {
+            owner: system_program::ID,
+            data: Vec::new(),
+            ..program_account(key, &reservation, NameReservation::SIZE)
+        });
+        check_name_reservation(&unreserved, &[spawner], 99).unwrap();
+    }
+
+    #[test]
+    fn distribution_interval_enforced_from_last_outflow() {
+        let mut registry: Registry = zeroed(Registry::SIZE);
+        let mut agent = agent();
+        agent.last_distributed_at = 1_000;
+        check_distribution_interval(&registry, &agent, 1_000).unwrap();
+
+        registry.min_distribution_interval_secs = 60;
+        assert_err(
+            check_distribution_interval(&registry, &agent, 1_059),
+            HydraError::DistributionTooSoon,
+        );
+        check_distribution_interval(&registry, &agent, 1_060).unwrap();
+    }
+
+    #[test]
+    fn single_distribution_cap() {
+        let mut child = agent();
+        check_distribution_limits(&child, u64::MAX, 0).unwrap();
+
+        child.max_single_distribution = 500;
+        check_distribution_limits(&child, 500, 0).unwrap();
+        assert_err(
+            check_distribution_limits(&child, 501, 0),
+            HydraError::DistributionLimitExceeded,
+        );
+    }
+
+    #[test]
+    fn daily_distribution_cap_rolls_over_at_utc_midnight() {
+        let mut child = agent();
+        child.max_daily_distribution = 1_000;
+        let day = 20_000 * SECONDS_PER_DAY;
+
+        child.record_daily_distribution(700, day + 10);
+        check_distribution_limits(&child, 300, day + 20).unwrap();
+        assert_err(
+            check_distribution_limits(&child, 301, day + 20),
+            HydraError::DistributionLimitExceeded,
+        );
+
+        child.record_daily_distribution(300, day + SECONDS_PER_DAY - 1);
+        assert_eq!(child.distributed_on_day(day + SECONDS_PER_DAY - 1), 1_000);
+        assert_eq!(child.distributed_on_day(day + SECONDS_PER_DAY), 0);
+        check_distribution_limits(&child, 1_000, day + SECONDS_PER_DAY).unwrap();
+
+        child.record_daily_distribution(400, day + SECONDS_PER_DAY);
+        assert_eq!(child.daily_distributed, 400);
+        assert_eq!(child.daily_distribution_day, utc_day(day) + 1);
+    }
+
+    #[test]
+    fn bounty_status_gates_each_transition() {
+        use BountyStatus::*;
+        // (current status, status the instruction requires, error when they differ)
+        let cases = [
+            (Open, HydraError::BountyNotClaimed),
+            (Claimed, HydraError::BountyAlreadyClaimed),
+            (Paid, HydraError::BountyAlreadyPaid),
+            (Cancelled, HydraError::BountyAlreadyCancelled),
+        ];
+        for (status, error) in cases {
+            // claim_bounty and cancel_bounty require Open;
+            // approve_and_pay and reject_claim require Claimed
+            for expected in [Open, Claimed] {
+                if status == expected {
+                    status.require(expected).unwrap();
+                } else {
+                    assert_err(status.require(expected), error);
+                }
+            }
+        }
+    }
+
+    fn approve_and_pay_accounts(claimant_namespace: &str) -> Vec<TestAccount> {
+        let poster_wallet = Pubkey::new_unique();
+        let claimant_wallet = Pubkey::new_unique();
+        let poster = agent_account(NAMESPACE, &poster_wallet, None);
+        let claimant = agent_account(claimant_namespace, &claimant_wallet, None);
+        let (key, bump) = Bounty::find_address(&poster.key, 4, &crate::ID);
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.poster_agent = poster.key;
+        bounty.bounty_id = 4;
+        bounty.claimant = Some(claimant.key);
+        bounty.status = BountyStatus::Claimed;
+        bounty.bump = bump;
+        vec![
+            registry_account(NAMESPACE),
+            stats_account(NAMESPACE),
+            poster,
+            program_account(key, &bounty, Bounty::SIZE),
+            claimant,
+            TestAccount { signer: false, ..signer(claimant_wallet) },
+            signer(poster_wallet),
+            absent(),
+            absent(),
+            absent(),
+        ]
+    }
+
+    #[test]
+    fn approve_and_pay_requires_claimant_in_namespace() {
+        try_accounts::<ApproveAndPay, ApproveAndPayBumps>(approve_and_pay_accounts(NAMESPACE), &NAMESPACE)
+            .unwrap();
+        assert_err(
+            try_accounts::<ApproveAndPay, ApproveAndPayBumps>(approve_and_pay_accounts("beta"), &NAMESPACE)
+                .map(|_| ()),
+            ErrorCode::ConstraintSeeds,
+        );
+    }
+
+    #[test]
+    fn reject_claim_is_poster_only() {
+        let poster_wallet = Pubkey::new_unique();
+        let poster = agent_account(NAMESPACE, &poster_wallet, None);
+        let (key, bump) = Bounty::find_address(&poster.key, 2, &crate::ID);
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.poster_agent = poster.key;
+        bounty.bounty_id = 2;
+        bounty.status = BountyStatus::Claimed;
+        bounty.bump = bump;
+        let bounty = program_account(key, &bounty, Bounty::SIZE);
+
+        let accounts = vec![stats_account(NAMESPACE), poster.clone(), bounty.clone(), signer(poster_wallet)];
+        try_accounts::<RejectClaim, RejectClaimBumps>(accounts, &NAMESPACE).unwrap();
+
+        let intruder = Pubkey::new_unique();
+        let accounts = vec![
+            stats_account(NAMESPACE),
+            agent_account(NAMESPACE, &intruder, None),
+            bounty,
+            signer(intruder),
+        ];
+        assert_err(
+            try_accounts::<RejectClaim, RejectClaimBumps>(accounts, &NAMESPACE).map(|_| ()),
+            ErrorCode::ConstraintSeeds,
+        );
+    }
+
+    #[test]
+    fn absorb_moves_every_earning_and_distribution_total() {
+        let mut target = agent();
+        target.total_earned = 100;
+        target.earned_by_category[EarningCategory::Referral as usize] = 100;
+        target.current_epoch = 7;
+        target.epoch_earned = 40;
+        let mut source = agent();
+        source.total_earned = 50;
+        source.earned_by_category[EarningCategory::Referral as usize] = 20;
+        source.earned_by_category[EarningCategory::Other as usize] = 30;
+        source.attested_earned = 10;
+        source.subtree_earned = 5;
+        source.unrolled_earnings = 50;
+        source.total_distributed_to_parent = 8;
+        source.total_distributed_to_secondary = 3;
+        source.current_epoch = 7;
+        source.epoch_earned = 15;
+
+        target.absorb(&source);
+        assert_eq!(target.total_earned, 150);
+        assert_eq!(target.earned_by_category[EarningCategory::Referral as usize], 120);
+        assert_eq!(target.earned_by_category[EarningCategory::Other as usize], 30);
+        assert_eq!(target.attested_earned, 10);
+        assert_eq!(target.subtree_earned, 5);
+        assert_eq!(target.unrolled_earnings, 50);
+        assert_eq!(target.total_distributed_to_parent, 8);
+        assert_eq!(target.total_distributed_to_secondary, 3);
+        assert_eq!((target.current_epoch, target.epoch_earned), (7, 55));
+    }
+
+    #[test]
+    fn absorb_keeps_the_later_epoch() {
+        let mut target = agent();
+        target.current_epoch = 7;
+        target.epoch_earned = 40;
+        let mut source = agent();
+        source.current_epoch = 6;
+        source.epoch_earned = 15;
+        target.absorb(&source);
+        assert_eq!((target.current_epoch, target.epoch_earned), (7, 40));
+
+        source.current_epoch = 8;
+        target.absorb(&source);
+        assert_eq!((target.current_epoch, target.epoch_earned), (8, 15));
+    }
+
+    #[test]
+    fn leaderboard_remove_frees_the_last_slot() {
+        let mut board: Leaderboard = zeroed(Leaderboard::SIZE);
+        let agents: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for (agent, total) in agents.iter().zip([300, 200, 100]) {
+            board.update(*agent, total);
+        }
+
+        board.remove(agents[1]);
+        assert_eq!(board.entries[0].agent, agents[0]);
+        assert_eq!(board.entries[1].agent, agents[2]);
+        assert!(board.entries.iter().all(|e| e.agent != agents[1]));
+
+        board.remove(Pubkey::new_unique());
+        assert_eq!(board.entries[1].agent, agents[2]);
+    }
+
+    #[test]
+    fn utc_day_buckets_whole_days() {
+        assert_eq!(utc_day(0), 0);
+        assert_eq!(utc_day(SECONDS_PER_DAY - 1), 0);
+        assert_eq!(utc_day(SECONDS_PER_DAY), 1);
+        assert_eq!(utc_day(-1), -1);
+        assert_eq!(utc_day(-SECONDS_PER_DAY), -1);
+    }
+
+    #[test]
+    fn streak_extends_on_consecutive_days() {
+        let mut agent = agent();
+        let day = 20_000 * SECONDS_PER_DAY;
+        agent.extend_streak(day);
+        assert_eq!((agent.streak_days, agent.streak_last_day), (1, 20_000));
+
+        agent.extend_streak(day + SECONDS_PER_DAY - 1);
+        assert_eq!(agent.streak_days, 1);
+
+        agent.extend_streak(day + SECONDS_PER_DAY);
+        agent.extend_streak(day + 2 * SECONDS_PER_DAY + 5);
+        assert_eq!((agent.streak_days, agent.streak_last_day), (3, 20_002));
+    }
+
+    #[test]
+    fn streak_resets_after_a_missed_day() {
+        let mut agent = agent();
+        let day = 20_000 * SECONDS_PER_DAY;
+        agent.extend_streak(day);
+        agent.extend_streak(day + SECONDS_PER_DAY);
+        assert_eq!(agent.streak_days, 2);
+
+        agent.extend_streak(day + 3 * SECONDS_PER_DAY);
+        assert_eq!((agent.streak_days, agent.streak_last_day), (1, 20_003));
+    }
+
+    #[test]
+    fn status_transition_matrix() {
+        use AgentStatus::*;
+        let all = [Active, Deactivated, Frozen, Quarantined, Expired];
+        let legal = [
+            (Active, Deactivated),
+            (Active, Frozen),
+            (Active, Expired),
+            (Quarantined, Active),
+            (Quarantined, Deactivated),
+            (Quarantined, Frozen),
+            (Quarantined, Expired),
+            (Deactivated, Active),
+            (Deactivated, Quarantined),
+            (Deactivated, Frozen),
+            (Expired, Active),
+            (Expired, Quarantined),
+            (Expired, Deactivated),
+            (Expired, Frozen),
+        ];
+        for from in all {
+            for to in all {
+                if legal.contains(&(from, to)) {
+                    assert_eq!(transition_status(from, to).unwrap(), to, "{from:?} -> {to:?}");
+                } else {
+                    assert_err(transition_status(from, to), HydraError::InvalidStatusTransition);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn set_status_syncs_compatibility_flags() {
+        let mut agent = agent();
+        agent.set_status(AgentStatus::Active).unwrap_err();
+        agent.set_status(AgentStatus::Deactivated).unwrap();
+        assert!(!agent.is_active && !agent.is_frozen);
+        agent.set_status(AgentStatus::Quarantined).unwrap();
+        assert!(agent.is_active && !agent.is_frozen);
+        agent.set_status(AgentStatus::Frozen).unwrap();
+        assert!(!agent.is_active && agent.is_frozen);
+        assert_err(agent.set_status(AgentStatus::Active), HydraError::InvalidStatusTransition);
+        assert_eq!(agent.status, AgentStatus::Frozen);
+    }
+
+    fn distribute_to_parent_accounts(
+        child_wallet: Pubkey,
+        parent: &TestAccount,
+        parent_wallet: TestAccount,
+    ) -> Vec<TestAccount> {
+        let parent_key = parent.key;
+        vec![
+            registry_account(NAMESPACE),
+            stats_account(NAMESPACE),
+            agent_account_with(NAMESPACE, &child_wallet, None, |child| child.parent = parent_key),
+            parent.clone(),
+            signer(child_wallet),
+            parent_wallet,
+            absent(),
+            absent(),
+            system_program_account(),
+            absent(),
+            absent(),
+        ]
+    }
+
+    #[test]
+    fn distribute_to_parent_pays_only_the_recorded_parent() {
+        let parent_wallet = Pubkey::new_unique();
+        let parent = agent_account(NAMESPACE, &parent_wallet, None);
+        let wallet = || TestAccount { signer: false, ..signer(parent_wallet) };
+
+        let accounts = distribute_to_parent_accounts(Pubkey::new_unique(), &parent, wallet());
+        try_accounts::<DistributeToParent, DistributeToParentBumps>(accounts, &NAMESPACE).unwrap();
+
+        let stranger_wallet = Pubkey::new_unique();
+        let stranger = agent_account(NAMESPACE, &stranger_wallet, None);
+        let mut accounts = distribute_to_parent_accounts(Pubkey::new_unique(), &parent, wallet());
+        accounts[3] = stranger;
+        accounts[5] = TestAccount { signer: false, ..signer(stranger_wallet) };
+        assert_err(
+            try_accounts::<DistributeToParent, DistributeToParentBumps>(accounts, &NAMESPACE).map(|_| ()),
+            HydraError::ParentMismatch,
+        );
+
+        let other_wallet = TestAccount { signer: false, ..signer(Pubkey::new_unique()) };
+        let accounts = distribute_to_parent_accounts(Pubkey::new_unique(), &parent, other_wallet);
+        assert_err(
+            try_accounts::<DistributeToParent, DistributeToParentBumps>(accounts, &NAMESPACE).map(|_| ()),
+            HydraError::ParentMismatch,
+        );
+
+        let program_owned = TestAccount { owner: crate::ID, ..wallet() };
+        let accounts = distribute_to_parent_accounts(Pubkey::new_unique(), &parent, program_owned);
+        assert_err(
+            try_accounts::<DistributeToParent, DistributeToParentBumps>(accounts, &NAMESPACE).map(|_| ()),
+            HydraError::InvalidParentWalletOwner,
+        );
+    }
+
+    #[test]
+    fn spawn_rejects_the_parent_wallet_as_child_wallet() {
+        let parent_wallet = Pubkey::new_unique();
+        check_spawn_wallets(&parent_wallet, &Pubkey::new_unique()).unwrap();
+        assert_err(check_spawn_wallets(&parent_wallet, &parent_wallet), HydraError::SelfSpawn);
+    }
+
+    /// Leaked program-owned agent infos for `keys`, each the child of the next
+    /// key in `parents` (default = root).
+    fn agent_infos(keys: &[Pubkey], parents: &[Pubkey]) -> &'static [AccountInfo<'static>] {
+        let infos: Vec<AccountInfo<'static>> = keys
+            .iter()
+            .zip(parents)
+            .map(|(key, parent)| {
+                let mut agent = agent();
+                agent.parent = *parent;
+                account_info(program_account(*key, &agent, AgentAccount::SIZE))
+            })
+            .collect();
+        Box::leak(infos.into_boxed_slice())
+    }
+
+    #[test]
+    fn not_descendant_matches_ancestry_for_every_pair() {
+        // chain[0] is the root; chain[i] is the child of chain[i - 1]
+        let chain: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let parents: Vec<Pubkey> = std::iter::once(Pubkey::default())
+            .chain(chain[..5].iter().copied())
+            .collect();
+        let infos = agent_infos(&chain, &parents);
+
+        for candidate in 0..chain.len() {
+            let candidate_parent = Account::<AgentAccount>::try_from(&infos[candidate]).unwrap();
+            // Ancestors in reverse, plus the candidate itself, to show order doesn't matter
+            let mut ancestors: Vec<AccountInfo<'static>> = infos[..=candidate].to_vec();
+            ancestors.reverse();
+            let ancestors: &'static [AccountInfo<'static>] = Box::leak(ancestors.into_boxed_slice());
+            for (i, agent) in chain.iter().enumerate() {
+                let result = assert_not_descendant(&candidate_parent, agent, ancestors);
+                if i <= candidate {
+                    assert_err(result, HydraError::InvalidReparent);
+                } else {
+                    result.unwrap();
+                }
+            }
+            let unrelated = Pubkey::new_unique();
+            assert_not_descendant(&candidate_parent, &unrelated, ancestors).unwrap();
+        }
+    }
+
+    #[test]
+    fn not_descendant_rejects_missing_duplicate_and_cyclic_chains() {
+        let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let infos = agent_infos(&keys, &[Pubkey::default(), keys[0], keys[1]]);
+        let leaf = Account::<AgentAccount>::try_from(&infos[2]).unwrap();
+        let agent = Pubkey::new_unique();
+
+        assert_err(assert_not_descendant(&leaf, &agent, &infos[..1]), HydraError::InvalidChain);
+        let duplicated: Vec<AccountInfo<'static>> = vec![infos[0].clone(), infos[1].clone(), infos[1].clone()];
+        let duplicated: &'static [AccountInfo<'static>] = Box::leak(duplicated.into_boxed_slice());
+        assert_err(assert_not_descendant(&leaf, &agent, duplicated), HydraError::InvalidChain);
+
+        // Two agents naming each other as parent: the walk must still terminate
+        let cycle = agent_infos(&keys[..2], &[keys[1], keys[0]]);
+        let start = Account::<AgentAccount>::try_from(&cycle[0]).unwrap();
+        assert_err(assert_not_descendant(&start, &agent, cycle), HydraError::InvalidChain);
+    }
+
+    #[test]
+    fn split_bps_remainder_covers_rounding() {
+        assert_eq!(split_bps(1_000, 0), (0, 1_000));
+        assert_eq!(split_bps(1_000, 10_000), (1_000, 0));
+        assert_eq!(split_bps(1_000, 2_500), (250, 750));
+        // 3 * 3333 / 10000 rounds down to 0; the child keeps everything
+        assert_eq!(split_bps(3, 3_333), (0, 3));
+        assert_eq!(split_bps(9_999, 5_000), (4_999, 5_000));
+        assert_eq!(split_bps(u64::MAX, 10_000), (u64::MAX, 0));
+
+        for amount in [0, 1, 7, 999, 10_001, u64::MAX / 3, u64::MAX] {
+            for bps in [0, 1, 3_333, 5_000, 9_999, 10_000] {
+                let (share, remainder) = split_bps(amount, bps);
+                assert_eq!(share + remainder, amount, "{amount} at {bps} bps");
+                assert_eq!(share, bps_share(amount, bps));
+            }
+        }
+    }
+
+    #[test]
+    fn child_depth_stops_at_the_ceiling() {
+        assert_eq!(child_depth(0).unwrap(), 1);
+        assert_eq!(child_depth(MAX_DEPTH_CEILING - 1).unwrap(), MAX_DEPTH_CEILING);
+        assert_err(child_depth(MAX_DEPTH_CEILING), HydraError::Overflow);
+    }
+
+    #[test]
+    fn stats_round_trip_the_deepest_level() {
+        let (key, bump) = GlobalStats::find_address(NAMESPACE, &crate::ID);
+        let mut stats: GlobalStats = zeroed(GlobalStats::SIZE);
+        stats.bump = bump;
+        stats.record_agent_at_depth(MAX_DEPTH_CEILING);
+        stats.record_agent_at_depth(MAX_DEPTH_CEILING);
+        stats.remove_agent_at_depth(MAX_DEPTH_CEILING);
+
+        let info = Box::leak(Box::new(account_info(program_account(key, &stats, GlobalStats::SIZE))));
+        let read = Account::<GlobalStats>::try_from(&*info).unwrap();
+        assert_eq!(read.depth_counts.len(), DEPTH_LEVELS);
+        assert_eq!(read.depth_counts[MAX_DEPTH_CEILING as usize], 1);
+        assert_eq!(read.depth_counts.iter().sum::<u32>(), 1);
+    }
+
+    /// Write `account` into a `size`-byte buffer as `init` would and read it
+    /// back, failing if the encoding does not fit or does not survive.
+    fn assert_round_trips<T: AccountSerialize + AccountDeserialize>(account: &T, size: usize) {
+        let mut encoded = Vec::new();
+        account.try_serialize(&mut encoded).unwrap();
+        assert!(
+            encoded.len() <= size,
+            "{} encodes to {} bytes, over its {size}-byte size",
+            std::any::type_name::<T>(),
+            encoded.len()
+        );
+        let mut data = encoded.clone();
+        data.resize(size, 0);
+        let read = T::try_deserialize(&mut data.as_slice()).unwrap();
+        let mut reencoded = Vec::new();
+        read.try_serialize(&mut reencoded).unwrap();
+        assert_eq!(reencoded, encoded, "{} changed on read-back", std::any::type_name::<T>());
+    }
+
+    #[test]
+    fn maximal_accounts_round_trip_at_their_size() {
+        let key = Some(Pubkey::new_unique());
+
+        let mut registry: Registry = zeroed(Registry::SIZE);
+        registry.pending_authority = key;
+        registry.namespace = "n".repeat(MAX_NAMESPACE_LEN);
+        registry.max_depth = MAX_DEPTH;
+        assert_round_trips(&registry, Registry::SIZE);
+
+        let mut stats: GlobalStats = zeroed(GlobalStats::SIZE);
+        stats.depth_counts = [u32::MAX; DEPTH_LEVELS];
+        assert_round_trips(&stats, GlobalStats::SIZE);
+
+        let mut agent = agent();
+        agent.name = "n".repeat(MAX_NAME_LEN);
+        agent.specialization = "s".repeat(MAX_SPEC_LEN);
+        agent.secondary_beneficiary = key;
+        agent.uri = "u".repeat(MAX_URI_LEN);
+        agent.total_earned = u64::MAX;
+        assert_round_trips(&agent, AgentAccount::SIZE);
+
+        let mut split: SplitConfig = zeroed(SplitConfig::SIZE);
+        split.recipients = vec![
+            SplitRecipient {
+                recipient: Pubkey::new_unique(),
+                bps: 2_500,
+            };
+            MAX_SPLIT_RECIPIENTS
+        ];
+        assert_round_trips(&split, SplitConfig::SIZE);
+
+        let mut template: SpawnTemplate = zeroed(SpawnTemplate::SIZE);
+        template.name_prefix = "n".repeat(MAX_NAME_LEN);
+        template.specialization = "s".repeat(MAX_SPEC_LEN);
+        assert_round_trips(&template, SpawnTemplate::SIZE);
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.assignee = key;
+        bounty.claimant = key;
+        bounty.status = BountyStatus::Claimed;
+        assert_round_trips(&bounty, Bounty::SIZE);
+
+        let mut leaderboard: Leaderboard = zeroed(Leaderboard::SIZE);
+        leaderboard.update(Pubkey::new_unique(), u64::MAX);
+        assert_round_trips(&leaderboard, Leaderboard::SIZE);
+
+        assert_round_trips(&zeroed::<Bond>(Bond::SIZE), Bond::SIZE);
+        assert_round_trips(&zeroed::<SpecCounter>(SpecCounter::SIZE), SpecCounter::SIZE);
+        assert_round_trips(
+            &zeroed::<WalletAgentCounter>(WalletAgentCounter::SIZE),
+            WalletAgentCounter::SIZE,
+        );
+        assert_round_trips(&zeroed::<OracleRecord>(OracleRecord::SIZE), OracleRecord::SIZE);
+        assert_round_trips(&zeroed::<NameReservation>(NameReservation::SIZE), NameReservation::SIZE);
+        assert_round_trips(&zeroed::<Referral>(Referral::SIZE), Referral::SIZE);
+        assert_round_trips(&zeroed::<SnapshotLog>(SnapshotLog::SIZE), SnapshotLog::SIZE);
+        assert_round_trips(&zeroed::<TaskLog>(TaskLog::SIZE), TaskLog::SIZE);
+        assert_round_trips(&zeroed::<TokenEarnings>(TokenEarnings::SIZE), TokenEarnings::SIZE);
+        assert_round_trips(
+            &zeroed::<DistributionCommitment>(DistributionCommitment::SIZE),
+            DistributionCommitment::SIZE,
+        );
+    }
+
+    #[test]
+    fn debit_earning_reverses_credit_earning() {
+        let mut agent = agent();
+        agent.credit_earning(500, EarningCategory::Subscription, 9);
+        agent.credit_earning(200, EarningCategory::Referral, 9);
+        agent.attested_earned = 650;
+
+        agent.debit_earning(300, EarningCategory::Subscription, 9).unwrap();
+        assert_eq!(agent.total_earned, 400);
+        assert_eq!(agent.earned_by_category[EarningCategory::Subscription as usize], 200);
+        assert_eq!(agent.earned_by_category[EarningCategory::Referral as usize], 200);
+        assert_eq!(agent.epoch_earned, 400);
+        assert_eq!(agent.attested_earned, 400);
+
+        // A past epoch's earnings are no longer in `epoch_earned`
+        agent.debit_earning(100, EarningCategory::Referral, 8).unwrap();
+        assert_eq!(agent.epoch_earned, 400);
+        assert_eq!(agent.total_earned, 300);
+    }
+
+    #[test]
+    fn debit_earning_rejects_more_than_the_category_holds() {
+        let mut agent = agent();
+        agent.credit_earning(500, EarningCategory::Subscription, 9);
+        assert_err(
+            agent.debit_earning(1, EarningCategory::Referral, 9),
+            HydraError::AdjustmentUnderflow,
+        );
+        assert_err(
+            agent.debit_earning(501, EarningCategory::Subscription, 9),
+            HydraError::AdjustmentUnderflow,
+        );
+        assert_eq!(agent.total_earned, 500);
+    }
+
+    #[test]
+    fn withdraw_subtree_credit_takes_pending_earnings_first() {
+        let mut parent = agent();
+        parent.parent = Pubkey::new_unique();
+        parent.subtree_earned = 1_000;
+        parent.unrolled_earnings = 400;
+        let mut child = agent();
+        child.parent = Pubkey::new_unique();
+        child.unrolled_earnings = 300;
+
+        withdraw_subtree_credit(&mut child, None, 200).unwrap();
+        assert_eq!(child.unrolled_earnings, 100);
+        assert_err(
+            withdraw_subtree_credit(&mut child, None, 150),
+            HydraError::MissingParentAccount,
+        );
+
+        withdraw_subtree_credit(&mut child, Some(&mut parent), 150).unwrap();
+        assert_eq!(child.unrolled_earnings, 0);
+        assert_eq!(parent.subtree_earned, 950);
+        assert_eq!(parent.unrolled_earnings, 350);
+
+        let mut root_child = agent();
+        withdraw_subtree_credit(&mut root_child, None, 10).unwrap();
+    }
+
+    #[test]
+    fn unrecord_reported_reverses_record_reported() {
+        let mut stats: GlobalStats = zeroed(GlobalStats::SIZE);
+        stats.record_reported(700, EarningCategory::TaskCompletion, 4);
+        stats.record_reported(100, EarningCategory::TaskCompletion, 5);
+
+        stats.unrecord_reported(250, EarningCategory::TaskCompletion, 4);
+        assert_eq!(stats.total_reported_earnings, 550);
+        assert_eq!(stats.earned_by_category[EarningCategory::TaskCompletion as usize], 550);
+        assert_eq!((stats.recent_epochs[0].epoch, stats.recent_epochs[0].earnings), (5, 100));
+        assert_eq!((stats.recent_epochs[1].epoch, stats.recent_epochs[1].earnings), (4, 450));
+    }
+
+    #[test]
+    fn detach_subtree_removes_the_branch_from_the_old_parent() {
+        let mut old_parent = agent();
+        old_parent.subtree_earned = 1_000;
+        old_parent.unrolled_earnings = 100;
+        let mut child = agent();
+        child.total_earned = 300;
+        child.subtree_earned = 200;
+
+        // A root old parent has nothing pending to correct
+        detach_subtree(&mut old_parent, &child);
+        assert_eq!(old_parent.subtree_earned, 500);
+        assert_eq!(old_parent.unrolled_earnings, 100);
+
+        old_parent.parent = Pubkey::new_unique();
+        detach_subtree(&mut old_parent, &child);
+        assert_eq!(old_parent.subtree_earned, 0);
+        assert_eq!(old_parent.unrolled_earnings, 0);
+    }
+
+    /// Borsh encoding of `args` as the program sees it in instruction data.
+    fn ix_bytes(args: &impl AnchorSerialize) -> Vec<u8> {
+        let mut data = Vec::new();
+        args.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn register_root_agent_v2_encodes_the_v1_arguments_first() {
+        let v1 = (NAMESPACE.to_string(), "scout".to_string(), "mev-detection".to_string());
+        let args = RegisterRootAgentArgs {
+            name: v1.1.clone(),
+            specialization: v1.2.clone(),
+            uri: "https://example.com/scout.json".to_string(),
+            content_hash: [7; 32],
+        };
+        let v2 = ix_bytes(&(NAMESPACE.to_string(), args));
+
+        // `#[instruction]` decodes the same prefix, so both versions derive the
+        // same agent, spec counter and name reservation addresses
+        assert!(v2.starts_with(&ix_bytes(&v1)));
+        let parsed = <(String, String, String)>::deserialize(&mut v2.as_slice()).unwrap();
+        assert_eq!(parsed, v1);
+    }
+
+    #[test]
+    fn spawn_child_v2_encodes_the_v1_arguments_first() {
+        let v1 = (
+            NAMESPACE.to_string(),
+            "scout".to_string(),
+            "mev-detection".to_string(),
+            2_000u16,
+            Some(Pubkey::new_unique()),
+            500u16,
+            1_700_000_000i64,
+            42u64,
+        );
+        let args = SpawnChildArgs {
+            name: v1.1.clone(),
+            specialization: v1.2.clone(),
+            revenue_share_bps: v1.3,
+            secondary_beneficiary: v1.4,
+            secondary_share_bps: v1.5,
+            expires_at: v1.6,
+            earning_goal: v1.7,
+            uri: String::new(),
+            content_hash: [0; 32],
+        };
+        let v2 = ix_bytes(&(NAMESPACE.to_string(), args));
+
+        // Everything `spawn_child` receives is the v1 encoding; the v2 tail only
+        // carries the uri and content hash, written after the v1 handler runs
+        let v1 = ix_bytes(&v1);
+        assert_eq!(&v2[..v1.len()], v1.as_slice());
+        assert_eq!(v2.len(), v1.len() + 4 + 32);
+    }
 }
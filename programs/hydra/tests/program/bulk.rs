@@ -0,0 +1,88 @@
+use crate::harness::*;
+use anchor_lang::system_program;
+use hydra::{BulkEarningsRecorded, HydraError, InitializeParams, TaskLog};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+
+fn bulk_ix(env: &Env, wallet: &Keypair, amounts: Vec<u64>, task_hashes: Vec<[u8; 32]>) -> Instruction {
+    let agent = env.agent_key(&wallet.pubkey());
+    let task_log = (!task_hashes.is_empty()).then(|| TaskLog::find_address(&agent, &hydra::ID).0);
+    instruction(
+        hydra::accounts::RecordEarningsBulk {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            agent,
+            wallet: wallet.pubkey(),
+            parent_agent: None,
+            leaderboard: None,
+            memo_program: None,
+            task_log,
+            system_program: system_program::ID,
+        },
+        hydra::instruction::RecordEarningsBulk {
+            _namespace: env.namespace.clone(),
+            amounts,
+            task_hashes,
+            category: None,
+        },
+    )
+}
+
+fn task(n: u8) -> [u8; 32] {
+    [n; 32]
+}
+
+#[tokio::test]
+async fn bulk_credits_the_sum_once() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let root = env.register_root("root", "scouting").await;
+
+    let amounts: Vec<u64> = (1..=10).map(|i| i * 100).collect();
+    let ix = bulk_ix(&env, &root, amounts, (1..=10).map(task).collect());
+    let outcome = env.send(ix, &[&root]).await.unwrap();
+
+    assert_eq!(env.agent(&root).await.total_earned, 5_500);
+    let recorded = outcome.events::<BulkEarningsRecorded>();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].count, 10);
+    assert_eq!(recorded[0].total, 5_500);
+    let task_log: TaskLog = env
+        .account(&TaskLog::find_address(&env.agent_key(&root.pubkey()), &hydra::ID).0)
+        .await;
+    assert_eq!(task_log.count, 10);
+}
+
+#[tokio::test]
+async fn duplicate_tasks_are_rejected_within_and_across_batches() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let root = env.register_root("root", "scouting").await;
+
+    let ix = bulk_ix(&env, &root, vec![100, 200, 300], vec![task(1), task(2), task(1)]);
+    assert_hydra_err(env.send(ix, &[&root]).await, HydraError::DuplicateTask);
+    assert_eq!(env.agent(&root).await.total_earned, 0);
+
+    let ix = bulk_ix(&env, &root, vec![100, 200], vec![task(1), task(2)]);
+    env.send(ix, &[&root]).await.unwrap();
+
+    let ix = bulk_ix(&env, &root, vec![300, 400], vec![task(3), task(2)]);
+    assert_hydra_err(env.send(ix, &[&root]).await, HydraError::DuplicateTask);
+    assert_eq!(env.agent(&root).await.total_earned, 300);
+}
+
+/// Logs what ten `record_earning` calls cost against one ten-amount
+/// `record_earnings_bulk`. Only meaningful against the SBF build.
+#[tokio::test]
+#[ignore]
+async fn bulk_compute_units() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let singles = env.register_root("singles", "scouting").await;
+    let bulk = env.register_root("bulk", "scouting").await;
+
+    let mut single_units = 0;
+    for _ in 0..10 {
+        single_units += env.record(&singles, 100, None).await.compute_units;
+    }
+    let ix = bulk_ix(&env, &bulk, vec![100; 10], Vec::new());
+    let bulk_units = env.send(ix, &[&bulk]).await.unwrap().compute_units;
+    println!("10 x record_earning: {single_units} CU; record_earnings_bulk of 10: {bulk_units} CU");
+}
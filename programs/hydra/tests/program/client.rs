@@ -0,0 +1,188 @@
+use crate::harness::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, Discriminator};
+use hydra::client::{self, OptionalAccounts};
+use hydra::{AgentAccount, NameReservation, SpecCounter, WalletAgentCounter};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::TransactionError;
+
+fn decode<T: Discriminator + AnchorDeserialize>(ix: &Instruction) -> T {
+    assert_eq!(ix.program_id, hydra::ID);
+    assert!(ix.data.starts_with(T::DISCRIMINATOR));
+    T::try_from_slice(&ix.data[T::DISCRIMINATOR.len()..]).unwrap()
+}
+
+/// Register an agent for `child_wallet` at its next indexed PDA under `parent`.
+async fn register_indexed(env: &mut Env, parent: &Keypair, child_wallet: &Pubkey, name: &str) -> Pubkey {
+    let child_agent = AgentAccount::find_indexed_address(&env.namespace, child_wallet, 0, &hydra::ID).0;
+    let ix = instruction(
+        hydra::accounts::RegisterAgentIndexed {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            parent_agent: env.agent_key(&parent.pubkey()),
+            child_agent,
+            wallet_agent_counter: WalletAgentCounter::find_address(child_wallet, &env.namespace, &hydra::ID).0,
+            bond: None,
+            spec_counter: SpecCounter::find_address("scouting", &env.namespace, &hydra::ID).0,
+            parent_wallet: parent.pubkey(),
+            child_wallet: *child_wallet,
+            system_program: system_program::ID,
+            spawn_license: None,
+            name_reservation: NameReservation::find_address(name, &env.namespace, &hydra::ID).0,
+        },
+        hydra::instruction::RegisterAgentIndexed {
+            _namespace: env.namespace.clone(),
+            agent_index: 0,
+            name: name.to_string(),
+            specialization: "scouting".to_string(),
+            revenue_share_bps: 1_000,
+        },
+    );
+    env.send(ix, &[parent]).await.unwrap();
+    child_agent
+}
+
+#[test]
+fn builders_encode_their_arguments() {
+    let authority = Pubkey::new_unique();
+    let parent = Pubkey::new_unique();
+    let child = Pubkey::new_unique();
+
+    let ix = client::build_register_root_agent_ix(
+        NAMESPACE,
+        &authority,
+        &parent,
+        "root".to_string(),
+        "scouting".to_string(),
+        &OptionalAccounts::default(),
+    );
+    let args: hydra::instruction::RegisterRootAgent = decode(&ix);
+    assert_eq!(args._namespace, NAMESPACE);
+    assert_eq!(args.name, "root");
+    assert_eq!(args.specialization, "scouting");
+
+    let ix = client::build_spawn_child_ix(
+        NAMESPACE,
+        &parent,
+        &child,
+        hydra::instruction::SpawnChild {
+            secondary_beneficiary: Some(authority),
+            secondary_share_bps: 250,
+            expires_at: 1_700_000_000,
+            earning_goal: 42,
+            ..spawn_args("child", 1_500)
+        },
+        &OptionalAccounts::default(),
+    );
+    let args: hydra::instruction::SpawnChild = decode(&ix);
+    assert_eq!(args._namespace, NAMESPACE);
+    assert_eq!(args.name, "child");
+    assert_eq!(args.specialization, "scouting");
+    assert_eq!(args.revenue_share_bps, 1_500);
+    assert_eq!(args.secondary_beneficiary, Some(authority));
+    assert_eq!(args.secondary_share_bps, 250);
+    assert_eq!(args.expires_at, 1_700_000_000);
+    assert_eq!(args.earning_goal, 42);
+
+    let ix = client::build_record_earning_ix(
+        NAMESPACE,
+        &child,
+        7_000,
+        3,
+        Some(hydra::EarningCategory::Subscription),
+        &OptionalAccounts::default(),
+    );
+    let args: hydra::instruction::RecordEarning = decode(&ix);
+    assert_eq!(args._namespace, NAMESPACE);
+    assert_eq!(args.amount, 7_000);
+    assert_eq!(args.nonce, 3);
+    assert_eq!(args.category, Some(hydra::EarningCategory::Subscription));
+
+    let ix = client::build_distribute_to_parent_ix(NAMESPACE, &child, &parent, 900, &OptionalAccounts::default());
+    let args: hydra::instruction::DistributeToParent = decode(&ix);
+    assert_eq!(args._namespace, NAMESPACE);
+    assert_eq!(args.amount, 900);
+}
+
+#[test]
+fn builders_derive_indexed_agents_from_the_given_indices() {
+    let parent = Pubkey::new_unique();
+    let child = Pubkey::new_unique();
+    let grandparent = Pubkey::new_unique();
+    let indexed = |wallet: &Pubkey, index| AgentAccount::find_indexed_address(NAMESPACE, wallet, index, &hydra::ID).0;
+    let optional = OptionalAccounts {
+        parent_wallet: Some(parent),
+        grandparent_wallet: Some(grandparent),
+        agent_index: Some(2),
+        parent_index: Some(0),
+        grandparent_index: Some(5),
+        ..OptionalAccounts::default()
+    };
+
+    let ix = client::build_record_earning_ix(NAMESPACE, &child, 1, 0, None, &optional);
+    assert_eq!(ix.accounts[2].pubkey, indexed(&child, 2));
+    assert_eq!(ix.accounts[4].pubkey, indexed(&parent, 0));
+
+    let ix = client::build_distribute_to_parent_ix(NAMESPACE, &child, &parent, 1, &optional);
+    assert_eq!(ix.accounts[2].pubkey, indexed(&child, 2));
+    assert_eq!(ix.accounts[3].pubkey, indexed(&parent, 0));
+    assert_eq!(ix.accounts[6].pubkey, indexed(&grandparent, 5));
+
+    let ix = client::build_spawn_child_ix(NAMESPACE, &parent, &child, spawn_args("child", 0), &optional);
+    assert_eq!(ix.accounts[2].pubkey, indexed(&parent, 0));
+    assert_eq!(
+        ix.accounts[3].pubkey,
+        AgentAccount::find_address(NAMESPACE, &child, &hydra::ID).0,
+    );
+}
+
+#[tokio::test]
+async fn builders_reach_indexed_agents() {
+    let mut env = Env::new(Default::default()).await;
+    let root = env.register_root("root", "scouting").await;
+    let wallet = env.funded(10 * SOL).await;
+    let indexed = register_indexed(&mut env, &root, &wallet.pubkey(), "indexed").await;
+
+    // Without the index the builder points at the wallet's canonical PDA, which
+    // does not exist.
+    let ix = client::build_record_earning_ix(&env.namespace, &wallet.pubkey(), 1_000, 0, None, &Default::default());
+    let err = env.send(ix, &[&wallet]).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(anchor_lang::error::ErrorCode::AccountNotInitialized as u32),
+        ),
+    );
+
+    let optional = OptionalAccounts {
+        agent_index: Some(0),
+        ..OptionalAccounts::default()
+    };
+    let ix = client::build_record_earning_ix(&env.namespace, &wallet.pubkey(), 1_000, 0, None, &optional);
+    env.send(ix, &[&wallet]).await.unwrap();
+    let ix = client::build_distribute_to_parent_ix(&env.namespace, &wallet.pubkey(), &root.pubkey(), 100, &optional);
+    env.send(ix, &[&wallet]).await.unwrap();
+
+    let agent: AgentAccount = env.account(&indexed).await;
+    assert_eq!(agent.total_earned, 1_000);
+    assert_eq!(agent.total_distributed_to_parent, 100);
+    assert_eq!(env.agent(&root).await.total_received_from_children, 100);
+
+    // The indexed agent spawns a child of its own.
+    let child = Keypair::new();
+    let ix = client::build_spawn_child_ix(
+        &env.namespace,
+        &wallet.pubkey(),
+        &child.pubkey(),
+        spawn_args("grandchild", 500),
+        &OptionalAccounts {
+            parent_index: Some(0),
+            ..OptionalAccounts::default()
+        },
+    );
+    env.send(ix, &[&wallet]).await.unwrap();
+    assert_eq!(env.agent(&child).await.parent, indexed);
+}
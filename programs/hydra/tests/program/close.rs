@@ -0,0 +1,91 @@
+use crate::harness::*;
+use hydra::{Bond, HydraError, InitializeParams, SpecCounter};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn close_with_forward_ix(
+    env: &mut Env,
+    agent: &Keypair,
+    parent: Option<&Keypair>,
+    new_parent: &Keypair,
+    children: &[&Keypair],
+) -> Instruction {
+    let agent_key = env.agent_key(&agent.pubkey());
+    let closing = env.agent(agent).await;
+    let mut ix = instruction(
+        hydra::accounts::CloseWithForward {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            agent: agent_key,
+            wallet: agent.pubkey(),
+            parent_agent: parent.map(|p| env.agent_key(&p.pubkey())),
+            new_parent: env.agent_key(&new_parent.pubkey()),
+            new_parent_wallet: new_parent.pubkey(),
+            rent_destination: closing.rent_refund_destination(),
+            bond: Bond::find_address(&agent_key, &hydra::ID).0,
+            spec_counter: SpecCounter::find_address(&closing.specialization, &env.namespace, &hydra::ID).0,
+        },
+        hydra::instruction::CloseWithForward {
+            _namespace: env.namespace.clone(),
+        },
+    );
+    let children: Vec<_> = children.iter().map(|w| env.agent_key(&w.pubkey())).collect();
+    ix.accounts.extend(remaining_mut(&children));
+    ix
+}
+
+#[tokio::test]
+async fn close_with_forward_refunds_the_bond_and_leaves_the_spec_count() {
+    let mut env = Env::new(InitializeParams {
+        required_bond_lamports: SOL,
+        ..InitializeParams::default()
+    })
+    .await;
+    env.optional.bond = true;
+    let parent = env.register_root("parent", "scouting").await;
+    let new_parent = env.register_root("new", "scouting").await;
+    let closing = env.spawn(&parent, "closing", 1_000).await;
+    let child = env.spawn(&closing, "child", 1_000).await;
+    let counter = SpecCounter::find_address("scouting", &env.namespace, &hydra::ID).0;
+    let bond = Bond::find_address(&env.agent_key(&closing.pubkey()), &hydra::ID).0;
+    let count_before = env.account::<SpecCounter>(&counter).await.count;
+    let wallet_before = env.balance(&closing.pubkey()).await;
+    let bond_lamports = env.balance(&bond).await;
+    assert!(bond_lamports > SOL);
+
+    let ix = close_with_forward_ix(&mut env, &closing, Some(&parent), &new_parent, &[&child]).await;
+    env.send(ix, &[&closing, &new_parent]).await.unwrap();
+
+    assert!(env.raw_account(&env.agent_key(&closing.pubkey())).await.is_none());
+    assert!(env.raw_account(&bond).await.is_none());
+    assert_eq!(env.balance(&closing.pubkey()).await, wallet_before + bond_lamports);
+    assert_eq!(env.account::<SpecCounter>(&counter).await.count, count_before - 1);
+    assert_eq!(env.agent(&child).await.parent, env.agent_key(&new_parent.pubkey()));
+    assert_eq!(env.agent(&parent).await.children_count, 0);
+}
+
+#[tokio::test]
+async fn close_with_forward_moves_subtree_earnings() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let parent = env.register_root("parent", "scouting").await;
+    let new_parent = env.register_root("new", "scouting").await;
+    let closing = env.spawn(&parent, "closing", 1_000).await;
+    let first = env.spawn(&closing, "first", 1_000).await;
+    let second = env.spawn(&closing, "second", 1_000).await;
+    env.record(&first, 300, Some(closing.pubkey())).await;
+    env.record(&second, 200, Some(closing.pubkey())).await;
+    env.record(&closing, 100, Some(parent.pubkey())).await;
+
+    // The children's earnings are still pending on the closing agent
+    let ix = close_with_forward_ix(&mut env, &closing, Some(&parent), &new_parent, &[&first, &second]).await;
+    assert_hydra_err(env.send(ix, &[&closing, &new_parent]).await, HydraError::PendingRollUp);
+
+    env.roll_up(&closing, &parent).await;
+    assert_eq!(env.agent(&parent).await.subtree_earned, 600);
+    let ix = close_with_forward_ix(&mut env, &closing, Some(&parent), &new_parent, &[&first, &second]).await;
+    env.send(ix, &[&closing, &new_parent]).await.unwrap();
+
+    // The closed agent's own earnings leave the tree; its children's move
+    assert_eq!(env.agent(&parent).await.subtree_earned, 0);
+    assert_eq!(env.agent(&new_parent).await.subtree_earned, 500);
+}
@@ -0,0 +1,100 @@
+use crate::harness::*;
+use hydra::client;
+use hydra::{AgentRegistered, AgentRegisteredCompact, AgentSpawned, AgentSpawnedCompact, InitializeParams};
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn set_compact_events(env: &mut Env, compact_events: bool) {
+    let ix = instruction(
+        hydra::accounts::UpdateRegistryConfig {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            authority: env.authority().pubkey(),
+        },
+        hydra::instruction::SetCompactEvents {
+            _namespace: env.namespace.clone(),
+            compact_events,
+        },
+    );
+    env.send(ix, &[]).await.unwrap();
+}
+
+async fn register(env: &mut Env, name: &str) -> (Keypair, Outcome) {
+    let wallet = env.funded(10 * SOL).await;
+    let ix = client::build_register_root_agent_ix(
+        &env.namespace,
+        &env.authority().pubkey(),
+        &wallet.pubkey(),
+        name.to_string(),
+        "scouting".to_string(),
+        &env.optional,
+    );
+    let outcome = env.send(ix, &[]).await.unwrap();
+    (wallet, outcome)
+}
+
+async fn spawn(env: &mut Env, parent: &Keypair, name: &str) -> Outcome {
+    let ix = env.spawn_ix(parent, &Keypair::new().pubkey(), name, 1_000);
+    env.send(ix, &[parent]).await.unwrap()
+}
+
+#[tokio::test]
+async fn creation_events_follow_the_compact_flag() {
+    let mut env = Env::new(InitializeParams::default()).await;
+
+    let (root, registered) = register(&mut env, "full-root").await;
+    let full = registered.events::<AgentRegistered>();
+    assert_eq!(full.len(), 1);
+    assert_eq!(full[0].name, "full-root");
+    assert_eq!(full[0].specialization, "scouting");
+    assert!(registered.events::<AgentRegisteredCompact>().is_empty());
+
+    let spawned = spawn(&mut env, &root, "full-child").await;
+    let full = spawned.events::<AgentSpawned>();
+    assert_eq!(full.len(), 1);
+    assert_eq!(full[0].name, "full-child");
+    assert!(spawned.events::<AgentSpawnedCompact>().is_empty());
+
+    set_compact_events(&mut env, true).await;
+
+    let (root, registered) = register(&mut env, "compact-root").await;
+    let compact = registered.events::<AgentRegisteredCompact>();
+    assert_eq!(compact.len(), 1);
+    assert_eq!(compact[0].wallet, root.pubkey());
+    assert_eq!(compact[0].agent, env.agent_key(&root.pubkey()));
+    assert!(registered.events::<AgentRegistered>().is_empty());
+
+    let spawned = spawn(&mut env, &root, "compact-child").await;
+    let compact = spawned.events::<AgentSpawnedCompact>();
+    assert_eq!(compact.len(), 1);
+    assert_eq!(compact[0].parent, env.agent_key(&root.pubkey()));
+    assert_eq!(compact[0].depth, 1);
+    assert_eq!(compact[0].revenue_share_bps, 1_000);
+    assert!(spawned.events::<AgentSpawned>().is_empty());
+
+    set_compact_events(&mut env, false).await;
+
+    let spawned = spawn(&mut env, &root, "full-again").await;
+    assert_eq!(spawned.events::<AgentSpawned>().len(), 1);
+    assert!(spawned.events::<AgentSpawnedCompact>().is_empty());
+}
+
+/// Logs what the same registration and spawn cost with full and compact
+/// events. Only meaningful against the SBF build.
+#[tokio::test]
+#[ignore]
+async fn compact_events_compute_units() {
+    let long_name = "n".repeat(32);
+    for compact_events in [false, true] {
+        let mut env = Env::new(InitializeParams {
+            compact_events,
+            ..InitializeParams::default()
+        })
+        .await;
+        let (root, registered) = register(&mut env, &long_name).await;
+        let spawned = spawn(&mut env, &root, &long_name.to_uppercase()).await;
+        println!(
+            "compact_events = {compact_events}: register_root_agent {} CU, spawn_child {} CU",
+            registered.compute_units, spawned.compute_units,
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::harness::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+use hydra::client::{self, OptionalAccounts};
+use hydra::{HydraError, InitializeParams};
+use solana_program_test::processor;
+use solana_sdk::signature::{Keypair, Signer};
+
+const CALLER_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// A program that forwards its instruction data to the program passed as its
+/// first account, handing on the remaining accounts with the signer and
+/// writable flags they arrived with.
+fn process_caller(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (program, forwarded) = accounts.split_first().unwrap();
+    let ix = Instruction {
+        program_id: *program.key,
+        accounts: forwarded
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke(&ix, accounts)
+}
+
+/// `ix` routed through the caller program.
+fn via_caller(ix: Instruction) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(ix.program_id, false)];
+    accounts.extend(ix.accounts);
+    Instruction {
+        program_id: CALLER_ID,
+        accounts,
+        data: ix.data,
+    }
+}
+
+async fn env(allow_cpi: bool) -> Env {
+    Env::with_programs(
+        InitializeParams {
+            allow_cpi,
+            ..InitializeParams::default()
+        },
+        |program_test| program_test.add_program("cpi_caller", CALLER_ID, processor!(process_caller)),
+    )
+    .await
+}
+
+struct Calls {
+    root: Keypair,
+    child: Keypair,
+    spawn: Instruction,
+    record: Instruction,
+    distribute: Instruction,
+}
+
+async fn calls(env: &mut Env) -> Calls {
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    let spawn = env.spawn_ix(&root, &Keypair::new().pubkey(), "grandchild", 1_000);
+    let record = client::build_record_earning_ix(
+        &env.namespace,
+        &child.pubkey(),
+        1_000,
+        0,
+        None,
+        &OptionalAccounts::default(),
+    );
+    let distribute = client::build_distribute_to_parent_ix(
+        &env.namespace,
+        &child.pubkey(),
+        &root.pubkey(),
+        100,
+        &OptionalAccounts::default(),
+    );
+    Calls {
+        root,
+        child,
+        spawn,
+        record,
+        distribute,
+    }
+}
+
+#[tokio::test]
+async fn protected_instructions_reject_cpi_by_default() {
+    let mut env = env(false).await;
+    let Calls {
+        root,
+        child,
+        spawn,
+        record,
+        distribute,
+    } = calls(&mut env).await;
+
+    assert_hydra_err(env.send(via_caller(spawn.clone()), &[&root]).await, HydraError::CpiNotAllowed);
+    assert_hydra_err(env.send(via_caller(record.clone()), &[&child]).await, HydraError::CpiNotAllowed);
+    assert_hydra_err(
+        env.send(via_caller(distribute.clone()), &[&child]).await,
+        HydraError::CpiNotAllowed,
+    );
+
+    // The same instructions are accepted at the top level.
+    env.send(spawn, &[&root]).await.unwrap();
+    env.send(record, &[&child]).await.unwrap();
+    env.send(distribute, &[&child]).await.unwrap();
+    assert_eq!(env.agent(&root).await.children_count, 2);
+    assert_eq!(env.agent(&child).await.total_earned, 1_000);
+    assert_eq!(env.agent(&child).await.total_distributed_to_parent, 100);
+}
+
+#[tokio::test]
+async fn allow_cpi_admits_protected_instructions_via_cpi() {
+    let mut env = env(true).await;
+    let Calls {
+        root,
+        child,
+        spawn,
+        record,
+        distribute,
+    } = calls(&mut env).await;
+
+    env.send(via_caller(spawn), &[&root]).await.unwrap();
+    env.send(via_caller(record), &[&child]).await.unwrap();
+    env.send(via_caller(distribute), &[&child]).await.unwrap();
+    assert_eq!(env.agent(&root).await.children_count, 2);
+    assert_eq!(env.agent(&child).await.total_earned, 1_000);
+    assert_eq!(env.agent(&child).await.total_distributed_to_parent, 100);
+    assert_eq!(env.agent(&root).await.total_received_from_children, 100);
+}
+
+#[tokio::test]
+async fn set_allow_cpi_takes_effect_on_the_next_call() {
+    let mut env = env(false).await;
+    let Calls { child, record, .. } = calls(&mut env).await;
+    assert_hydra_err(env.send(via_caller(record.clone()), &[&child]).await, HydraError::CpiNotAllowed);
+
+    let set_allow_cpi = instruction(
+        hydra::accounts::UpdateRegistryConfig {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            authority: env.authority().pubkey(),
+        },
+        hydra::instruction::SetAllowCpi {
+            _namespace: env.namespace.clone(),
+            allow_cpi: true,
+        },
+    );
+    env.send(set_allow_cpi, &[]).await.unwrap();
+
+    env.send(via_caller(record), &[&child]).await.unwrap();
+    assert_eq!(env.agent(&child).await.total_earned, 1_000);
+}
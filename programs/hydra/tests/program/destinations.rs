@@ -0,0 +1,142 @@
+use crate::harness::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use hydra::client::{self, OptionalAccounts};
+use hydra::{HydraError, InitializeParams};
+use solana_program_test::processor;
+use solana_sdk::account::AccountSharedData;
+use solana_sdk::signature::{Keypair, Signer};
+
+const TOKEN_PROGRAM_ID: Pubkey = Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Stands in for a multisig program such as Squads: it owns nothing, but signs
+/// for its system-owned vault PDA when forwarding an instruction.
+const VAULT_PROGRAM_ID: Pubkey = Pubkey::new_from_array([9; 32]);
+const VAULT_SEED: &[u8] = b"vault";
+
+fn vault() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED], &VAULT_PROGRAM_ID)
+}
+
+/// Forwards its instruction data to the program in its first account, signing
+/// for the vault among the remaining accounts.
+fn process_vault(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (vault, bump) = vault();
+    let (program, forwarded) = accounts.split_first().unwrap();
+    let ix = Instruction {
+        program_id: *program.key,
+        accounts: forwarded
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer || *a.key == vault,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke_signed(&ix, accounts, &[&[VAULT_SEED, &[bump]]])
+}
+
+/// `ix` executed by the vault program, which signs for the vault.
+fn via_vault(ix: Instruction) -> Instruction {
+    let (vault, _) = vault();
+    let mut accounts = vec![AccountMeta::new_readonly(ix.program_id, false)];
+    accounts.extend(ix.accounts.into_iter().map(|mut meta| {
+        if meta.pubkey == vault {
+            meta.is_signer = false;
+        }
+        meta
+    }));
+    Instruction {
+        program_id: VAULT_PROGRAM_ID,
+        accounts,
+        data: ix.data,
+    }
+}
+
+fn distribute_ix(env: &Env, child: &Keypair, parent_wallet: &Pubkey, amount: u64) -> Instruction {
+    client::build_distribute_to_parent_ix(
+        &env.namespace,
+        &child.pubkey(),
+        parent_wallet,
+        amount,
+        &OptionalAccounts::default(),
+    )
+}
+
+#[tokio::test]
+async fn distribution_reaches_a_keypair_wallet() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    env.record(&child, 10_000, None).await;
+
+    let before = env.balance(&root.pubkey()).await;
+    let ix = distribute_ix(&env, &child, &root.pubkey(), 1_000);
+    env.send(ix, &[&child]).await.unwrap();
+    assert_eq!(env.balance(&root.pubkey()).await - before, 1_000);
+}
+
+#[tokio::test]
+async fn distribution_reaches_a_multisig_vault_pda() {
+    // The vault spawns through a CPI, as a multisig executes its transactions.
+    let mut env = Env::with_programs(
+        InitializeParams {
+            allow_cpi: true,
+            ..InitializeParams::default()
+        },
+        |program_test| program_test.add_program("vault", VAULT_PROGRAM_ID, processor!(process_vault)),
+    )
+    .await;
+    let (vault, _) = vault();
+    assert!(!vault.is_on_curve());
+    env.fund(&vault, SOL).await;
+
+    let ix = client::build_register_root_agent_ix(
+        &env.namespace,
+        &env.authority().pubkey(),
+        &vault,
+        "vault".to_string(),
+        "scouting".to_string(),
+        &env.optional,
+    );
+    env.send(ix, &[]).await.unwrap();
+    let child = env.funded(10 * SOL).await;
+    let ix = client::build_spawn_child_ix(
+        &env.namespace,
+        &vault,
+        &child.pubkey(),
+        spawn_args("child", 1_000),
+        &env.optional,
+    );
+    env.send(via_vault(ix), &[]).await.unwrap();
+    env.record(&child, 10_000, None).await;
+
+    let before = env.balance(&vault).await;
+    let ix = distribute_ix(&env, &child, &vault, 1_000);
+    env.send(ix, &[&child]).await.unwrap();
+    assert_eq!(env.balance(&vault).await - before, 1_000);
+    assert_eq!(env.raw_account(&vault).await.unwrap().owner, system_program::ID);
+}
+
+#[tokio::test]
+async fn distribution_to_a_token_account_is_rejected() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    env.record(&child, 10_000, None).await;
+
+    // The parent's wallet address now holds an SPL token account.
+    let lamports = env.balance(&root.pubkey()).await;
+    let token_account = AccountSharedData::new(lamports, TOKEN_ACCOUNT_LEN, &TOKEN_PROGRAM_ID);
+    env.ctx.set_account(&root.pubkey(), &token_account);
+
+    let ix = distribute_ix(&env, &child, &root.pubkey(), 1_000);
+    assert_hydra_err(env.send(ix, &[&child]).await, HydraError::InvalidParentWalletOwner);
+    assert_eq!(env.balance(&root.pubkey()).await, lamports);
+    assert_eq!(env.agent(&child).await.total_distributed_to_parent, 0);
+}
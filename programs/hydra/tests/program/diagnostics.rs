@@ -0,0 +1,120 @@
+use crate::harness::*;
+use hydra::client::{self, OptionalAccounts};
+use hydra::{HydraError, InitializeParams};
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn deactivate(env: &mut Env, wallet: &Keypair) {
+    let ix = instruction(
+        hydra::accounts::DeactivateAgent {
+            registry: env.registry_key(),
+            agent: env.agent_key(&wallet.pubkey()),
+            stats: env.stats_key(),
+            authority: env.authority().pubkey(),
+        },
+        hydra::instruction::DeactivateAgent {
+            _namespace: env.namespace.clone(),
+        },
+    );
+    env.send(ix, &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn length_limits_log_the_rejected_length() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let wallet = env.funded(SOL).await;
+    let ix = client::build_register_root_agent_ix(
+        &env.namespace,
+        &env.authority().pubkey(),
+        &wallet.pubkey(),
+        "n".repeat(33),
+        "scouting".to_string(),
+        &env.optional,
+    );
+    let (result, outcome) = env.send_logged(ix, &[]).await;
+    assert_hydra_err(result, HydraError::NameTooLong);
+    assert!(outcome.logged("name length 33 exceeds limit 32"));
+
+    let root = env.register_root("root", "scouting").await;
+    let ix = client::build_spawn_child_ix(
+        &env.namespace,
+        &root.pubkey(),
+        &Keypair::new().pubkey(),
+        hydra::instruction::SpawnChild {
+            specialization: "s".repeat(70),
+            ..spawn_args("child", 1_000)
+        },
+        &env.optional,
+    );
+    let (result, outcome) = env.send_logged(ix, &[&root]).await;
+    assert_hydra_err(result, HydraError::SpecTooLong);
+    assert!(outcome.logged("specialization length 70 exceeds limit 64"));
+}
+
+#[tokio::test]
+async fn share_and_depth_limits_log_the_rejected_value() {
+    let mut env = Env::new(InitializeParams {
+        max_depth: 1,
+        ..InitializeParams::default()
+    })
+    .await;
+    let root = env.register_root("root", "scouting").await;
+
+    let ix = env.spawn_ix(&root, &Keypair::new().pubkey(), "greedy", 10_001);
+    let (result, outcome) = env.send_logged(ix, &[&root]).await;
+    assert_hydra_err(result, HydraError::InvalidRevenueShare);
+    assert!(outcome.logged("revenue and secondary share bps 10001 exceeds limit 10000"));
+
+    let child = env.spawn(&root, "child", 1_000).await;
+    let ix = env.spawn_ix(&child, &Keypair::new().pubkey(), "too-deep", 1_000);
+    let (result, outcome) = env.send_logged(ix, &[&child]).await;
+    assert_hydra_err(result, HydraError::MaxDepthReached);
+    assert!(outcome.logged("child depth 2 exceeds limit 1"));
+}
+
+#[tokio::test]
+async fn earning_cap_logs_the_rejected_amount() {
+    let mut env = Env::new(InitializeParams {
+        max_single_earning: 1_000,
+        ..InitializeParams::default()
+    })
+    .await;
+    let root = env.register_root("root", "scouting").await;
+
+    let ix = client::build_record_earning_ix(
+        &env.namespace,
+        &root.pubkey(),
+        1_500,
+        0,
+        None,
+        &OptionalAccounts::default(),
+    );
+    let (result, outcome) = env.send_logged(ix, &[&root]).await;
+    assert_hydra_err(result, HydraError::EarningExceedsLimit);
+    assert!(outcome.logged("earning 1500 exceeds limit 1000"));
+
+    // At the cap is fine, and logs nothing about it.
+    let outcome = env.record(&root, 1_000, None).await;
+    assert!(!outcome.logged("exceeds limit"));
+}
+
+#[tokio::test]
+async fn inactive_parent_and_child_get_their_own_errors() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    env.record(&child, 1_000, None).await;
+
+    deactivate(&mut env, &child).await;
+    let ix = client::build_distribute_to_parent_ix(
+        &env.namespace,
+        &child.pubkey(),
+        &root.pubkey(),
+        100,
+        &OptionalAccounts::default(),
+    );
+    assert_hydra_err(env.send(ix, &[&child]).await, HydraError::ChildInactive);
+
+    deactivate(&mut env, &root).await;
+    let ix = env.spawn_ix(&root, &Keypair::new().pubkey(), "orphan", 1_000);
+    assert_hydra_err(env.send(ix, &[&root]).await, HydraError::ParentInactive);
+}
@@ -0,0 +1,46 @@
+use crate::harness::*;
+use hydra::InitializeParams;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn every_state_change_advances_event_seq() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    // `initialize` is the first change
+    assert_eq!(env.stats().await.event_seq, 1);
+
+    let set_max_depth = instruction(
+        hydra::accounts::UpdateRegistryConfig {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            authority: env.authority().pubkey(),
+        },
+        hydra::instruction::SetMaxDepth {
+            _namespace: env.namespace.clone(),
+            max_depth: 4,
+        },
+    );
+    env.send(set_max_depth, &[]).await.unwrap();
+    assert_eq!(env.stats().await.event_seq, 2);
+
+    let root = env.register_root("root", "scouting").await;
+    let registered = env.stats().await.event_seq;
+    let heartbeat = instruction(
+        hydra::accounts::Heartbeat {
+            stats: env.stats_key(),
+            agent: env.agent_key(&root.pubkey()),
+            wallet: root.pubkey(),
+        },
+        hydra::instruction::Heartbeat {
+            _namespace: env.namespace.clone(),
+        },
+    );
+    env.send(heartbeat, &[&root]).await.unwrap();
+    assert_eq!(env.stats().await.event_seq, registered + 1);
+
+    let child = env.spawn(&root, "child", 1_000).await;
+    let grandchild = env.spawn(&child, "grandchild", 1_000).await;
+    env.record(&grandchild, 100, Some(child.pubkey())).await;
+    let before = env.stats().await.event_seq;
+    env.roll_up(&child, &root).await;
+    assert_eq!(env.stats().await.event_seq, before + 1);
+}
@@ -0,0 +1,337 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, AccountDeserialize, Discriminator, InstructionData};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use hydra::client::{self, OptionalAccounts};
+use hydra::{AgentAccount, GlobalStats, HydraError, InitializeParams, Registry};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account as SolanaAccount;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub const NAMESPACE: &str = "test";
+pub const SOL: u64 = 1_000_000_000;
+
+/// Anchor's `entry` ties its accounts to one lifetime; the native processor
+/// hands out a shorter-lived slice.
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    hydra::entry(program_id, accounts, data)
+}
+
+/// What a processed transaction left behind.
+#[derive(Debug)]
+pub struct Outcome {
+    pub logs: Vec<String>,
+    /// Compute units the whole transaction consumed
+    pub compute_units: u64,
+    /// What the last instruction to set return data left behind
+    pub return_data: Option<Vec<u8>>,
+}
+
+impl Outcome {
+    /// Anchor events of type `E`, in emission order.
+    pub fn events<E: Discriminator + AnchorDeserialize>(&self) -> Vec<E> {
+        self.logs
+            .iter()
+            .filter_map(|line| line.split_once("Program data: ").map(|(_, data)| data))
+            .filter_map(|data| BASE64_STANDARD.decode(data.split(' ').next()?).ok())
+            .filter(|data| data.starts_with(E::DISCRIMINATOR))
+            .map(|data| E::deserialize(&mut &data[E::DISCRIMINATOR.len()..]).unwrap())
+            .collect()
+    }
+
+    /// Whether any log line contains `message`.
+    pub fn logged(&self, message: &str) -> bool {
+        self.logs.iter().any(|line| line.contains(message))
+    }
+
+    /// The return data decoded as `T`.
+    pub fn returned<T: AnchorDeserialize>(&self) -> T {
+        T::try_from_slice(self.return_data.as_deref().expect("no return data")).unwrap()
+    }
+}
+
+pub struct Env {
+    pub ctx: ProgramTestContext,
+    pub namespace: String,
+    /// Optional accounts passed when registering and spawning agents
+    pub optional: OptionalAccounts,
+    sent: u64,
+}
+
+impl Env {
+    /// A bank with a registry under `NAMESPACE`, the payer as its authority.
+    pub async fn new(params: InitializeParams) -> Env {
+        Self::with_programs(params, |_| {}).await
+    }
+
+    /// Like `new`, with `add_programs` registering extra programs (e.g. a CPI
+    /// caller) before the bank starts.
+    pub async fn with_programs(params: InitializeParams, add_programs: impl FnOnce(&mut ProgramTest)) -> Env {
+        let mut program_test = ProgramTest::new("hydra", hydra::ID, processor!(process_instruction));
+        add_programs(&mut program_test);
+        let ctx = program_test.start_with_context().await;
+
+        let mut env = Env {
+            ctx,
+            namespace: NAMESPACE.to_string(),
+            optional: OptionalAccounts::default(),
+            sent: 0,
+        };
+        let params = InitializeParams {
+            namespace: env.namespace.clone(),
+            ..params
+        };
+        let authority = env.authority().pubkey();
+        env.send(
+            instruction(
+                hydra::accounts::Initialize {
+                    registry: env.registry_key(),
+                    stats: env.stats_key(),
+                    authority,
+                    system_program: system_program::ID,
+                },
+                hydra::instruction::Initialize { params },
+            ),
+            &[],
+        )
+        .await
+        .unwrap();
+        env
+    }
+
+    pub fn authority(&self) -> &Keypair {
+        &self.ctx.payer
+    }
+
+    pub fn registry_key(&self) -> Pubkey {
+        Registry::find_namespaced_address(&self.namespace, &hydra::ID).0
+    }
+
+    pub fn stats_key(&self) -> Pubkey {
+        GlobalStats::find_address(&self.namespace, &hydra::ID).0
+    }
+
+    pub fn agent_key(&self, wallet: &Pubkey) -> Pubkey {
+        AgentAccount::find_address(&self.namespace, wallet, &hydra::ID).0
+    }
+
+    /// Process `ix` signed by the payer and `signers`. Each transaction carries
+    /// a distinct priority fee so identical instructions can be resent.
+    pub async fn send(&mut self, ix: Instruction, signers: &[&Keypair]) -> std::result::Result<Outcome, TransactionError> {
+        self.send_all(vec![ix], signers).await
+    }
+
+    pub async fn send_all(
+        &mut self,
+        ixs: Vec<Instruction>,
+        signers: &[&Keypair],
+    ) -> std::result::Result<Outcome, TransactionError> {
+        let (result, outcome) = self.process(ixs, signers).await;
+        result.map(|()| outcome)
+    }
+
+    /// Like `send`, but keeps the outcome of a failed transaction for its logs.
+    pub async fn send_logged(
+        &mut self,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> (std::result::Result<(), TransactionError>, Outcome) {
+        self.process(vec![ix], signers).await
+    }
+
+    async fn process(
+        &mut self,
+        ixs: Vec<Instruction>,
+        signers: &[&Keypair],
+    ) -> (std::result::Result<(), TransactionError>, Outcome) {
+        self.sent += 1;
+        let mut all = vec![ComputeBudgetInstruction::set_compute_unit_price(self.sent)];
+        all.extend(ixs);
+        let mut keys = vec![&self.ctx.payer];
+        keys.extend(signers.iter().filter(|s| s.pubkey() != self.ctx.payer.pubkey()));
+        let tx = Transaction::new_signed_with_payer(&all, Some(&self.ctx.payer.pubkey()), &keys, self.ctx.last_blockhash);
+        let processed = self.ctx.banks_client.process_transaction_with_metadata(tx).await.unwrap();
+        let metadata = processed.metadata.unwrap();
+        let outcome = Outcome {
+            logs: metadata.log_messages,
+            compute_units: metadata.compute_units_consumed,
+            return_data: metadata.return_data.map(|r| r.data),
+        };
+        (processed.result, outcome)
+    }
+
+    /// Simulate `ix` signed by the payer and `signers` without committing it.
+    pub async fn simulate(&mut self, ix: Instruction, signers: &[&Keypair]) -> std::result::Result<Outcome, TransactionError> {
+        let mut keys = vec![&self.ctx.payer];
+        keys.extend(signers.iter().filter(|s| s.pubkey() != self.ctx.payer.pubkey()));
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&self.ctx.payer.pubkey()), &keys, self.ctx.last_blockhash);
+        let simulated = self.ctx.banks_client.simulate_transaction(tx).await.unwrap();
+        let details = simulated.simulation_details.unwrap();
+        simulated.result.unwrap().map(|()| Outcome {
+            logs: details.logs,
+            compute_units: details.units_consumed,
+            return_data: details.return_data.map(|r| r.data),
+        })
+    }
+
+    /// A fresh wallet holding `lamports`.
+    pub async fn funded(&mut self, lamports: u64) -> Keypair {
+        let wallet = Keypair::new();
+        self.fund(&wallet.pubkey(), lamports).await;
+        wallet
+    }
+
+    pub async fn fund(&mut self, to: &Pubkey, lamports: u64) {
+        let payer = self.ctx.payer.pubkey();
+        self.send(
+            anchor_lang::solana_program::system_instruction::transfer(&payer, to, lamports),
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    /// Register a funded root agent, returning its wallet.
+    pub async fn register_root(&mut self, name: &str, specialization: &str) -> Keypair {
+        let wallet = self.funded(10 * SOL).await;
+        let ix = client::build_register_root_agent_ix(
+            &self.namespace,
+            &self.authority().pubkey(),
+            &wallet.pubkey(),
+            name.to_string(),
+            specialization.to_string(),
+            &self.optional,
+        );
+        self.send(ix, &[]).await.unwrap();
+        wallet
+    }
+
+    /// Spawn a funded child of `parent`'s agent, returning its wallet.
+    pub async fn spawn(&mut self, parent: &Keypair, name: &str, revenue_share_bps: u16) -> Keypair {
+        let wallet = self.funded(10 * SOL).await;
+        let ix = self.spawn_ix(parent, &wallet.pubkey(), name, revenue_share_bps);
+        self.send(ix, &[parent]).await.unwrap();
+        wallet
+    }
+
+    pub fn spawn_ix(&self, parent: &Keypair, child_wallet: &Pubkey, name: &str, revenue_share_bps: u16) -> Instruction {
+        client::build_spawn_child_ix(
+            &self.namespace,
+            &parent.pubkey(),
+            child_wallet,
+            spawn_args(name, revenue_share_bps),
+            &self.optional,
+        )
+    }
+
+    /// Record `amount` for `wallet`'s agent, crediting its parent right away
+    /// when `parent_wallet` is given.
+    pub async fn record(&mut self, wallet: &Keypair, amount: u64, parent_wallet: Option<Pubkey>) -> Outcome {
+        let ix = client::build_record_earning_ix(
+            &self.namespace,
+            &wallet.pubkey(),
+            amount,
+            0,
+            None,
+            &OptionalAccounts {
+                parent_wallet,
+                ..OptionalAccounts::default()
+            },
+        );
+        self.send(ix, &[wallet]).await.unwrap()
+    }
+
+    /// Roll `wallet`'s agent's pending subtree earnings up to its parent.
+    pub async fn roll_up(&mut self, wallet: &Keypair, parent_wallet: &Keypair) {
+        let ix = instruction(
+            hydra::accounts::RollUpEarnings {
+                stats: self.stats_key(),
+                agent: self.agent_key(&wallet.pubkey()),
+                parent_agent: self.agent_key(&parent_wallet.pubkey()),
+            },
+            hydra::instruction::RollUpEarnings {
+                namespace: self.namespace.clone(),
+            },
+        );
+        self.send(ix, &[]).await.unwrap();
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, key: &Pubkey) -> T {
+        let account = self.raw_account(key).await.expect("account missing");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn raw_account(&mut self, key: &Pubkey) -> Option<SolanaAccount> {
+        self.ctx.banks_client.get_account(*key).await.unwrap()
+    }
+
+    pub async fn agent(&mut self, wallet: &Keypair) -> AgentAccount {
+        let key = self.agent_key(&wallet.pubkey());
+        self.account(&key).await
+    }
+
+    pub async fn stats(&mut self) -> GlobalStats {
+        let key = self.stats_key();
+        self.account(&key).await
+    }
+
+    pub async fn balance(&mut self, key: &Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(*key).await.unwrap()
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        let clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    pub async fn set_time(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.ctx.set_sysvar(&clock);
+    }
+}
+
+pub fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: hydra::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn spawn_args(name: &str, revenue_share_bps: u16) -> hydra::instruction::SpawnChild {
+    hydra::instruction::SpawnChild {
+        _namespace: String::new(),
+        name: name.to_string(),
+        specialization: "scouting".to_string(),
+        revenue_share_bps,
+        secondary_beneficiary: None,
+        secondary_share_bps: 0,
+        expires_at: 0,
+        earning_goal: 0,
+    }
+}
+
+/// Read-only metas for `keys`, as traversal instructions take them.
+pub fn remaining(keys: &[Pubkey]) -> Vec<AccountMeta> {
+    keys.iter().map(|k| AccountMeta::new_readonly(*k, false)).collect()
+}
+
+/// Writable metas for `keys`, for instructions that update the accounts they walk.
+pub fn remaining_mut(keys: &[Pubkey]) -> Vec<AccountMeta> {
+    keys.iter().map(|k| AccountMeta::new(*k, false)).collect()
+}
+
+#[track_caller]
+pub fn assert_hydra_err<T: std::fmt::Debug>(result: std::result::Result<T, TransactionError>, expected: HydraError) {
+    let code = expected as u32 + anchor_lang::error::ERROR_CODE_OFFSET;
+    match result {
+        Err(TransactionError::InstructionError(_, InstructionError::Custom(c))) if c == code => {}
+        other => panic!("expected {expected:?} ({code}), got {other:?}"),
+    }
+}
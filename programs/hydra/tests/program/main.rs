@@ -0,0 +1,25 @@
+//! Program-level tests: each one starts a bank with hydra loaded and drives it
+//! through real transactions.
+//!
+//! Run with `cargo test-sbf -p hydra`. Off-chain, Anchor cannot make CPIs and
+//! drops event logs and return data, so these need the SBF build that
+//! `test-sbf` loads from `SBF_OUT_DIR`; the compute-unit measurements are
+//! ignored by default.
+
+#![cfg(feature = "test-sbf")]
+
+mod harness;
+
+mod bulk;
+mod client;
+mod close;
+mod compact_events;
+mod cpi;
+mod destinations;
+mod diagnostics;
+mod event_seq;
+mod memo;
+mod referral;
+mod reparent;
+mod sweep;
+mod views;
@@ -0,0 +1,83 @@
+use crate::harness::*;
+use hydra::client::{self, OptionalAccounts};
+use hydra::{HydraError, InitializeParams, MEMO_PROGRAM_ID};
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn set_emit_memos(env: &mut Env, emit_memos: bool) {
+    let ix = instruction(
+        hydra::accounts::UpdateRegistryConfig {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            authority: env.authority().pubkey(),
+        },
+        hydra::instruction::SetEmitMemos {
+            _namespace: env.namespace.clone(),
+            emit_memos,
+        },
+    );
+    env.send(ix, &[]).await.unwrap();
+}
+
+/// A root and a child that has earned enough to distribute.
+async fn tree(env: &mut Env) -> (Keypair, Keypair) {
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    env.record(&child, 10_000, None).await;
+    (root, child)
+}
+
+/// The line SPL Memo logs for `memo`, which carries its exact bytes.
+fn memo_log(memo: &str) -> String {
+    format!("Memo (len {}): {:?}", memo.len(), memo)
+}
+
+fn with_memo(memo: bool) -> OptionalAccounts {
+    OptionalAccounts {
+        memo,
+        ..OptionalAccounts::default()
+    }
+}
+
+#[tokio::test]
+async fn memos_record_the_exact_earning_and_distribution() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let (root, child) = tree(&mut env).await;
+    set_emit_memos(&mut env, true).await;
+    let child_agent = env.agent_key(&child.pubkey());
+    let root_agent = env.agent_key(&root.pubkey());
+
+    let ix = client::build_record_earning_ix(&env.namespace, &child.pubkey(), 1_234, 0, None, &with_memo(true));
+    let outcome = env.send(ix, &[&child]).await.unwrap();
+    assert!(outcome.logged(&format!("Program {MEMO_PROGRAM_ID} invoke [2]")));
+    assert!(outcome.logged(&memo_log(&format!("hydra:earn:{child_agent}:1234"))));
+
+    let ix = client::build_distribute_to_parent_ix(
+        &env.namespace,
+        &child.pubkey(),
+        &root.pubkey(),
+        567,
+        &with_memo(true),
+    );
+    let outcome = env.send(ix, &[&child]).await.unwrap();
+    assert!(outcome.logged(&memo_log(&format!("hydra:dist:{child_agent}:{root_agent}:567"))));
+}
+
+#[tokio::test]
+async fn memos_need_the_memo_program_only_while_enabled() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let (root, child) = tree(&mut env).await;
+
+    // Off: no memo account, and no memo CPI.
+    let ix = client::build_record_earning_ix(&env.namespace, &child.pubkey(), 100, 0, None, &with_memo(false));
+    let outcome = env.send(ix, &[&child]).await.unwrap();
+    assert!(!outcome.logged(&MEMO_PROGRAM_ID.to_string()));
+    let ix = client::build_distribute_to_parent_ix(&env.namespace, &child.pubkey(), &root.pubkey(), 100, &with_memo(false));
+    let outcome = env.send(ix, &[&child]).await.unwrap();
+    assert!(!outcome.logged(&MEMO_PROGRAM_ID.to_string()));
+
+    set_emit_memos(&mut env, true).await;
+    let ix = client::build_record_earning_ix(&env.namespace, &child.pubkey(), 100, 0, None, &with_memo(false));
+    assert_hydra_err(env.send(ix, &[&child]).await, HydraError::MissingMemoProgram);
+    let ix = client::build_distribute_to_parent_ix(&env.namespace, &child.pubkey(), &root.pubkey(), 100, &with_memo(false));
+    assert_hydra_err(env.send(ix, &[&child]).await, HydraError::MissingMemoProgram);
+}
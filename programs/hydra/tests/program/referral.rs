@@ -0,0 +1,158 @@
+use crate::harness::*;
+use anchor_lang::system_program;
+use hydra::{HydraError, InitializeParams, Referral, ReferralPaid};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::TransactionError;
+
+const REFERRAL_BPS: u16 = 500;
+const REFERRAL_SECS: i64 = 3_600;
+const AMOUNT: u64 = 100_000;
+
+struct Tree {
+    root: Keypair,
+    child: Keypair,
+    referrer: Keypair,
+}
+
+async fn setup() -> (Env, Tree) {
+    let mut env = Env::new(InitializeParams {
+        referral_bps: REFERRAL_BPS,
+        referral_duration_secs: REFERRAL_SECS,
+        ..InitializeParams::default()
+    })
+    .await;
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    let referrer = env.register_root("referrer", "scouting").await;
+    (env, Tree { root, child, referrer })
+}
+
+async fn register_referral(env: &mut Env, payer: &Keypair, referrer: &Keypair) {
+    let ix = instruction(
+        hydra::accounts::RegisterReferral {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            referral: Referral::find_address(&payer.pubkey(), &env.namespace, &hydra::ID).0,
+            referring_agent: env.agent_key(&referrer.pubkey()),
+            payer: payer.pubkey(),
+            system_program: system_program::ID,
+        },
+        hydra::instruction::RegisterReferral {
+            _namespace: env.namespace.clone(),
+        },
+    );
+    env.send(ix, &[payer]).await.unwrap();
+}
+
+/// `payer` pays `child` through `pay_agent_with_split`, passing its referral
+/// accounts when `referrer` is given.
+async fn pay(
+    env: &mut Env,
+    payer: &Keypair,
+    child: &Keypair,
+    parent: &Keypair,
+    referrer: Option<&Keypair>,
+) -> std::result::Result<Outcome, TransactionError> {
+    let ix = instruction(
+        hydra::accounts::PayAgentWithSplit {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            child_agent: env.agent_key(&child.pubkey()),
+            child_wallet: child.pubkey(),
+            parent_agent: Some(env.agent_key(&parent.pubkey())),
+            parent_wallet: Some(parent.pubkey()),
+            payer: payer.pubkey(),
+            system_program: system_program::ID,
+            referral: referrer.map(|_| Referral::find_address(&payer.pubkey(), &env.namespace, &hydra::ID).0),
+            referring_agent: referrer.map(|r| env.agent_key(&r.pubkey())),
+            referring_wallet: referrer.map(|r| r.pubkey()),
+        },
+        hydra::instruction::PayAgentWithSplit {
+            _namespace: env.namespace.clone(),
+            amount: AMOUNT,
+        },
+    );
+    env.send(ix, &[payer]).await
+}
+
+async fn balances(env: &mut Env, tree: &Tree) -> [u64; 3] {
+    [
+        env.balance(&tree.child.pubkey()).await,
+        env.balance(&tree.root.pubkey()).await,
+        env.balance(&tree.referrer.pubkey()).await,
+    ]
+}
+
+#[tokio::test]
+async fn active_referral_pays_the_referring_agent() {
+    let (mut env, tree) = setup().await;
+    let payer = env.funded(SOL).await;
+    register_referral(&mut env, &payer, &tree.referrer).await;
+
+    let before = balances(&mut env, &tree).await;
+    let outcome = pay(&mut env, &payer, &tree.child, &tree.root, Some(&tree.referrer)).await.unwrap();
+    let after = balances(&mut env, &tree).await;
+
+    // 10% to the parent, 5% to the referrer, the rest to the child
+    assert_eq!(after[0] - before[0], 85_000);
+    assert_eq!(after[1] - before[1], 10_000);
+    assert_eq!(after[2] - before[2], 5_000);
+    assert_eq!(env.agent(&tree.referrer).await.total_referral_earnings, 5_000);
+
+    let paid = outcome.events::<ReferralPaid>();
+    assert_eq!(paid.len(), 1);
+    assert_eq!(paid[0].payer, payer.pubkey());
+    assert_eq!(paid[0].referring_agent, env.agent_key(&tree.referrer.pubkey()));
+    assert_eq!(paid[0].amount, 5_000);
+}
+
+#[tokio::test]
+async fn payment_without_referral_splits_between_parent_and_child() {
+    let (mut env, tree) = setup().await;
+    let payer = env.funded(SOL).await;
+
+    let before = balances(&mut env, &tree).await;
+    let outcome = pay(&mut env, &payer, &tree.child, &tree.root, None).await.unwrap();
+    let after = balances(&mut env, &tree).await;
+
+    assert_eq!(after[0] - before[0], 90_000);
+    assert_eq!(after[1] - before[1], 10_000);
+    assert_eq!(after[2], before[2]);
+    assert!(outcome.events::<ReferralPaid>().is_empty());
+}
+
+#[tokio::test]
+async fn expired_referral_pays_nothing() {
+    let (mut env, tree) = setup().await;
+    let payer = env.funded(SOL).await;
+    register_referral(&mut env, &payer, &tree.referrer).await;
+    let now = env.now().await;
+    env.set_time(now + REFERRAL_SECS).await;
+
+    let before = balances(&mut env, &tree).await;
+    let outcome = pay(&mut env, &payer, &tree.child, &tree.root, Some(&tree.referrer)).await.unwrap();
+    let after = balances(&mut env, &tree).await;
+
+    assert_eq!(after[0] - before[0], 90_000);
+    assert_eq!(after[1] - before[1], 10_000);
+    assert_eq!(after[2], before[2]);
+    assert_eq!(env.agent(&tree.referrer).await.total_referral_earnings, 0);
+    assert!(outcome.events::<ReferralPaid>().is_empty());
+}
+
+#[tokio::test]
+async fn referral_and_parent_shares_may_not_exceed_the_payment() {
+    let (mut env, tree) = setup().await;
+    let greedy_parent = env.register_root("greedy", "scouting").await;
+    // 9_600 + 500 > 10_000
+    let child = env.spawn(&greedy_parent, "squeezed", 9_600).await;
+    let payer = env.funded(SOL).await;
+    register_referral(&mut env, &payer, &tree.referrer).await;
+
+    assert_hydra_err(
+        pay(&mut env, &payer, &child, &greedy_parent, Some(&tree.referrer)).await,
+        HydraError::InvalidRevenueShare,
+    );
+    // Without the referral the same payment goes through.
+    pay(&mut env, &payer, &child, &greedy_parent, None).await.unwrap();
+}
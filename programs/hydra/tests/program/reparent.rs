@@ -0,0 +1,110 @@
+use crate::harness::*;
+use hydra::{HydraError, InitializeParams};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+
+fn reparent_ix(env: &Env, child: &Keypair, old_parent: &Keypair, new_parent: &Keypair, ancestors: &[&Keypair]) -> Instruction {
+    let mut ix = instruction(
+        hydra::accounts::ReparentAgent {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            child_agent: env.agent_key(&child.pubkey()),
+            old_parent: env.agent_key(&old_parent.pubkey()),
+            new_parent: env.agent_key(&new_parent.pubkey()),
+            old_parent_wallet: old_parent.pubkey(),
+            new_parent_wallet: new_parent.pubkey(),
+            child_wallet: None,
+        },
+        hydra::instruction::ReparentAgent {
+            _namespace: env.namespace.clone(),
+        },
+    );
+    let ancestors: Vec<_> = ancestors.iter().map(|w| env.agent_key(&w.pubkey())).collect();
+    ix.accounts.extend(remaining(&ancestors));
+    ix
+}
+
+#[tokio::test]
+async fn reparent_moves_counts_shares_and_subtree_earnings() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let old_parent = env.register_root("old", "scouting").await;
+    let new_parent = env.register_root("new", "scouting").await;
+    let child = env.spawn(&old_parent, "child", 1_500).await;
+    let grandchild = env.spawn(&child, "grandchild", 500).await;
+    env.record(&grandchild, 300, Some(child.pubkey())).await;
+    env.record(&child, 700, Some(old_parent.pubkey())).await;
+    // The grandchild's earnings reach the old parent through the child's roll-up
+    env.roll_up(&child, &old_parent).await;
+    assert_eq!(env.agent(&old_parent).await.subtree_earned, 1_000);
+
+    let ix = reparent_ix(&env, &child, &old_parent, &new_parent, &[]);
+    let outcome = env.send(ix, &[&old_parent, &new_parent]).await.unwrap();
+
+    let old = env.agent(&old_parent).await;
+    let new = env.agent(&new_parent).await;
+    let moved = env.agent(&child).await;
+    assert_eq!((old.children_count, old.children_share_sum, old.subtree_earned), (0, 0, 0));
+    assert_eq!((new.children_count, new.children_share_sum, new.subtree_earned), (1, 1_500, 1_000));
+    assert_eq!((moved.parent, moved.depth), (env.agent_key(&new_parent.pubkey()), 1));
+    assert_eq!(env.agent(&grandchild).await.depth, 2);
+    let stats = env.stats().await;
+    assert_eq!((stats.total_agents, stats.depth_counts[1], stats.depth_counts[2]), (4, 1, 1));
+
+    let reparented = outcome.events::<hydra::AgentReparented>();
+    assert_eq!(reparented.len(), 1);
+    assert_eq!(reparented[0].new_depth, 1);
+}
+
+#[tokio::test]
+async fn reparent_requires_pending_earnings_rolled_up() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let old_parent = env.register_root("old", "scouting").await;
+    let new_parent = env.register_root("new", "scouting").await;
+    let child = env.spawn(&old_parent, "child", 1_000).await;
+    let grandchild = env.spawn(&child, "grandchild", 1_000).await;
+    // Credited to the child but not yet rolled up to the old parent
+    env.record(&grandchild, 300, Some(child.pubkey())).await;
+
+    let ix = reparent_ix(&env, &child, &old_parent, &new_parent, &[]);
+    assert_hydra_err(env.send(ix, &[&old_parent, &new_parent]).await, HydraError::PendingRollUp);
+}
+
+#[tokio::test]
+async fn reparent_past_max_depth_fails() {
+    let mut env = Env::new(InitializeParams {
+        max_depth: 2,
+        ..InitializeParams::default()
+    })
+    .await;
+    let root = env.register_root("root", "scouting").await;
+    let middle = env.spawn(&root, "middle", 1_000).await;
+    let deepest = env.spawn(&middle, "deepest", 1_000).await;
+    let other_root = env.register_root("other", "scouting").await;
+    let leaf = env.spawn(&other_root, "leaf", 1_000).await;
+
+    let ix = reparent_ix(&env, &leaf, &other_root, &deepest, &[&middle, &root]);
+    assert_hydra_err(env.send(ix, &[&other_root, &deepest]).await, HydraError::MaxDepthReached);
+
+    // One level up fits
+    let ix = reparent_ix(&env, &leaf, &other_root, &middle, &[&root]);
+    env.send(ix, &[&other_root, &middle]).await.unwrap();
+    assert_eq!(env.agent(&leaf).await.depth, 2);
+}
+
+#[tokio::test]
+async fn reparent_under_own_descendant_fails() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    let grandchild = env.spawn(&child, "grandchild", 1_000).await;
+    let leaf = env.spawn(&grandchild, "leaf", 1_000).await;
+    let other_root = env.register_root("other", "scouting").await;
+    let moving = env.spawn(&other_root, "moving", 1_000).await;
+
+    // Moving `child` under its own grandchild would close a cycle
+    let ix = reparent_ix(&env, &child, &root, &grandchild, &[&child, &root]);
+    assert_hydra_err(env.send(ix, &[&root, &grandchild]).await, HydraError::InvalidReparent);
+
+    let ix = reparent_ix(&env, &moving, &other_root, &leaf, &[&grandchild, &child, &root]);
+    env.send(ix, &[&other_root, &leaf]).await.unwrap();
+}
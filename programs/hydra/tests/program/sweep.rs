@@ -0,0 +1,42 @@
+use crate::harness::*;
+use hydra::{DustSwept, HydraError, InitializeParams};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+
+fn sweep_ix(env: &Env, wallet: &Keypair) -> Instruction {
+    instruction(
+        hydra::accounts::SweepAgentPda {
+            stats: env.stats_key(),
+            agent: env.agent_key(&wallet.pubkey()),
+            wallet: wallet.pubkey(),
+        },
+        hydra::instruction::SweepAgentPda {
+            _namespace: env.namespace.clone(),
+        },
+    )
+}
+
+#[tokio::test]
+async fn sweep_returns_exactly_the_surplus_and_keeps_the_agent_rent_exempt() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let root = env.register_root("root", "scouting").await;
+    let agent = env.agent_key(&root.pubkey());
+    let rent = env.ctx.banks_client.get_rent().await.unwrap();
+    let data_len = env.raw_account(&agent).await.unwrap().data.len();
+    let rent_exempt = rent.minimum_balance(data_len);
+    assert_eq!(env.balance(&agent).await, rent_exempt);
+
+    // Someone sends SOL to the agent PDA instead of its wallet.
+    env.fund(&agent, 12_345).await;
+    let wallet_before = env.balance(&root.pubkey()).await;
+
+    let outcome = env.send(sweep_ix(&env, &root), &[&root]).await.unwrap();
+    assert_eq!(env.balance(&root.pubkey()).await - wallet_before, 12_345);
+    assert_eq!(env.balance(&agent).await, rent_exempt);
+    assert_eq!(env.agent(&root).await.wallet, root.pubkey());
+    let swept = outcome.events::<DustSwept>();
+    assert_eq!(swept.len(), 1);
+    assert_eq!((swept[0].agent, swept[0].amount), (agent, 12_345));
+
+    assert_hydra_err(env.send(sweep_ix(&env, &root), &[&root]).await, HydraError::NothingToSweep);
+}
@@ -0,0 +1,146 @@
+use crate::harness::*;
+use anchor_lang::prelude::*;
+use hydra::views::{AgentSummary, Ancestry, LineageDepth, RegistryStats};
+use hydra::{InitializeParams, Registry};
+use solana_sdk::signature::{Keypair, Signer};
+
+/// A root with a child and a grandchild; the child has earned and paid its parent.
+async fn tree(env: &mut Env) -> (Keypair, Keypair, Keypair) {
+    let root = env.register_root("root", "scouting").await;
+    let child = env.spawn(&root, "child", 1_000).await;
+    let grandchild = env.spawn(&child, "grandchild", 1_000).await;
+    env.record(&child, 5_000, Some(root.pubkey())).await;
+    (root, child, grandchild)
+}
+
+#[tokio::test]
+async fn get_agent_summary_matches_the_agent_account() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let (_, child, _) = tree(&mut env).await;
+    let key = env.agent_key(&child.pubkey());
+
+    let ix = instruction(
+        hydra::accounts::ReportPosition { agent: key },
+        hydra::instruction::GetAgentSummary {},
+    );
+    let summary: AgentSummary = env.simulate(ix, &[]).await.unwrap().returned();
+    let agent = env.agent(&child).await;
+    assert_eq!(
+        summary,
+        AgentSummary {
+            agent: key,
+            wallet: agent.wallet,
+            parent: agent.parent,
+            depth: agent.depth,
+            children_count: agent.children_count,
+            is_operational: true,
+            total_earned: agent.total_earned,
+            net_position: agent.net_position(),
+        }
+    );
+    assert_eq!(summary.total_earned, 5_000);
+    assert_eq!(summary.children_count, 1);
+}
+
+#[tokio::test]
+async fn get_lineage_depth_matches_the_agent_and_registry() {
+    let mut env = Env::new(InitializeParams {
+        max_depth: 4,
+        ..InitializeParams::default()
+    })
+    .await;
+    let (_, _, grandchild) = tree(&mut env).await;
+
+    let ix = instruction(
+        hydra::accounts::GetLineageDepth {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+            agent: env.agent_key(&grandchild.pubkey()),
+        },
+        hydra::instruction::GetLineageDepth {
+            _namespace: env.namespace.clone(),
+        },
+    );
+    let depth: LineageDepth = env.simulate(ix, &[]).await.unwrap().returned();
+    let registry: Registry = env.account(&env.registry_key()).await;
+    assert_eq!(
+        depth,
+        LineageDepth {
+            depth: env.agent(&grandchild).await.depth,
+            max_depth: registry.max_depth,
+            levels_below: 2,
+        }
+    );
+}
+
+#[tokio::test]
+async fn get_registry_stats_matches_the_stats_account() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    tree(&mut env).await;
+
+    let ix = instruction(
+        hydra::accounts::ReconcileReport {
+            registry: env.registry_key(),
+            stats: env.stats_key(),
+        },
+        hydra::instruction::GetRegistryStats {
+            _namespace: env.namespace.clone(),
+        },
+    );
+    let returned: RegistryStats = env.simulate(ix, &[]).await.unwrap().returned();
+    let registry: Registry = env.account(&env.registry_key()).await;
+    let stats = env.stats().await;
+    assert_eq!(
+        returned,
+        RegistryStats {
+            total_agents: stats.total_agents,
+            total_spawns: stats.total_spawns,
+            total_reported_earnings: stats.total_reported_earnings,
+            total_transferred_volume: stats.total_transferred_volume,
+            max_depth: registry.max_depth,
+            decimals: registry.decimals,
+            currency_symbol: registry.currency_symbol,
+            event_seq: stats.event_seq,
+        }
+    );
+    assert_eq!(returned.total_agents, 3);
+}
+
+#[tokio::test]
+async fn is_ancestor_and_list_children_follow_the_tree() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let (root, child, grandchild) = tree(&mut env).await;
+    let [root, child, grandchild] = [&root, &child, &grandchild].map(|w| env.agent_key(&w.pubkey()));
+
+    let mut ix = instruction(
+        hydra::accounts::IsAncestor {
+            ancestor: root,
+            descendant: grandchild,
+        },
+        hydra::instruction::IsAncestor {},
+    );
+    ix.accounts.extend(remaining(&[child]));
+    let ancestry: Ancestry = env.simulate(ix, &[]).await.unwrap().returned();
+    assert_eq!(ancestry, Ancestry { is_ancestor: true, hops: 2 });
+
+    let mut ix = instruction(hydra::accounts::ListChildren { parent: root }, hydra::instruction::ListChildren {});
+    ix.accounts.extend(remaining(&[child, grandchild, child]));
+    let wallets: Vec<Pubkey> = env.simulate(ix, &[]).await.unwrap().returned();
+    assert_eq!(wallets, vec![env.account::<hydra::AgentAccount>(&child).await.wallet]);
+}
+
+#[tokio::test]
+async fn views_leave_event_seq_alone() {
+    let mut env = Env::new(InitializeParams::default()).await;
+    let (_, child, _) = tree(&mut env).await;
+    let before = env.stats().await.event_seq;
+
+    let ix = instruction(
+        hydra::accounts::ReportPosition {
+            agent: env.agent_key(&child.pubkey()),
+        },
+        hydra::instruction::GetAgentSummary {},
+    );
+    env.send(ix, &[]).await.unwrap();
+    assert_eq!(env.stats().await.event_seq, before);
+}